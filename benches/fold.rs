@@ -0,0 +1,84 @@
+//! Compares consuming `Accumulate` through external iteration (`next()`) with consuming it
+//! through internal iteration (`fold()`, `for_each()` and, with the nightly `try_trait` feature,
+//! `try_fold()`).
+//!
+//! Run with `cargo bench`, or with `cargo +nightly bench --features try_trait`.
+
+use iter_accumulate::IterAccumulate;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+const LEN: usize = 1 << 16;
+const ROUNDS: u32 = 200;
+
+/// Runs `f` for [`ROUNDS`] rounds and prints the average time per round.
+fn bench(name: &str, mut f: impl FnMut() -> u64) {
+    // Warm up
+    black_box(f());
+
+    let start = Instant::now();
+    for _ in 0..ROUNDS {
+        black_box(f());
+    }
+    let elapsed: Duration = start.elapsed() / ROUNDS;
+    println!("{name:<32} {:>10.1} µs/round", elapsed.as_secs_f64() * 1e6);
+}
+
+fn main() {
+    let a: Vec<u64> = (0..LEN as u64).collect();
+    let b: Vec<u64> = (0..LEN as u64).rev().collect();
+
+    // A chain of two slices is a base iterator with a much faster `fold()` than `next()`.
+    let base = || black_box(&a).iter().chain(black_box(&b));
+
+    bench("chain: next()", || {
+        // `for` loops drive the iterator through `next()`
+        let mut total = 0u64;
+        for acc in base().accumulate(0u64, |acc, &i| acc.wrapping_add(i)) {
+            total ^= acc;
+        }
+        total
+    });
+    bench("chain: fold()", || {
+        base()
+            .accumulate(0u64, |acc, &i| acc.wrapping_add(i))
+            .fold(0, |total, acc| total ^ acc)
+    });
+    bench("chain: for_each()", || {
+        let mut total = 0u64;
+        base()
+            .accumulate(0u64, |acc, &i| acc.wrapping_add(i))
+            .for_each(|acc| total ^= acc);
+        total
+    });
+    bench("chain: last()", || {
+        base()
+            .accumulate(0u64, |acc, &i| acc.wrapping_add(i))
+            .last()
+            .unwrap_or(0)
+    });
+    bench("chain: position()", || {
+        // Only uses the internal iteration of the base iterator with the `try_trait` feature
+        base()
+            .accumulate(0u64, |acc, &i| acc.wrapping_add(i))
+            .position(|acc| acc == u64::MAX)
+            .map_or(0, |i| i as u64)
+    });
+
+    bench("slice: next()", || {
+        let mut total = 0u64;
+        for acc in black_box(&a)
+            .iter()
+            .accumulate(0u64, |acc, &i| acc.wrapping_add(i))
+        {
+            total ^= acc;
+        }
+        total
+    });
+    bench("slice: fold()", || {
+        black_box(&a)
+            .iter()
+            .accumulate(0u64, |acc, &i| acc.wrapping_add(i))
+            .fold(0, |total, acc| total ^ acc)
+    });
+}
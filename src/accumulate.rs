@@ -0,0 +1,812 @@
+use crate::POISONED;
+#[cfg(feature = "alloc")]
+use alloc::boxed::Box;
+use core::{fmt, iter::FusedIterator};
+
+/// An iterator adaptor that accumulates the elements from the base iterator using the provided
+/// closure.
+///
+/// See the [crate-level documentation](crate) for more information.
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone)]
+pub struct Accumulate<I, B, F> {
+    iter: I,
+    /// The value that [`reset()`](Self::reset) goes back to, if it has been stored by
+    /// [`with_reset()`](Self::with_reset).
+    init: Option<B>,
+    /// The accumulated value. This is only [`None`] while it is moved into the closure, or if the
+    /// closure panicked.
+    acc: Option<B>,
+    f: F,
+    /// Whether the base iterator has returned [`None`] yet.
+    done: bool,
+}
+
+/// The parts of an [`Accumulate`], as returned by [`Accumulate::into_parts()`] and taken by
+/// [`Accumulate::from_parts()`].
+#[derive(Clone)]
+pub struct AccumulateParts<I, B, F> {
+    /// The base iterator.
+    pub iter: I,
+    /// The current accumulated value, i.e. the value the next element is accumulated into.
+    pub acc: B,
+    /// The value that [`reset()`](Accumulate::reset) goes back to, if it has been stored by
+    /// [`with_reset()`](Accumulate::with_reset).
+    pub init: Option<B>,
+    /// The closure.
+    pub f: F,
+}
+
+impl<I, B, F> fmt::Debug for AccumulateParts<I, B, F>
+where
+    I: fmt::Debug,
+    B: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("AccumulateParts")
+            .field("iter", &self.iter)
+            .field("acc", &self.acc)
+            .field("init", &self.init)
+            .finish_non_exhaustive()
+    }
+}
+
+/// An [`Accumulate`] with a boxed closure, which can be named in struct fields and function
+/// signatures.
+///
+/// This is created by [`accumulate_boxed()`](crate::IterAccumulate::accumulate_boxed).
+#[cfg(feature = "alloc")]
+pub type BoxedAccumulate<'a, I, B> =
+    Accumulate<I, B, Box<dyn FnMut(B, <I as Iterator>::Item) -> B + 'a>>;
+
+impl<I, B, F> Accumulate<I, B, F>
+where
+    B: Clone,
+{
+    /// Creates an iterator adaptor that accumulates the elements from `iter`, starting with
+    /// `init`, using the closure `f`.
+    ///
+    /// This is equivalent to `iter.accumulate(init, f)`, see
+    /// [`IterAccumulate::accumulate()`](crate::IterAccumulate::accumulate).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iter_accumulate::Accumulate;
+    ///
+    /// let input = [1, 2, 3];
+    /// let mut iter = Accumulate::new(input.iter(), 0, |acc, i| acc + i);
+    ///
+    /// assert_eq!(iter.next(), Some(1));
+    /// assert_eq!(iter.next(), Some(3));
+    /// assert_eq!(iter.next(), Some(6));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    #[inline]
+    pub fn new(iter: I, init: B, f: F) -> Self {
+        Self {
+            iter,
+            init: None,
+            acc: Some(init),
+            f,
+            done: false,
+        }
+    }
+
+    /// Stores a clone of the current accumulated value, which [`reset()`](Self::reset) goes back
+    /// to.
+    ///
+    /// Called right after creating the adaptor, this stores the initial value. The value is only
+    /// stored on request, so adaptors that are never reset do not keep a second copy of it.
+    ///
+    /// See [`reset()`](Self::reset) for an example.
+    #[inline]
+    pub fn with_reset(mut self) -> Self {
+        self.init = Some(self.acc().clone());
+        self
+    }
+
+    /// Resets the accumulated value to the value stored by [`with_reset()`](Self::with_reset).
+    ///
+    /// The next element of the base iterator is accumulated into the stored value, as if it was
+    /// the first one.
+    ///
+    /// # Panics
+    ///
+    /// Panics if no value has been stored by [`with_reset()`](Self::with_reset).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iter_accumulate::IterAccumulate;
+    ///
+    /// let input = [1, 2, 3, 4];
+    /// let mut iter = input.iter().accumulate(0, |acc, i| acc + i).with_reset();
+    ///
+    /// assert_eq!(iter.next(), Some(1));
+    /// assert_eq!(iter.next(), Some(3));
+    /// iter.reset();
+    /// assert_eq!(iter.acc(), &0);
+    /// assert_eq!(iter.next(), Some(3));
+    /// assert_eq!(iter.next(), Some(7));
+    /// ```
+    #[inline]
+    pub fn reset(&mut self) {
+        let init = self
+            .init
+            .as_ref()
+            .expect("no value stored by `with_reset()`");
+        self.acc = Some(init.clone());
+    }
+}
+
+impl<I, B, F> Accumulate<I, B, F> {
+    /// Decomposes the iterator adaptor into its [`AccumulateParts`]: the base iterator, the
+    /// current accumulated value, the value stored by [`with_reset()`](Self::with_reset), and
+    /// the closure.
+    ///
+    /// The parts can be put back together using [`from_parts()`](Self::from_parts). If the base
+    /// iterator has already returned [`None`], the returned base iterator is that exhausted
+    /// iterator, so whether it yields any more elements depends on the base iterator.
+    ///
+    /// See [`from_parts()`](Self::from_parts) for an example.
+    #[inline]
+    pub fn into_parts(self) -> AccumulateParts<I, B, F> {
+        AccumulateParts {
+            iter: self.iter,
+            acc: self.acc.expect(POISONED),
+            init: self.init,
+            f: self.f,
+        }
+    }
+
+    /// Recreates an iterator adaptor from the parts returned by
+    /// [`into_parts()`](Self::into_parts), e.g. to resume a suspended accumulation.
+    ///
+    /// The resumed adaptor goes on exactly like the decomposed one would have, including the
+    /// value that [`reset()`](Self::reset) goes back to.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iter_accumulate::{Accumulate, IterAccumulate};
+    ///
+    /// let input = [1, 2, 3, 4];
+    /// let mut iter = input.iter().accumulate(0, |acc, i| acc + i).with_reset();
+    /// assert_eq!(iter.next(), Some(1));
+    /// assert_eq!(iter.next(), Some(3));
+    ///
+    /// let parts = iter.into_parts();
+    /// assert_eq!(parts.acc, 3);
+    /// assert_eq!(parts.init, Some(0));
+    ///
+    /// let mut iter = Accumulate::from_parts(parts);
+    /// assert_eq!(iter.next(), Some(6));
+    /// iter.reset();
+    /// assert_eq!(iter.next(), Some(4));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    #[inline]
+    pub fn from_parts(parts: AccumulateParts<I, B, F>) -> Self {
+        Self {
+            iter: parts.iter,
+            init: parts.init,
+            acc: Some(parts.acc),
+            f: parts.f,
+            done: false,
+        }
+    }
+
+    /// Returns a reference to the current accumulated value.
+    ///
+    /// Before the first element has been yielded, this is the initial value. Afterwards, it is the
+    /// value that was yielded last.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iter_accumulate::IterAccumulate;
+    ///
+    /// let input = [1, 2, 3, 4, 5];
+    /// let mut iter = input.iter().accumulate(0, |acc, i| acc + i);
+    ///
+    /// assert_eq!(iter.acc(), &0);
+    /// assert_eq!(iter.by_ref().take_while(|&acc| acc < 5).count(), 2);
+    /// assert_eq!(iter.acc(), &6);
+    /// ```
+    #[inline]
+    pub fn acc(&self) -> &B {
+        self.acc.as_ref().expect(POISONED)
+    }
+
+    /// Returns a mutable reference to the current accumulated value.
+    ///
+    /// The next element of the base iterator is accumulated into the modified value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iter_accumulate::IterAccumulate;
+    ///
+    /// let input = [1, 2, 3];
+    /// let mut iter = input.iter().accumulate(0, |acc, i| acc + i);
+    ///
+    /// assert_eq!(iter.next(), Some(1));
+    /// *iter.acc_mut() *= 10;
+    /// assert_eq!(iter.next(), Some(12));
+    /// ```
+    #[inline]
+    pub fn acc_mut(&mut self) -> &mut B {
+        self.acc.as_mut().expect(POISONED)
+    }
+
+    /// Overwrites the current accumulated value.
+    ///
+    /// The next element of the base iterator is accumulated into `acc`. The value that
+    /// [`reset()`](Self::reset) goes back to stays the same.
+    ///
+    /// This also makes the iterator adaptor usable again if the closure has panicked.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iter_accumulate::IterAccumulate;
+    ///
+    /// let input = [1, 2, 3];
+    /// let mut iter = input.iter().accumulate(0, |acc, i| acc + i).with_reset();
+    ///
+    /// assert_eq!(iter.next(), Some(1));
+    /// iter.set_acc(100);
+    /// assert_eq!(iter.next(), Some(102));
+    /// iter.reset();
+    /// assert_eq!(iter.next(), Some(3));
+    /// ```
+    #[inline]
+    pub fn set_acc(&mut self, acc: B) {
+        self.acc = Some(acc);
+    }
+
+    /// Consumes the iterator adaptor and returns the current accumulated value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iter_accumulate::IterAccumulate;
+    ///
+    /// let input = [1, 2, 3, 4, 5];
+    /// let mut iter = input.iter().accumulate(1, |acc, i| acc * i);
+    ///
+    /// assert_eq!(iter.nth(2), Some(6));
+    /// assert_eq!(iter.into_acc(), 6);
+    /// ```
+    #[inline]
+    pub fn into_acc(self) -> B {
+        self.acc.expect(POISONED)
+    }
+
+    /// Turns the iterator adaptor into a [`PeekableAccumulate`], which can look at the next
+    /// accumulated value before yielding it.
+    ///
+    /// See [`PeekableAccumulate::peek_acc()`] for an example.
+    #[inline]
+    pub fn into_peekable(self) -> PeekableAccumulate<I, B, F>
+    where
+        I: Iterator,
+    {
+        PeekableAccumulate {
+            inner: self,
+            peeked: None,
+            peeked_acc: None,
+        }
+    }
+}
+
+impl<I, B, F> fmt::Debug for Accumulate<I, B, F>
+where
+    I: fmt::Debug,
+    B: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Accumulate")
+            .field("iter", &self.iter)
+            .field("init", &self.init)
+            .field("acc", &self.acc)
+            .field("done", &self.done)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<I, B, F> Accumulate<I, B, F>
+where
+    I: Iterator,
+    B: Clone,
+    F: FnMut(B, I::Item) -> B,
+{
+    /// Feeds up to `n` elements of the base iterator through the closure without yielding them,
+    /// and returns how many elements were skipped.
+    fn skip_accumulating(&mut self, n: usize) -> usize {
+        if self.done {
+            return 0;
+        }
+        let mut skipped = 0;
+        let f = &mut self.f;
+        let acc = self.acc.take().expect(POISONED);
+        let acc = self.iter.by_ref().take(n).fold(acc, |acc, item| {
+            skipped += 1;
+            f(acc, item)
+        });
+        self.acc = Some(acc);
+        if skipped < n {
+            self.done = true;
+        }
+        skipped
+    }
+}
+
+impl<I, B, F> Iterator for Accumulate<I, B, F>
+where
+    I: Iterator,
+    B: Clone,
+    F: FnMut(B, I::Item) -> B,
+{
+    type Item = B;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        match self.iter.next() {
+            Some(item) => {
+                let acc = (self.f)(self.acc.take().expect(POISONED), item);
+                Some(self.acc.insert(acc).clone())
+            }
+            None => {
+                self.done = true;
+                None
+            }
+        }
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        if self.skip_accumulating(n) < n {
+            return None;
+        }
+        self.next()
+    }
+
+    #[cfg(feature = "iter_advance_by")]
+    #[inline]
+    fn advance_by(&mut self, n: usize) -> Result<(), core::num::NonZero<usize>> {
+        match core::num::NonZero::new(n - self.skip_accumulating(n)) {
+            None => Ok(()),
+            Some(remaining) => Err(remaining),
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            (0, Some(0))
+        } else {
+            self.iter.size_hint()
+        }
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        if self.done {
+            0
+        } else {
+            self.iter.count()
+        }
+    }
+
+    #[inline]
+    fn last(self) -> Option<Self::Item> {
+        self.fold(None, |_, acc| Some(acc))
+    }
+
+    #[cfg(feature = "try_trait")]
+    #[inline]
+    fn try_fold<Acc, G, R>(&mut self, init: Acc, mut g: G) -> R
+    where
+        G: FnMut(Acc, Self::Item) -> R,
+        R: core::ops::Try<Output = Acc>,
+    {
+        if self.done {
+            return R::from_output(init);
+        }
+        let f = &mut self.f;
+        let state = &mut self.acc;
+        let result = self.iter.try_fold(init, |acc, item| {
+            let next = f(state.take().expect(POISONED), item);
+            g(acc, state.insert(next).clone())
+        });
+        match result.branch() {
+            core::ops::ControlFlow::Continue(acc) => {
+                // The base iterator only runs to completion once it has returned `None`.
+                self.done = true;
+                R::from_output(acc)
+            }
+            core::ops::ControlFlow::Break(residual) => R::from_residual(residual),
+        }
+    }
+
+    #[inline]
+    fn fold<Acc, G>(self, init: Acc, mut g: G) -> Acc
+    where
+        G: FnMut(Acc, Self::Item) -> Acc,
+    {
+        if self.done {
+            return init;
+        }
+        let mut f = self.f;
+        let state = self.acc.expect(POISONED);
+        self.iter
+            .fold((init, state), move |(acc, state), item| {
+                let state = f(state, item);
+                (g(acc, state.clone()), state)
+            })
+            .0
+    }
+
+    #[inline]
+    fn for_each<G>(self, mut g: G)
+    where
+        G: FnMut(Self::Item),
+    {
+        if self.done {
+            return;
+        }
+        let mut f = self.f;
+        let state = self.acc.expect(POISONED);
+        self.iter.fold(state, move |state, item| {
+            let state = f(state, item);
+            g(state.clone());
+            state
+        });
+    }
+}
+
+impl<I, B, F> ExactSizeIterator for Accumulate<I, B, F>
+where
+    I: ExactSizeIterator,
+    B: Clone,
+    F: FnMut(B, I::Item) -> B,
+{
+    #[inline]
+    fn len(&self) -> usize {
+        if self.done {
+            0
+        } else {
+            self.iter.len()
+        }
+    }
+}
+
+impl<I, B, F> FusedIterator for Accumulate<I, B, F>
+where
+    I: Iterator,
+    B: Clone,
+    F: FnMut(B, I::Item) -> B,
+{
+}
+
+// SAFETY: `Accumulate` yields exactly one element for every element of the base iterator, and its
+// `size_hint()` is the one of the base iterator until that has returned `None`.
+#[cfg(feature = "trusted_len")]
+unsafe impl<I, B, F> core::iter::TrustedLen for Accumulate<I, B, F>
+where
+    I: core::iter::TrustedLen,
+    B: Clone,
+    F: FnMut(B, I::Item) -> B,
+{
+}
+
+/// Allows collecting into the allocation of the source vector.
+///
+/// # Examples
+///
+/// ```
+/// use iter_accumulate::IterAccumulate;
+///
+/// let input = vec![1, 2, 3, 4, 5];
+/// let ptr = input.as_ptr();
+/// let output: Vec<_> = input.into_iter().accumulate(0, |acc, i| acc + i).collect();
+///
+/// assert_eq!(output, [1, 3, 6, 10, 15]);
+/// assert_eq!(output.as_ptr(), ptr);
+/// ```
+// SAFETY: The source is only ever accessed through the base iterator.
+#[cfg(feature = "inplace_iteration")]
+unsafe impl<I, B, F> core::iter::SourceIter for Accumulate<I, B, F>
+where
+    I: core::iter::SourceIter,
+{
+    type Source = I::Source;
+
+    #[inline]
+    unsafe fn as_inner(&mut self) -> &mut I::Source {
+        // SAFETY: The caller upholds the contract of `SourceIter::as_inner()`, which is the same
+        // for the base iterator.
+        unsafe { core::iter::SourceIter::as_inner(&mut self.iter) }
+    }
+}
+
+// SAFETY: `Accumulate` consumes exactly one element of the base iterator for every element it
+// yields, and the accumulated value is stored outside of the source.
+#[cfg(feature = "inplace_iteration")]
+unsafe impl<I, B, F> core::iter::InPlaceIterable for Accumulate<I, B, F>
+where
+    I: core::iter::InPlaceIterable,
+{
+    const EXPAND_BY: Option<core::num::NonZero<usize>> = I::EXPAND_BY;
+    const MERGE_BY: Option<core::num::NonZero<usize>> = I::MERGE_BY;
+}
+
+/// An iterator adaptor like [`Accumulate`] that can look at the next accumulated value before
+/// yielding it.
+///
+/// This `struct` is created by [`Accumulate::into_peekable()`]. See its documentation for more
+/// information.
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone)]
+pub struct PeekableAccumulate<I: Iterator, B, F> {
+    inner: Accumulate<I, B, F>,
+    /// An element that was pulled from the base iterator by [`peek_acc()`](Self::peek_acc), but
+    /// has not been accumulated into the accumulated value of `inner` yet.
+    peeked: Option<I::Item>,
+    /// The value that `peeked` accumulates into, as returned by `peek_acc()`. This is discarded
+    /// when the accumulated value is modified, so that `peeked` is accumulated into the modified
+    /// value instead.
+    peeked_acc: Option<B>,
+}
+
+impl<I, B, F> PeekableAccumulate<I, B, F>
+where
+    I: Iterator,
+    B: Clone,
+    F: FnMut(B, I::Item) -> B,
+{
+    /// Returns a reference to the next value that will be yielded, without yielding it.
+    ///
+    /// This pulls the next element from the base iterator and accumulates a clone of it into a
+    /// clone of the current accumulated value, but keeps both until the peeked value is yielded.
+    /// Peeking again returns the same value without advancing the base iterator. The current
+    /// accumulated value, as returned by [`acc()`](Self::acc), does not include the peeked
+    /// element yet, and if it is modified, e.g. by [`reset()`](Self::reset) or
+    /// [`set_acc()`](Self::set_acc), the peeked element is accumulated into the modified value
+    /// instead.
+    ///
+    /// # Examples
+    ///
+    /// Packing items into a bin without exceeding its capacity:
+    ///
+    /// ```
+    /// use iter_accumulate::IterAccumulate;
+    ///
+    /// let sizes = [4, 3, 5, 2];
+    /// let mut iter = sizes.iter().accumulate(0, |acc, i| acc + i).into_peekable();
+    ///
+    /// let mut packed = 0;
+    /// while iter.peek_acc().map_or(false, |&total| total <= 10) {
+    ///     iter.next();
+    ///     packed += 1;
+    /// }
+    ///
+    /// assert_eq!(packed, 2);
+    /// assert_eq!(iter.peek_acc(), Some(&12));
+    /// assert_eq!(iter.next(), Some(12));
+    /// ```
+    ///
+    /// Starting a new bin with the item that does not fit into the current one:
+    ///
+    /// ```
+    /// use iter_accumulate::IterAccumulate;
+    ///
+    /// let sizes = [4, 3, 5, 2];
+    /// let mut iter = sizes
+    ///     .iter()
+    ///     .accumulate(0, |acc, i| acc + i)
+    ///     .with_reset()
+    ///     .into_peekable();
+    ///
+    /// let mut bins = Vec::new();
+    /// while let Some(&total) = iter.peek_acc() {
+    ///     if total > 10 {
+    ///         bins.push(*iter.acc());
+    ///         iter.reset();
+    ///     }
+    ///     iter.next();
+    /// }
+    /// bins.push(*iter.acc());
+    ///
+    /// assert_eq!(bins, [7, 7]);
+    /// ```
+    #[inline]
+    pub fn peek_acc(&mut self) -> Option<&B>
+    where
+        I::Item: Clone,
+    {
+        if self.peeked.is_none() {
+            if self.inner.done {
+                return None;
+            }
+            match self.inner.iter.next() {
+                Some(item) => self.peeked = Some(item),
+                None => {
+                    self.inner.done = true;
+                    return None;
+                }
+            }
+        }
+        if self.peeked_acc.is_none() {
+            let acc = self.inner.acc().clone();
+            let item = self.peeked.clone()?;
+            self.peeked_acc = Some((self.inner.f)(acc, item));
+        }
+        self.peeked_acc.as_ref()
+    }
+
+    /// Resets the accumulated value to the value stored by
+    /// [`Accumulate::with_reset()`], like [`Accumulate::reset()`].
+    ///
+    /// A peeked element is accumulated into the stored value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if no value has been stored by [`Accumulate::with_reset()`].
+    ///
+    /// See [`peek_acc()`](Self::peek_acc) for an example.
+    #[inline]
+    pub fn reset(&mut self) {
+        self.inner.reset();
+        self.peeked_acc = None;
+    }
+
+    /// Accumulates the peeked element, if there is one, into the accumulated value, and returns
+    /// whether there was one.
+    fn accumulate_peeked(&mut self) -> bool {
+        let item = match self.peeked.take() {
+            Some(item) => item,
+            None => return false,
+        };
+        let acc = match self.peeked_acc.take() {
+            Some(acc) => acc,
+            None => (self.inner.f)(self.inner.acc.take().expect(POISONED), item),
+        };
+        self.inner.acc = Some(acc);
+        true
+    }
+}
+
+impl<I: Iterator, B, F> PeekableAccumulate<I, B, F> {
+    /// Returns a reference to the current accumulated value, which does not include a peeked
+    /// element until it is yielded.
+    #[inline]
+    pub fn acc(&self) -> &B {
+        self.inner.acc()
+    }
+
+    /// Overwrites the current accumulated value, like [`Accumulate::set_acc()`].
+    ///
+    /// A peeked element is accumulated into `acc`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iter_accumulate::IterAccumulate;
+    ///
+    /// let input = [1, 2, 3];
+    /// let mut iter = input.iter().accumulate(0, |acc, i| acc + i).into_peekable();
+    ///
+    /// assert_eq!(iter.next(), Some(1));
+    /// assert_eq!(iter.peek_acc(), Some(&3));
+    /// iter.set_acc(100);
+    /// assert_eq!(iter.peek_acc(), Some(&102));
+    /// assert_eq!(iter.next(), Some(102));
+    /// ```
+    #[inline]
+    pub fn set_acc(&mut self, acc: B) {
+        self.inner.set_acc(acc);
+        self.peeked_acc = None;
+    }
+
+    /// Consumes the iterator adaptor and returns the current accumulated value, which does not
+    /// include a peeked element.
+    #[inline]
+    pub fn into_acc(self) -> B {
+        self.inner.into_acc()
+    }
+}
+
+impl<I, B, F> fmt::Debug for PeekableAccumulate<I, B, F>
+where
+    I: Iterator + fmt::Debug,
+    B: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("PeekableAccumulate")
+            .field("inner", &self.inner)
+            .field("peeked_acc", &self.peeked_acc)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<I, B, F> Iterator for PeekableAccumulate<I, B, F>
+where
+    I: Iterator,
+    B: Clone,
+    F: FnMut(B, I::Item) -> B,
+{
+    type Item = B;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.accumulate_peeked() {
+            return Some(self.inner.acc().clone());
+        }
+        self.inner.next()
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        match n.checked_sub(usize::from(self.accumulate_peeked())) {
+            Some(n) => self.inner.nth(n),
+            None => Some(self.inner.acc().clone()),
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let peeked = usize::from(self.peeked.is_some());
+        let (lower, upper) = self.inner.size_hint();
+        (
+            lower.saturating_add(peeked),
+            upper.and_then(|upper| upper.checked_add(peeked)),
+        )
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        usize::from(self.peeked.is_some()) + self.inner.count()
+    }
+
+    #[inline]
+    fn last(self) -> Option<Self::Item> {
+        self.fold(None, |_, acc| Some(acc))
+    }
+
+    #[inline]
+    fn fold<Acc, G>(mut self, mut init: Acc, mut g: G) -> Acc
+    where
+        G: FnMut(Acc, Self::Item) -> Acc,
+    {
+        if self.accumulate_peeked() {
+            init = g(init, self.inner.acc().clone());
+        }
+        self.inner.fold(init, g)
+    }
+}
+
+impl<I, B, F> ExactSizeIterator for PeekableAccumulate<I, B, F>
+where
+    I: ExactSizeIterator,
+    B: Clone,
+    F: FnMut(B, I::Item) -> B,
+{
+    #[inline]
+    fn len(&self) -> usize {
+        usize::from(self.peeked.is_some()) + self.inner.len()
+    }
+}
+
+impl<I, B, F> FusedIterator for PeekableAccumulate<I, B, F>
+where
+    I: Iterator,
+    B: Clone,
+    F: FnMut(B, I::Item) -> B,
+{
+}
@@ -0,0 +1,156 @@
+use core::{fmt, iter::FusedIterator};
+
+/// An iterator adaptor that accumulates the elements from the base iterator by updating the
+/// accumulated value in place.
+///
+/// This `struct` is created by [`accumulate_mut()`] and [`accumulate_mut_map()`]. See their
+/// documentation for more information.
+///
+/// [`accumulate_mut()`]: crate::IterAccumulate::accumulate_mut
+/// [`accumulate_mut_map()`]: crate::IterAccumulate::accumulate_mut_map
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone)]
+pub struct AccumulateMut<I, B, F, P> {
+    iter: I,
+    acc: B,
+    f: F,
+    project: P,
+    /// Whether the base iterator has returned [`None`] yet.
+    done: bool,
+}
+
+impl<I, B, F, P> AccumulateMut<I, B, F, P> {
+    pub(crate) fn new(iter: I, acc: B, f: F, project: P) -> Self {
+        Self {
+            iter,
+            acc,
+            f,
+            project,
+            done: false,
+        }
+    }
+
+    /// Returns a reference to the current accumulated value.
+    #[inline]
+    pub fn acc(&self) -> &B {
+        &self.acc
+    }
+
+    /// Consumes the iterator adaptor and returns the current accumulated value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iter_accumulate::IterAccumulate;
+    ///
+    /// let input = [3, 1, 2];
+    /// let mut iter = input.iter().accumulate_mut(Vec::new(), |acc, &i| acc.push(i));
+    ///
+    /// iter.next();
+    /// iter.next();
+    /// assert_eq!(iter.into_acc(), [3, 1]);
+    /// ```
+    #[inline]
+    pub fn into_acc(self) -> B {
+        self.acc
+    }
+}
+
+impl<I, B, F, P> fmt::Debug for AccumulateMut<I, B, F, P>
+where
+    I: fmt::Debug,
+    B: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("AccumulateMut")
+            .field("iter", &self.iter)
+            .field("acc", &self.acc)
+            .field("done", &self.done)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<I, B, F, P, O> Iterator for AccumulateMut<I, B, F, P>
+where
+    I: Iterator,
+    F: FnMut(&mut B, I::Item),
+    P: FnMut(&B) -> O,
+{
+    type Item = O;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        match self.iter.next() {
+            Some(item) => {
+                (self.f)(&mut self.acc, item);
+                Some((self.project)(&self.acc))
+            }
+            None => {
+                self.done = true;
+                None
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            (0, Some(0))
+        } else {
+            self.iter.size_hint()
+        }
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        if self.done {
+            0
+        } else {
+            self.iter.count()
+        }
+    }
+
+    #[inline]
+    fn fold<Acc, G>(self, init: Acc, mut g: G) -> Acc
+    where
+        G: FnMut(Acc, Self::Item) -> Acc,
+    {
+        if self.done {
+            return init;
+        }
+        let mut state = self.acc;
+        let mut f = self.f;
+        let mut project = self.project;
+        self.iter.fold(init, move |acc, item| {
+            f(&mut state, item);
+            g(acc, project(&state))
+        })
+    }
+}
+
+impl<I, B, F, P, O> ExactSizeIterator for AccumulateMut<I, B, F, P>
+where
+    I: ExactSizeIterator,
+    F: FnMut(&mut B, I::Item),
+    P: FnMut(&B) -> O,
+{
+    #[inline]
+    fn len(&self) -> usize {
+        if self.done {
+            0
+        } else {
+            self.iter.len()
+        }
+    }
+}
+
+impl<I, B, F, P, O> FusedIterator for AccumulateMut<I, B, F, P>
+where
+    I: Iterator,
+    F: FnMut(&mut B, I::Item),
+    P: FnMut(&B) -> O,
+{
+}
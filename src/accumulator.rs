@@ -0,0 +1,513 @@
+use core::{fmt, iter::FusedIterator, ops::Add};
+
+use crate::{Ewma, Float, MaxSubarray, Mean, Quantile, Trapezoid};
+
+/// A running computation over a sequence of values, which can report its result after every
+/// value.
+///
+/// An accumulator is its own state: it starts out as the state for no values, is
+/// [updated](Self::update) with every value, and its [current output](Self::current) can be
+/// queried at any time. Accumulators can be composed with [`pair()`](Self::pair),
+/// [`map()`](Self::map) and [`filter()`](Self::filter), and driven over an iterator with
+/// [`running()`](crate::IterAccumulate::running), which yields the output after every element.
+///
+/// Besides the basic [`Count`], [`Total`], [`Maximum`] and [`Minimum`] accumulators, this is
+/// implemented for the states of some running adaptors, like [`Mean`] and [`Ewma`].
+///
+/// # Examples
+///
+/// ```
+/// use iter_accumulate::{Accumulator, Count, Maximum, Mean};
+///
+/// let mut stats = Mean::new().pair(Maximum::new()).pair(Count::new());
+/// for x in [2.0, 7.0, 3.0] {
+///     stats.update(x);
+/// }
+///
+/// let ((mean, max), count) = stats.finish();
+/// assert_eq!(mean, 4.0);
+/// assert_eq!(max, Some(7.0));
+/// assert_eq!(count, 3);
+/// ```
+pub trait Accumulator<T> {
+    /// The type of the result of the accumulator.
+    type Output;
+
+    /// Adds `item` to the accumulator.
+    fn update(&mut self, item: T);
+
+    /// Returns the result of the accumulator for the values added so far.
+    fn current(&self) -> Self::Output;
+
+    /// Consumes the accumulator and returns its result.
+    ///
+    /// By default, this is the same as [`current()`](Self::current), but accumulators can
+    /// override it to avoid cloning their state.
+    #[inline]
+    fn finish(self) -> Self::Output
+    where
+        Self: Sized,
+    {
+        self.current()
+    }
+
+    /// Combines this accumulator with `other` into one that adds every value to both, and whose
+    /// output is the pair of their outputs.
+    #[inline]
+    fn pair<A>(self, other: A) -> AccumulatorPair<Self, A>
+    where
+        Self: Sized,
+        T: Clone,
+        A: Accumulator<T>,
+    {
+        AccumulatorPair(self, other)
+    }
+
+    /// Creates an accumulator whose output is the output of this one, transformed by the closure
+    /// `f`.
+    #[inline]
+    fn map<O, F>(self, f: F) -> MapAccumulator<Self, F>
+    where
+        Self: Sized,
+        F: Fn(Self::Output) -> O,
+    {
+        MapAccumulator { acc: self, f }
+    }
+
+    /// Creates an accumulator that only adds the values that satisfy the predicate `pred` to this
+    /// one.
+    #[inline]
+    fn filter<P>(self, pred: P) -> FilterAccumulator<Self, P>
+    where
+        Self: Sized,
+        P: FnMut(&T) -> bool,
+    {
+        FilterAccumulator { acc: self, pred }
+    }
+}
+
+/// An accumulator that adds every value to two accumulators, and whose output is the pair of
+/// their outputs.
+///
+/// This `struct` is created by [`Accumulator::pair()`]. See its documentation for more
+/// information.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct AccumulatorPair<A, B>(pub A, pub B);
+
+impl<T, A, B> Accumulator<T> for AccumulatorPair<A, B>
+where
+    T: Clone,
+    A: Accumulator<T>,
+    B: Accumulator<T>,
+{
+    type Output = (A::Output, B::Output);
+
+    #[inline]
+    fn update(&mut self, item: T) {
+        self.0.update(item.clone());
+        self.1.update(item);
+    }
+
+    #[inline]
+    fn current(&self) -> Self::Output {
+        (self.0.current(), self.1.current())
+    }
+
+    #[inline]
+    fn finish(self) -> Self::Output {
+        (self.0.finish(), self.1.finish())
+    }
+}
+
+/// An accumulator whose output is the output of another accumulator, transformed by a closure.
+///
+/// This `struct` is created by [`Accumulator::map()`]. See its documentation for more
+/// information.
+#[derive(Clone)]
+pub struct MapAccumulator<A, F> {
+    acc: A,
+    f: F,
+}
+
+impl<A: fmt::Debug, F> fmt::Debug for MapAccumulator<A, F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("MapAccumulator")
+            .field("acc", &self.acc)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<T, A, F, O> Accumulator<T> for MapAccumulator<A, F>
+where
+    A: Accumulator<T>,
+    F: Fn(A::Output) -> O,
+{
+    type Output = O;
+
+    #[inline]
+    fn update(&mut self, item: T) {
+        self.acc.update(item);
+    }
+
+    #[inline]
+    fn current(&self) -> Self::Output {
+        (self.f)(self.acc.current())
+    }
+
+    #[inline]
+    fn finish(self) -> Self::Output {
+        (self.f)(self.acc.finish())
+    }
+}
+
+/// An accumulator that only adds the values that satisfy a predicate to another accumulator.
+///
+/// This `struct` is created by [`Accumulator::filter()`]. See its documentation for more
+/// information.
+#[derive(Clone)]
+pub struct FilterAccumulator<A, P> {
+    acc: A,
+    pred: P,
+}
+
+impl<A: fmt::Debug, P> fmt::Debug for FilterAccumulator<A, P> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("FilterAccumulator")
+            .field("acc", &self.acc)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<T, A, P> Accumulator<T> for FilterAccumulator<A, P>
+where
+    A: Accumulator<T>,
+    P: FnMut(&T) -> bool,
+{
+    type Output = A::Output;
+
+    #[inline]
+    fn update(&mut self, item: T) {
+        if (self.pred)(&item) {
+            self.acc.update(item);
+        }
+    }
+
+    #[inline]
+    fn current(&self) -> Self::Output {
+        self.acc.current()
+    }
+
+    #[inline]
+    fn finish(self) -> Self::Output {
+        self.acc.finish()
+    }
+}
+
+/// An accumulator that counts the values.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Count(usize);
+
+impl Count {
+    /// Creates an accumulator that has counted no values.
+    #[inline]
+    pub fn new() -> Self {
+        Self(0)
+    }
+}
+
+impl<T> Accumulator<T> for Count {
+    type Output = usize;
+
+    #[inline]
+    fn update(&mut self, _item: T) {
+        self.0 += 1;
+    }
+
+    #[inline]
+    fn current(&self) -> Self::Output {
+        self.0
+    }
+}
+
+/// An accumulator that sums the values, starting from [`T::default()`](Default::default).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Total<T>(T);
+
+impl<T: Default> Total<T> {
+    /// Creates an accumulator with a sum of zero, i.e. [`T::default()`](Default::default).
+    #[inline]
+    pub fn new() -> Self {
+        Self(T::default())
+    }
+}
+
+impl<T> Accumulator<T> for Total<T>
+where
+    T: Add<Output = T> + Clone + Default,
+{
+    type Output = T;
+
+    #[inline]
+    fn update(&mut self, item: T) {
+        self.0 = core::mem::take(&mut self.0) + item;
+    }
+
+    #[inline]
+    fn current(&self) -> Self::Output {
+        self.0.clone()
+    }
+
+    #[inline]
+    fn finish(self) -> Self::Output {
+        self.0
+    }
+}
+
+/// An accumulator that keeps the largest value, or the last of several equally large ones.
+///
+/// Values only need to be [`PartialOrd`], and values that are not comparable to the current
+/// maximum, like NaN, do not replace it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Maximum<T>(Option<T>);
+
+/// An accumulator that keeps the smallest value, or the first of several equally small ones.
+///
+/// Values only need to be [`PartialOrd`], and values that are not comparable to the current
+/// minimum, like NaN, do not replace it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Minimum<T>(Option<T>);
+
+macro_rules! impl_extremum {
+    ($name:ident, $desc:literal, $replaces:expr) => {
+        impl<T> $name<T> {
+            #[doc = concat!("Creates an accumulator with no ", $desc, " value.")]
+            #[inline]
+            pub fn new() -> Self {
+                Self(None)
+            }
+        }
+
+        impl<T> Default for $name<T> {
+            #[inline]
+            fn default() -> Self {
+                Self::new()
+            }
+        }
+
+        impl<T: PartialOrd + Clone> Accumulator<T> for $name<T> {
+            type Output = Option<T>;
+
+            #[inline]
+            fn update(&mut self, item: T) {
+                let replaces: fn(&T, &T) -> bool = $replaces;
+                match &self.0 {
+                    // A current value that is not even comparable to itself, like NaN, is
+                    // replaced by any value.
+                    Some(current)
+                        if !replaces(&item, current) && current.partial_cmp(current).is_some() => {}
+                    _ => self.0 = Some(item),
+                }
+            }
+
+            #[inline]
+            fn current(&self) -> Self::Output {
+                self.0.clone()
+            }
+
+            #[inline]
+            fn finish(self) -> Self::Output {
+                self.0
+            }
+        }
+    };
+}
+
+impl_extremum!(Maximum, "largest", |item, current| item >= current);
+impl_extremum!(Minimum, "smallest", |item, current| item < current);
+
+/// An iterator adaptor that adds the elements from the base iterator to an [`Accumulator`], and
+/// yields its output after every element.
+///
+/// This `struct` is created by [`running()`]. See its documentation for more information.
+///
+/// [`running()`]: crate::IterAccumulate::running
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone)]
+pub struct Running<I, A> {
+    iter: I,
+    acc: A,
+}
+
+impl<I, A> Running<I, A> {
+    pub(crate) fn new(iter: I, acc: A) -> Self {
+        Self { iter, acc }
+    }
+
+    /// Returns a reference to the accumulator.
+    #[inline]
+    pub fn accumulator(&self) -> &A {
+        &self.acc
+    }
+
+    /// Consumes the iterator adaptor and returns the accumulator.
+    #[inline]
+    pub fn into_accumulator(self) -> A {
+        self.acc
+    }
+}
+
+impl<I, A> fmt::Debug for Running<I, A>
+where
+    I: fmt::Debug,
+    A: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Running")
+            .field("iter", &self.iter)
+            .field("acc", &self.acc)
+            .finish()
+    }
+}
+
+impl<I, A> Iterator for Running<I, A>
+where
+    I: Iterator,
+    A: Accumulator<I::Item>,
+{
+    type Item = A::Output;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.iter.next()?;
+        self.acc.update(item);
+        Some(self.acc.current())
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        self.iter.count()
+    }
+
+    #[inline]
+    fn fold<Acc, G>(self, init: Acc, mut g: G) -> Acc
+    where
+        G: FnMut(Acc, Self::Item) -> Acc,
+    {
+        let mut acc = self.acc;
+        self.iter.fold(init, move |out, item| {
+            acc.update(item);
+            g(out, acc.current())
+        })
+    }
+}
+
+impl<I, A> ExactSizeIterator for Running<I, A>
+where
+    I: ExactSizeIterator,
+    A: Accumulator<I::Item>,
+{
+    #[inline]
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+
+impl<I, A> FusedIterator for Running<I, A>
+where
+    I: FusedIterator,
+    A: Accumulator<I::Item>,
+{
+}
+
+impl<T: Float> Accumulator<T> for Mean<T> {
+    type Output = T;
+
+    #[inline]
+    fn update(&mut self, item: T) {
+        self.push(item);
+    }
+
+    #[inline]
+    fn current(&self) -> Self::Output {
+        self.mean()
+    }
+}
+
+impl<T: Float> Accumulator<T> for Ewma<T> {
+    type Output = T;
+
+    #[inline]
+    fn update(&mut self, item: T) {
+        self.push(item);
+    }
+
+    #[inline]
+    fn current(&self) -> Self::Output {
+        self.value()
+    }
+}
+
+impl<T: Float> Accumulator<T> for Quantile<T> {
+    type Output = T;
+
+    #[inline]
+    fn update(&mut self, item: T) {
+        self.push(item);
+    }
+
+    #[inline]
+    fn current(&self) -> Self::Output {
+        self.quantile()
+    }
+}
+
+impl<T: Float> Accumulator<(T, T)> for Trapezoid<T> {
+    type Output = T;
+
+    #[inline]
+    fn update(&mut self, (t, y): (T, T)) {
+        self.push(t, y);
+    }
+
+    #[inline]
+    fn current(&self) -> Self::Output {
+        self.integral()
+    }
+}
+
+impl<T> Accumulator<T> for MaxSubarray<T>
+where
+    T: Copy + PartialOrd + Add<Output = T>,
+{
+    type Output = Option<T>;
+
+    #[inline]
+    fn update(&mut self, item: T) {
+        self.push(item);
+    }
+
+    #[inline]
+    fn current(&self) -> Self::Output {
+        self.best()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: Float> Accumulator<T> for crate::Median<T> {
+    type Output = T;
+
+    #[inline]
+    fn update(&mut self, item: T) {
+        self.push(item);
+    }
+
+    #[inline]
+    fn current(&self) -> Self::Output {
+        self.median()
+    }
+}
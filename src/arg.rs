@@ -0,0 +1,141 @@
+use core::{fmt, iter::FusedIterator};
+
+/// An iterator adaptor that yields the index and value of the best element from the base iterator
+/// so far, according to a key.
+///
+/// This `struct` is created by [`running_argmax_by_key()`] and [`running_argmin_by_key()`]. See
+/// their documentation for more information.
+///
+/// [`running_argmax_by_key()`]: crate::IterAccumulate::running_argmax_by_key
+/// [`running_argmin_by_key()`]: crate::IterAccumulate::running_argmin_by_key
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone)]
+pub struct RunningArgBest<I: Iterator, K, F> {
+    iter: I,
+    /// The index, value and key of the best element so far.
+    best: Option<(usize, I::Item, K)>,
+    /// The index of the next element.
+    index: usize,
+    f: F,
+    /// Whether the maximum is tracked, rather than the minimum.
+    max: bool,
+    /// Whether the base iterator has returned [`None`] yet.
+    done: bool,
+}
+
+impl<I: Iterator, K, F> RunningArgBest<I, K, F> {
+    pub(crate) fn new(iter: I, f: F, max: bool) -> Self {
+        Self {
+            iter,
+            best: None,
+            index: 0,
+            f,
+            max,
+            done: false,
+        }
+    }
+
+    /// Returns the index and a reference to the best element so far, or [`None`] if no element
+    /// has been yielded yet.
+    #[inline]
+    pub fn best(&self) -> Option<(usize, &I::Item)> {
+        self.best.as_ref().map(|(index, item, _)| (*index, item))
+    }
+}
+
+impl<I, K, F> fmt::Debug for RunningArgBest<I, K, F>
+where
+    I: Iterator + fmt::Debug,
+    I::Item: fmt::Debug,
+    K: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("RunningArgBest")
+            .field("iter", &self.iter)
+            .field("best", &self.best)
+            .field("index", &self.index)
+            .field("max", &self.max)
+            .field("done", &self.done)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<I, K, F> Iterator for RunningArgBest<I, K, F>
+where
+    I: Iterator,
+    I::Item: Clone,
+    K: Ord,
+    F: FnMut(&I::Item) -> K,
+{
+    type Item = (usize, I::Item);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let Some(item) = self.iter.next() else {
+            self.done = true;
+            return None;
+        };
+        let index = self.index;
+        self.index += 1;
+        let key = (self.f)(&item);
+        // Like `max_by_key()` and `min_by_key()`, the last maximum and the first minimum win
+        let better = match &self.best {
+            None => true,
+            Some((_, _, best)) if self.max => key >= *best,
+            Some((_, _, best)) => key < *best,
+        };
+        if better {
+            self.best = Some((index, item, key));
+        }
+        self.best
+            .as_ref()
+            .map(|(index, item, _)| (*index, item.clone()))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            (0, Some(0))
+        } else {
+            self.iter.size_hint()
+        }
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        if self.done {
+            0
+        } else {
+            self.iter.count()
+        }
+    }
+}
+
+impl<I, K, F> ExactSizeIterator for RunningArgBest<I, K, F>
+where
+    I: ExactSizeIterator,
+    I::Item: Clone,
+    K: Ord,
+    F: FnMut(&I::Item) -> K,
+{
+    #[inline]
+    fn len(&self) -> usize {
+        if self.done {
+            0
+        } else {
+            self.iter.len()
+        }
+    }
+}
+
+impl<I, K, F> FusedIterator for RunningArgBest<I, K, F>
+where
+    I: Iterator,
+    I::Item: Clone,
+    K: Ord,
+    F: FnMut(&I::Item) -> K,
+{
+}
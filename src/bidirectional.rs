@@ -0,0 +1,129 @@
+use crate::{IterAccumulate, POISONED};
+use alloc::vec::Vec;
+use core::{fmt, iter::FusedIterator};
+
+/// An iterator adaptor that yields both the prefix and the suffix accumulation for each element
+/// of the base iterator.
+///
+/// This `struct` is created by [`bidirectional_accumulate()`]. See its documentation for more
+/// information.
+///
+/// [`bidirectional_accumulate()`]: crate::IterAccumulate::bidirectional_accumulate
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone)]
+pub struct BidirectionalAccumulate<I, B, F> {
+    iter: I,
+    /// The value the suffix accumulations start from. This is taken when they are computed.
+    init: Option<B>,
+    /// The prefix accumulation yielded last. This is only [`None`] while it is moved into the
+    /// closure, or if the closure panicked.
+    prefix: Option<B>,
+    /// The suffix accumulations of the remaining elements, once they have been computed.
+    suffixes: Option<Vec<B>>,
+    f: F,
+    /// Whether the base iterator has returned [`None`] yet.
+    done: bool,
+}
+
+impl<I, B, F> BidirectionalAccumulate<I, B, F>
+where
+    B: Clone,
+{
+    pub(crate) fn new(iter: I, acc: B, f: F) -> Self {
+        Self {
+            iter,
+            init: Some(acc.clone()),
+            prefix: Some(acc),
+            suffixes: None,
+            f,
+            done: false,
+        }
+    }
+}
+
+impl<I, B, F> fmt::Debug for BidirectionalAccumulate<I, B, F>
+where
+    I: fmt::Debug,
+    B: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("BidirectionalAccumulate")
+            .field("iter", &self.iter)
+            .field("prefix", &self.prefix)
+            .field("suffixes", &self.suffixes)
+            .field("done", &self.done)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<I, B, F> Iterator for BidirectionalAccumulate<I, B, F>
+where
+    I: DoubleEndedIterator + ExactSizeIterator + Clone,
+    B: Clone,
+    F: FnMut(B, I::Item) -> B,
+{
+    type Item = (B, B);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        // The suffix accumulations are stored from the back, so that the one belonging to the
+        // next element can be popped off the end of the buffer.
+        let suffixes = match &mut self.suffixes {
+            Some(suffixes) => suffixes,
+            None => {
+                let mut suffixes = Vec::with_capacity(self.iter.len());
+                let init = self.init.take().expect(POISONED);
+                suffixes.extend(self.iter.clone().rev().accumulate(init, &mut self.f));
+                self.suffixes.insert(suffixes)
+            }
+        };
+        match self.iter.next() {
+            Some(item) => {
+                let prefix = (self.f)(self.prefix.take().expect(POISONED), item);
+                let prefix = self.prefix.insert(prefix).clone();
+                suffixes.pop().map(|suffix| (prefix, suffix))
+            }
+            None => {
+                self.done = true;
+                None
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            (0, Some(0))
+        } else {
+            self.iter.size_hint()
+        }
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        if self.done {
+            0
+        } else {
+            self.iter.count()
+        }
+    }
+}
+
+impl<I, B, F> ExactSizeIterator for BidirectionalAccumulate<I, B, F>
+where
+    I: DoubleEndedIterator + ExactSizeIterator + Clone,
+    B: Clone,
+    F: FnMut(B, I::Item) -> B,
+{
+}
+
+impl<I, B, F> FusedIterator for BidirectionalAccumulate<I, B, F>
+where
+    I: DoubleEndedIterator + ExactSizeIterator + Clone,
+    B: Clone,
+    F: FnMut(B, I::Item) -> B,
+{
+}
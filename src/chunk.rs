@@ -0,0 +1,110 @@
+use core::{fmt, iter::FusedIterator};
+
+/// What [`chunk_fold()`](crate::IterAccumulate::chunk_fold) does with the last chunk if it has
+/// fewer elements than the chunk size.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum PartialChunk {
+    /// Yield the folded value of the partial chunk like that of any other chunk.
+    Keep,
+    /// Drop the partial chunk, like [`slice::chunks_exact()`] does.
+    Discard,
+}
+
+/// An iterator adaptor that folds every chunk of `n` consecutive elements from the base iterator
+/// into one value.
+///
+/// This `struct` is created by [`chunk_fold()`]. See its documentation for more information.
+///
+/// [`chunk_fold()`]: crate::IterAccumulate::chunk_fold
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone)]
+pub struct ChunkFold<I, B, F> {
+    iter: I,
+    n: usize,
+    init: B,
+    f: F,
+    partial: PartialChunk,
+}
+
+impl<I, B, F> ChunkFold<I, B, F> {
+    pub(crate) fn new(iter: I, n: usize, partial: PartialChunk, init: B, f: F) -> Self {
+        assert!(n != 0, "`n` must not be zero");
+        Self {
+            iter,
+            n,
+            init,
+            f,
+            partial,
+        }
+    }
+}
+
+impl<I, B, F> fmt::Debug for ChunkFold<I, B, F>
+where
+    I: fmt::Debug,
+    B: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("ChunkFold")
+            .field("iter", &self.iter)
+            .field("n", &self.n)
+            .field("init", &self.init)
+            .field("partial", &self.partial)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<I, B, F> Iterator for ChunkFold<I, B, F>
+where
+    I: Iterator,
+    B: Clone,
+    F: FnMut(B, I::Item) -> B,
+{
+    type Item = B;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let f = &mut self.f;
+        let mut len = 0;
+        // Folding the chunk uses the internal iteration of the base iterator.
+        let acc = self
+            .iter
+            .by_ref()
+            .take(self.n)
+            .fold(self.init.clone(), |acc, item| {
+                len += 1;
+                f(acc, item)
+            });
+        match len {
+            0 => None,
+            len if len < self.n && self.partial == PartialChunk::Discard => None,
+            _ => Some(acc),
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let chunks = |len: usize| match self.partial {
+            PartialChunk::Keep => len.div_ceil(self.n),
+            PartialChunk::Discard => len / self.n,
+        };
+        let (lower, upper) = self.iter.size_hint();
+        (chunks(lower), upper.map(chunks))
+    }
+}
+
+impl<I, B, F> ExactSizeIterator for ChunkFold<I, B, F>
+where
+    I: ExactSizeIterator,
+    B: Clone,
+    F: FnMut(B, I::Item) -> B,
+{
+}
+
+impl<I, B, F> FusedIterator for ChunkFold<I, B, F>
+where
+    I: FusedIterator,
+    B: Clone,
+    F: FnMut(B, I::Item) -> B,
+{
+}
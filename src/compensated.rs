@@ -0,0 +1,148 @@
+use crate::Float;
+use core::iter::FusedIterator;
+
+/// A running sum together with its accumulated rounding error.
+#[derive(Clone, Copy, Debug)]
+struct Compensated<T> {
+    /// The naive running sum.
+    sum: T,
+    /// The accumulated rounding error of `sum`.
+    compensation: T,
+}
+
+impl<T: Float> Compensated<T> {
+    /// Adds `x` to the naive sum and its rounding error to the compensation.
+    #[inline]
+    fn add(&mut self, x: T) {
+        let t = self.sum + x;
+        // Neumaier's variant of Kahan summation, which also handles `x` being larger than the sum
+        let error = if self.sum.abs() >= x.abs() {
+            (self.sum - t) + x
+        } else {
+            (x - t) + self.sum
+        };
+        self.compensation = self.compensation + error;
+        self.sum = t;
+    }
+
+    /// Returns the corrected sum.
+    #[inline]
+    fn get(self) -> T {
+        self.sum + self.compensation
+    }
+}
+
+/// An iterator adaptor that yields the running sums of the floating point numbers from the base
+/// iterator, using compensated summation.
+///
+/// This `struct` is created by [`cumsum_compensated()`]. See its documentation for more
+/// information.
+///
+/// [`cumsum_compensated()`]: crate::IterAccumulate::cumsum_compensated
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone, Debug)]
+pub struct CumSumCompensated<I, T> {
+    iter: I,
+    state: Compensated<T>,
+    /// Whether the base iterator has returned [`None`] yet.
+    done: bool,
+}
+
+impl<I, T: Float> CumSumCompensated<I, T> {
+    pub(crate) fn new(iter: I) -> Self {
+        Self {
+            iter,
+            state: Compensated {
+                sum: T::ZERO,
+                compensation: T::ZERO,
+            },
+            done: false,
+        }
+    }
+
+    /// Returns the current corrected running sum.
+    #[inline]
+    pub fn sum(&self) -> T {
+        self.state.get()
+    }
+}
+
+impl<I, T> Iterator for CumSumCompensated<I, T>
+where
+    I: Iterator<Item = T>,
+    T: Float,
+{
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        match self.iter.next() {
+            Some(x) => {
+                self.state.add(x);
+                Some(self.state.get())
+            }
+            None => {
+                self.done = true;
+                None
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            (0, Some(0))
+        } else {
+            self.iter.size_hint()
+        }
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        if self.done {
+            0
+        } else {
+            self.iter.count()
+        }
+    }
+
+    #[inline]
+    fn fold<Acc, G>(self, init: Acc, mut g: G) -> Acc
+    where
+        G: FnMut(Acc, Self::Item) -> Acc,
+    {
+        if self.done {
+            return init;
+        }
+        let mut state = self.state;
+        self.iter.fold(init, move |acc, x| {
+            state.add(x);
+            g(acc, state.get())
+        })
+    }
+}
+
+impl<I, T> ExactSizeIterator for CumSumCompensated<I, T>
+where
+    I: ExactSizeIterator<Item = T>,
+    T: Float,
+{
+    #[inline]
+    fn len(&self) -> usize {
+        if self.done {
+            0
+        } else {
+            self.iter.len()
+        }
+    }
+}
+
+impl<I, T> FusedIterator for CumSumCompensated<I, T>
+where
+    I: Iterator<Item = T>,
+    T: Float,
+{
+}
@@ -0,0 +1,288 @@
+use core::{fmt, iter, iter::FusedIterator};
+
+use crate::Float;
+
+/// An iterator adaptor that yields the number of elements from the base iterator so far that
+/// satisfy a predicate.
+///
+/// This `struct` is created by [`running_count_where()`]. See its documentation for more
+/// information.
+///
+/// [`running_count_where()`]: crate::IterAccumulate::running_count_where
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone)]
+pub struct RunningCountWhere<I, P> {
+    iter: I,
+    count: usize,
+    pred: P,
+    /// Whether the base iterator has returned [`None`] yet.
+    done: bool,
+}
+
+impl<I, P> RunningCountWhere<I, P> {
+    pub(crate) fn new(iter: I, pred: P) -> Self {
+        Self {
+            iter,
+            count: 0,
+            pred,
+            done: false,
+        }
+    }
+
+    /// Returns the number of elements so far that satisfy the predicate.
+    #[inline]
+    pub fn count_so_far(&self) -> usize {
+        self.count
+    }
+}
+
+impl<I, P> fmt::Debug for RunningCountWhere<I, P>
+where
+    I: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("RunningCountWhere")
+            .field("iter", &self.iter)
+            .field("count", &self.count)
+            .field("done", &self.done)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<I, P> Iterator for RunningCountWhere<I, P>
+where
+    I: Iterator,
+    P: FnMut(&I::Item) -> bool,
+{
+    type Item = usize;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        match self.iter.next() {
+            Some(item) => {
+                self.count += usize::from((self.pred)(&item));
+                Some(self.count)
+            }
+            None => {
+                self.done = true;
+                None
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            (0, Some(0))
+        } else {
+            self.iter.size_hint()
+        }
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        if self.done {
+            0
+        } else {
+            self.iter.count()
+        }
+    }
+
+    #[inline]
+    fn fold<Acc, G>(self, init: Acc, mut g: G) -> Acc
+    where
+        G: FnMut(Acc, Self::Item) -> Acc,
+    {
+        if self.done {
+            return init;
+        }
+        let mut pred = self.pred;
+        let mut count = self.count;
+        self.iter.fold(init, move |acc, item| {
+            count += usize::from(pred(&item));
+            g(acc, count)
+        })
+    }
+}
+
+impl<I, P> ExactSizeIterator for RunningCountWhere<I, P>
+where
+    I: ExactSizeIterator,
+    P: FnMut(&I::Item) -> bool,
+{
+    #[inline]
+    fn len(&self) -> usize {
+        if self.done {
+            0
+        } else {
+            self.iter.len()
+        }
+    }
+}
+
+impl<I, P> FusedIterator for RunningCountWhere<I, P>
+where
+    I: Iterator,
+    P: FnMut(&I::Item) -> bool,
+{
+}
+
+/// An iterator adaptor that yields the fraction of elements from the base iterator so far that
+/// satisfy a predicate.
+///
+/// This is created by [`running_fraction_where()`]. See its documentation for more
+/// information.
+///
+/// [`running_fraction_where()`]: crate::IterAccumulate::running_fraction_where
+pub type RunningFractionWhere<I, P, T> =
+    iter::Map<iter::Enumerate<RunningCountWhere<I, P>>, fn((usize, usize)) -> T>;
+
+/// Divides the number of matches by the number of elements seen, given the zero-based index of
+/// the latest element.
+pub(crate) fn fraction<T: Float>((index, count): (usize, usize)) -> T {
+    T::from_usize(count) / T::from_usize(index + 1)
+}
+
+/// An iterator adaptor that yields the length of the current run of consecutive elements from
+/// the base iterator that satisfy a predicate.
+///
+/// This `struct` is created by [`running_streak()`]. See its documentation for more information.
+///
+/// [`running_streak()`]: crate::IterAccumulate::running_streak
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone)]
+pub struct RunningStreak<I, P> {
+    iter: I,
+    streak: usize,
+    longest: usize,
+    pred: P,
+    /// Whether the base iterator has returned [`None`] yet.
+    done: bool,
+}
+
+impl<I, P> RunningStreak<I, P> {
+    pub(crate) fn new(iter: I, pred: P) -> Self {
+        Self {
+            iter,
+            streak: 0,
+            longest: 0,
+            pred,
+            done: false,
+        }
+    }
+
+    /// Returns the length of the current run of elements that satisfy the predicate.
+    #[inline]
+    pub fn streak(&self) -> usize {
+        self.streak
+    }
+
+    /// Returns the length of the longest run of elements so far that satisfy the predicate.
+    #[inline]
+    pub fn longest(&self) -> usize {
+        self.longest
+    }
+}
+
+impl<I, P> fmt::Debug for RunningStreak<I, P>
+where
+    I: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("RunningStreak")
+            .field("iter", &self.iter)
+            .field("streak", &self.streak)
+            .field("longest", &self.longest)
+            .field("done", &self.done)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<I, P> Iterator for RunningStreak<I, P>
+where
+    I: Iterator,
+    P: FnMut(&I::Item) -> bool,
+{
+    type Item = usize;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        match self.iter.next() {
+            Some(item) => {
+                self.streak = if (self.pred)(&item) {
+                    self.streak + 1
+                } else {
+                    0
+                };
+                self.longest = self.longest.max(self.streak);
+                Some(self.streak)
+            }
+            None => {
+                self.done = true;
+                None
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            (0, Some(0))
+        } else {
+            self.iter.size_hint()
+        }
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        if self.done {
+            0
+        } else {
+            self.iter.count()
+        }
+    }
+
+    #[inline]
+    fn fold<Acc, G>(self, init: Acc, mut g: G) -> Acc
+    where
+        G: FnMut(Acc, Self::Item) -> Acc,
+    {
+        if self.done {
+            return init;
+        }
+        let mut pred = self.pred;
+        let mut streak = self.streak;
+        self.iter.fold(init, move |acc, item| {
+            streak = if pred(&item) { streak + 1 } else { 0 };
+            g(acc, streak)
+        })
+    }
+}
+
+impl<I, P> ExactSizeIterator for RunningStreak<I, P>
+where
+    I: ExactSizeIterator,
+    P: FnMut(&I::Item) -> bool,
+{
+    #[inline]
+    fn len(&self) -> usize {
+        if self.done {
+            0
+        } else {
+            self.iter.len()
+        }
+    }
+}
+
+impl<I, P> FusedIterator for RunningStreak<I, P>
+where
+    I: Iterator,
+    P: FnMut(&I::Item) -> bool,
+{
+}
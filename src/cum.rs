@@ -0,0 +1,116 @@
+use crate::{Accumulate, AccumulateFirst, AccumulateOutput, Overflow, TryAccumulate};
+use core::{iter::Map, ops::Mul};
+
+/// An iterator adaptor that yields the running sums of the elements from the base iterator.
+///
+/// This is created by [`cumsum()`] and [`cumsum_from()`]. See their documentation for more
+/// information.
+///
+/// [`cumsum()`]: crate::IterAccumulate::cumsum
+/// [`cumsum_from()`]: crate::IterAccumulate::cumsum_from
+pub type CumSum<I, B> = Accumulate<I, B, fn(B, <I as Iterator>::Item) -> B>;
+
+/// An iterator adaptor that yields the running products of the elements from the base iterator.
+///
+/// This is created by [`cumprod()`], [`cumprod_from()`] and [`cumprod_from_ref()`]. See their
+/// documentation for more information.
+///
+/// [`cumprod()`]: crate::IterAccumulate::cumprod
+/// [`cumprod_from()`]: crate::IterAccumulate::cumprod_from
+/// [`cumprod_from_ref()`]: crate::IterAccumulate::cumprod_from_ref
+pub type CumProd<I, B> = Accumulate<I, B, fn(B, <I as Iterator>::Item) -> B>;
+
+/// Multiplies the accumulated value by an element, both by reference.
+pub(crate) fn mul_ref<B, T>(acc: B, item: T) -> B
+where
+    for<'a> &'a B: Mul<&'a T, Output = B>,
+{
+    &acc * &item
+}
+
+/// An iterator adaptor that yields the running maximum or minimum of the elements from the base
+/// iterator.
+///
+/// This is created by [`cummax()`] and [`cummin()`]. See their documentation for more
+/// information.
+///
+/// [`cummax()`]: crate::IterAccumulate::cummax
+/// [`cummin()`]: crate::IterAccumulate::cummin
+pub type CumExtremum<I> =
+    AccumulateFirst<I, fn(<I as Iterator>::Item, <I as Iterator>::Item) -> <I as Iterator>::Item>;
+
+/// An iterator adaptor that yields the running minimum and maximum of the elements from the base
+/// iterator as a pair.
+///
+/// This is created by [`running_extrema()`](crate::IterAccumulate::running_extrema). See its
+/// documentation for more information.
+pub type RunningExtrema<I> = AccumulateFirst<
+    Map<I, fn(<I as Iterator>::Item) -> (<I as Iterator>::Item, <I as Iterator>::Item)>,
+    fn(
+        (<I as Iterator>::Item, <I as Iterator>::Item),
+        (<I as Iterator>::Item, <I as Iterator>::Item),
+    ) -> (<I as Iterator>::Item, <I as Iterator>::Item),
+>;
+
+/// An iterator adaptor that yields the running greatest common divisors of the integers from the
+/// base iterator.
+///
+/// This is created by [`running_gcd()`](crate::IterAccumulate::running_gcd). See its
+/// documentation for more information.
+pub type RunningGcd<I> = Accumulate<
+    I,
+    <I as Iterator>::Item,
+    fn(<I as Iterator>::Item, <I as Iterator>::Item) -> <I as Iterator>::Item,
+>;
+
+/// An iterator adaptor that yields the running bitwise combination of the integers from the base
+/// iterator.
+///
+/// This is created by [`cumxor()`], [`cumand()`] and [`cumor()`]. See their documentation for
+/// more information.
+///
+/// [`cumxor()`]: crate::IterAccumulate::cumxor
+/// [`cumand()`]: crate::IterAccumulate::cumand
+/// [`cumor()`]: crate::IterAccumulate::cumor
+pub type CumBitwise<I> = Accumulate<
+    I,
+    <I as Iterator>::Item,
+    fn(<I as Iterator>::Item, <I as Iterator>::Item) -> <I as Iterator>::Item,
+>;
+
+/// Duplicates an element, to start a `(min, max)` pair.
+pub(crate) fn pair<T: Clone>(x: T) -> (T, T) {
+    (x.clone(), x)
+}
+
+/// An iterator adaptor that yields the running sums, products or least common multiples of the
+/// integers from the base iterator, and stops when they overflow.
+///
+/// This is created by [`checked_cumsum()`], [`checked_cumprod()`] and [`running_lcm()`]. See
+/// their documentation for more information.
+///
+/// [`checked_cumsum()`]: crate::IterAccumulate::checked_cumsum
+/// [`checked_cumprod()`]: crate::IterAccumulate::checked_cumprod
+/// [`running_lcm()`]: crate::IterAccumulate::running_lcm
+pub type CheckedCum<I> = TryAccumulate<
+    I,
+    <I as Iterator>::Item,
+    fn(<I as Iterator>::Item, <I as Iterator>::Item) -> Result<<I as Iterator>::Item, Overflow>,
+>;
+
+/// An iterator adaptor that yields the running sums or products of the integers from the base
+/// iterator, together with whether each step overflowed.
+///
+/// This is created by [`overflowing_cumsum()`] and [`overflowing_cumprod()`]. See their
+/// documentation for more information.
+///
+/// [`overflowing_cumsum()`]: crate::IterAccumulate::overflowing_cumsum
+/// [`overflowing_cumprod()`]: crate::IterAccumulate::overflowing_cumprod
+pub type OverflowingCum<I> = AccumulateOutput<
+    I,
+    <I as Iterator>::Item,
+    fn(
+        <I as Iterator>::Item,
+        <I as Iterator>::Item,
+    ) -> (<I as Iterator>::Item, (<I as Iterator>::Item, bool)),
+>;
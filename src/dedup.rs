@@ -0,0 +1,80 @@
+use crate::Accumulate;
+use core::{fmt, iter::FusedIterator};
+
+/// An iterator adaptor that accumulates the elements from the base iterator using the provided
+/// closure, and only yields the accumulated value when it changes.
+///
+/// This `struct` is created by [`dedup_acc()`]. See its documentation for more information.
+///
+/// [`dedup_acc()`]: crate::IterAccumulate::dedup_acc
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone)]
+pub struct DedupAcc<I, B, F> {
+    inner: Accumulate<I, B, F>,
+    /// The value that was yielded last.
+    last: Option<B>,
+}
+
+impl<I, B, F> DedupAcc<I, B, F>
+where
+    B: Clone,
+{
+    pub(crate) fn new(iter: I, init: B, f: F) -> Self {
+        Self {
+            inner: Accumulate::new(iter, init, f),
+            last: None,
+        }
+    }
+}
+
+impl<I, B, F> fmt::Debug for DedupAcc<I, B, F>
+where
+    I: fmt::Debug,
+    B: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("DedupAcc")
+            .field("inner", &self.inner)
+            .field("last", &self.last)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<I, B, F> Iterator for DedupAcc<I, B, F>
+where
+    I: Iterator,
+    B: Clone + PartialEq,
+    F: FnMut(B, I::Item) -> B,
+{
+    type Item = B;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let acc = self.inner.next()?;
+            if self.last.as_ref() != Some(&acc) {
+                self.last = Some(acc.clone());
+                return Some(acc);
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.inner.size_hint();
+        if self.last.is_some() {
+            (0, upper)
+        } else {
+            // The first accumulated value is always yielded
+            (lower.min(1), upper)
+        }
+    }
+}
+
+impl<I, B, F> FusedIterator for DedupAcc<I, B, F>
+where
+    I: Iterator,
+    B: Clone + PartialEq,
+    F: FnMut(B, I::Item) -> B,
+{
+}
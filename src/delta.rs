@@ -0,0 +1,167 @@
+use core::{
+    fmt,
+    iter::FusedIterator,
+    ops::{Add, Sub},
+};
+
+/// An iterator adaptor that yields the differences between consecutive elements from the base
+/// iterator.
+///
+/// This `struct` is created by [`deltas()`]. See its documentation for more information.
+///
+/// [`deltas()`]: crate::IterAccumulate::deltas
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone)]
+pub struct Deltas<I: Iterator> {
+    iter: I,
+    /// The previous element, or [`None`] before the first element.
+    prev: Option<I::Item>,
+}
+
+impl<I: Iterator> Deltas<I> {
+    pub(crate) fn new(iter: I) -> Self {
+        Self { iter, prev: None }
+    }
+}
+
+impl<I> fmt::Debug for Deltas<I>
+where
+    I: Iterator + fmt::Debug,
+    I::Item: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Deltas")
+            .field("iter", &self.iter)
+            .field("prev", &self.prev)
+            .finish()
+    }
+}
+
+impl<I, D> Iterator for Deltas<I>
+where
+    I: Iterator,
+    I::Item: Clone + Sub<Output = D>,
+{
+    type Item = D;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.prev.is_none() {
+            self.prev = Some(self.iter.next()?);
+        }
+        let item = self.iter.next()?;
+        let prev = self.prev.replace(item.clone())?;
+        Some(item - prev)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.iter.size_hint();
+        if self.prev.is_some() {
+            (lower, upper)
+        } else {
+            (
+                lower.saturating_sub(1),
+                upper.map(|upper| upper.saturating_sub(1)),
+            )
+        }
+    }
+}
+
+impl<I, D> ExactSizeIterator for Deltas<I>
+where
+    I: ExactSizeIterator,
+    I::Item: Clone + Sub<Output = D>,
+{
+}
+
+impl<I, D> FusedIterator for Deltas<I>
+where
+    I: FusedIterator,
+    I::Item: Clone + Sub<Output = D>,
+{
+}
+
+/// An iterator adaptor that yields an initial value, followed by the running sums of the
+/// differences from the base iterator added to it.
+///
+/// This `struct` is created by [`undeltas()`]. See its documentation for more information.
+///
+/// [`undeltas()`]: crate::IterAccumulate::undeltas
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone)]
+pub struct Undeltas<I, B> {
+    iter: I,
+    /// The current value. This is only [`None`] while it is moved into the addition, or if the
+    /// addition panicked.
+    acc: Option<B>,
+    /// Whether the initial value has been yielded yet.
+    started: bool,
+}
+
+impl<I, B> Undeltas<I, B> {
+    pub(crate) fn new(iter: I, init: B) -> Self {
+        Self {
+            iter,
+            acc: Some(init),
+            started: false,
+        }
+    }
+}
+
+impl<I, B> fmt::Debug for Undeltas<I, B>
+where
+    I: fmt::Debug,
+    B: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Undeltas")
+            .field("iter", &self.iter)
+            .field("acc", &self.acc)
+            .field("started", &self.started)
+            .finish()
+    }
+}
+
+impl<I, B> Iterator for Undeltas<I, B>
+where
+    I: Iterator,
+    B: Add<I::Item, Output = B> + Clone,
+{
+    type Item = B;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if !self.started {
+            self.started = true;
+            return self.acc.clone();
+        }
+        let delta = self.iter.next()?;
+        let acc = self.acc.take().expect(crate::POISONED) + delta;
+        Some(self.acc.insert(acc).clone())
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.iter.size_hint();
+        let init = usize::from(!self.started);
+        (
+            lower.saturating_add(init),
+            upper.and_then(|upper| upper.checked_add(init)),
+        )
+    }
+}
+
+impl<I, B> ExactSizeIterator for Undeltas<I, B>
+where
+    I: ExactSizeIterator,
+    B: Add<I::Item, Output = B> + Clone,
+{
+}
+
+impl<I, B> FusedIterator for Undeltas<I, B>
+where
+    I: FusedIterator,
+    B: Add<I::Item, Output = B> + Clone,
+{
+}
@@ -0,0 +1,97 @@
+use crate::Accumulate;
+use core::{fmt, iter::FusedIterator};
+
+/// An iterator adaptor that accumulates all elements from the base iterator using the provided
+/// closure, but only yields every `k`-th accumulated value.
+///
+/// This `struct` is created by [`accumulate_every()`]. See its documentation for more
+/// information.
+///
+/// [`accumulate_every()`]: crate::IterAccumulate::accumulate_every
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone)]
+pub struct AccumulateEvery<I, B, F> {
+    inner: Accumulate<I, B, F>,
+    /// The number of elements to skip before each yielded one, i.e. `k - 1`.
+    skip: usize,
+}
+
+impl<I, B, F> AccumulateEvery<I, B, F>
+where
+    B: Clone,
+{
+    pub(crate) fn new(iter: I, k: usize, init: B, f: F) -> Self {
+        assert!(k != 0, "`k` must not be zero");
+        Self {
+            inner: Accumulate::new(iter, init, f),
+            skip: k - 1,
+        }
+    }
+}
+
+impl<I, B, F> AccumulateEvery<I, B, F> {
+    /// Returns a reference to the current accumulated value, which includes the elements that
+    /// were accumulated but not yielded.
+    #[inline]
+    pub fn acc(&self) -> &B {
+        self.inner.acc()
+    }
+
+    /// Consumes the iterator adaptor and returns the current accumulated value, which includes
+    /// the elements that were accumulated but not yielded.
+    #[inline]
+    pub fn into_acc(self) -> B {
+        self.inner.into_acc()
+    }
+}
+
+impl<I, B, F> fmt::Debug for AccumulateEvery<I, B, F>
+where
+    I: fmt::Debug,
+    B: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("AccumulateEvery")
+            .field("inner", &self.inner)
+            .field("skip", &self.skip)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<I, B, F> Iterator for AccumulateEvery<I, B, F>
+where
+    I: Iterator,
+    B: Clone,
+    F: FnMut(B, I::Item) -> B,
+{
+    type Item = B;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        // `nth()` still accumulates the skipped elements
+        self.inner.nth(self.skip)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let k = self.skip + 1;
+        let (lower, upper) = self.inner.size_hint();
+        (lower / k, upper.map(|upper| upper / k))
+    }
+}
+
+impl<I, B, F> ExactSizeIterator for AccumulateEvery<I, B, F>
+where
+    I: ExactSizeIterator,
+    B: Clone,
+    F: FnMut(B, I::Item) -> B,
+{
+}
+
+impl<I, B, F> FusedIterator for AccumulateEvery<I, B, F>
+where
+    I: Iterator,
+    B: Clone,
+    F: FnMut(B, I::Item) -> B,
+{
+}
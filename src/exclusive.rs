@@ -0,0 +1,133 @@
+use crate::POISONED;
+use core::{fmt, iter::FusedIterator};
+
+/// An iterator adaptor that accumulates the elements from the base iterator using the provided
+/// closure, and yields the accumulated value before each element is accumulated.
+///
+/// This `struct` is created by [`exclusive_accumulate()`]. See its documentation for more
+/// information.
+///
+/// [`exclusive_accumulate()`]: crate::IterAccumulate::exclusive_accumulate
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone)]
+pub struct ExclusiveAccumulate<I, B, F> {
+    iter: I,
+    /// The accumulated value. This is only [`None`] while it is moved into the closure, or if the
+    /// closure panicked.
+    acc: Option<B>,
+    f: F,
+    /// Whether the base iterator has returned [`None`] yet.
+    done: bool,
+}
+
+impl<I, B, F> ExclusiveAccumulate<I, B, F> {
+    pub(crate) fn new(iter: I, acc: B, f: F) -> Self {
+        Self {
+            iter,
+            acc: Some(acc),
+            f,
+            done: false,
+        }
+    }
+
+    /// Returns a reference to the current accumulated value, which includes all elements that
+    /// have been yielded so far.
+    #[inline]
+    pub fn acc(&self) -> &B {
+        self.acc.as_ref().expect(POISONED)
+    }
+
+    /// Consumes the iterator adaptor and returns the current accumulated value, which includes
+    /// all elements that have been yielded so far.
+    ///
+    /// Once the iterator is exhausted, this is the accumulation of all elements, which is never
+    /// yielded itself.
+    #[inline]
+    pub fn into_acc(self) -> B {
+        self.acc.expect(POISONED)
+    }
+}
+
+impl<I, B, F> fmt::Debug for ExclusiveAccumulate<I, B, F>
+where
+    I: fmt::Debug,
+    B: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("ExclusiveAccumulate")
+            .field("iter", &self.iter)
+            .field("acc", &self.acc)
+            .field("done", &self.done)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<I, B, F> Iterator for ExclusiveAccumulate<I, B, F>
+where
+    I: Iterator,
+    B: Clone,
+    F: FnMut(B, I::Item) -> B,
+{
+    type Item = B;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        match self.iter.next() {
+            Some(item) => {
+                let acc = self.acc.take().expect(POISONED);
+                let yielded = acc.clone();
+                self.acc = Some((self.f)(acc, item));
+                Some(yielded)
+            }
+            None => {
+                self.done = true;
+                None
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            (0, Some(0))
+        } else {
+            self.iter.size_hint()
+        }
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        if self.done {
+            0
+        } else {
+            self.iter.count()
+        }
+    }
+}
+
+impl<I, B, F> ExactSizeIterator for ExclusiveAccumulate<I, B, F>
+where
+    I: ExactSizeIterator,
+    B: Clone,
+    F: FnMut(B, I::Item) -> B,
+{
+    #[inline]
+    fn len(&self) -> usize {
+        if self.done {
+            0
+        } else {
+            self.iter.len()
+        }
+    }
+}
+
+impl<I, B, F> FusedIterator for ExclusiveAccumulate<I, B, F>
+where
+    I: Iterator,
+    B: Clone,
+    F: FnMut(B, I::Item) -> B,
+{
+}
@@ -0,0 +1,322 @@
+use crate::POISONED;
+use core::{fmt, iter::FusedIterator};
+
+/// An iterator adaptor that accumulates the elements from the base iterator using the provided
+/// fallible closure.
+///
+/// This `struct` is created by [`try_accumulate()`]. See its documentation for more information.
+///
+/// [`try_accumulate()`]: crate::IterAccumulate::try_accumulate
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone)]
+pub struct TryAccumulate<I, B, F> {
+    iter: I,
+    /// The accumulated value. This is [`None`] once the iterator has finished, either because the
+    /// base iterator returned [`None`] or because the closure returned an error.
+    acc: Option<B>,
+    f: F,
+}
+
+impl<I, B, F> TryAccumulate<I, B, F> {
+    pub(crate) fn new(iter: I, acc: B, f: F) -> Self {
+        Self {
+            iter,
+            acc: Some(acc),
+            f,
+        }
+    }
+}
+
+impl<I, B, F> fmt::Debug for TryAccumulate<I, B, F>
+where
+    I: fmt::Debug,
+    B: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("TryAccumulate")
+            .field("iter", &self.iter)
+            .field("acc", &self.acc)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<I, B, E, F> Iterator for TryAccumulate<I, B, F>
+where
+    I: Iterator,
+    B: Clone,
+    F: FnMut(B, I::Item) -> Result<B, E>,
+{
+    type Item = Result<B, E>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let acc = self.acc.take()?;
+        let item = self.iter.next()?;
+        match (self.f)(acc, item) {
+            Ok(acc) => Some(Ok(self.acc.insert(acc).clone())),
+            Err(err) => Some(Err(err)),
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.acc.is_none() {
+            (0, Some(0))
+        } else {
+            let (_, upper) = self.iter.size_hint();
+            (0, upper)
+        }
+    }
+}
+
+impl<I, B, E, F> FusedIterator for TryAccumulate<I, B, F>
+where
+    I: Iterator,
+    B: Clone,
+    F: FnMut(B, I::Item) -> Result<B, E>,
+{
+}
+
+/// An iterator adaptor that accumulates the [`Ok`] values from a base iterator of [`Result`]s
+/// using the provided closure, and stops at the first [`Err`].
+///
+/// This `struct` is created by [`accumulate_ok()`]. See its documentation for more information.
+///
+/// [`accumulate_ok()`]: crate::IterAccumulate::accumulate_ok
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone)]
+pub struct AccumulateOk<I, B, F> {
+    iter: I,
+    /// The accumulated value. This is [`None`] once the iterator has finished, either because the
+    /// base iterator returned [`None`] or because it yielded an error.
+    acc: Option<B>,
+    f: F,
+}
+
+impl<I, B, F> AccumulateOk<I, B, F> {
+    pub(crate) fn new(iter: I, acc: B, f: F) -> Self {
+        Self {
+            iter,
+            acc: Some(acc),
+            f,
+        }
+    }
+}
+
+impl<I, B, F> fmt::Debug for AccumulateOk<I, B, F>
+where
+    I: fmt::Debug,
+    B: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("AccumulateOk")
+            .field("iter", &self.iter)
+            .field("acc", &self.acc)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<I, T, E, B, F> Iterator for AccumulateOk<I, B, F>
+where
+    I: Iterator<Item = Result<T, E>>,
+    B: Clone,
+    F: FnMut(B, T) -> B,
+{
+    type Item = Result<B, E>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let acc = self.acc.take()?;
+        match self.iter.next()? {
+            Ok(item) => {
+                let acc = (self.f)(acc, item);
+                Some(Ok(self.acc.insert(acc).clone()))
+            }
+            Err(err) => Some(Err(err)),
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.acc.is_none() {
+            (0, Some(0))
+        } else {
+            let (_, upper) = self.iter.size_hint();
+            (0, upper)
+        }
+    }
+}
+
+impl<I, T, E, B, F> FusedIterator for AccumulateOk<I, B, F>
+where
+    I: Iterator<Item = Result<T, E>>,
+    B: Clone,
+    F: FnMut(B, T) -> B,
+{
+}
+
+/// An iterator adaptor that accumulates the [`Some`] values from a base iterator of [`Option`]s
+/// using the provided closure, and stops at the first [`None`].
+///
+/// This `struct` is created by [`accumulate_some()`]. See its documentation for more information.
+///
+/// [`accumulate_some()`]: crate::IterAccumulate::accumulate_some
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone)]
+pub struct AccumulateSome<I, B, F> {
+    iter: I,
+    /// The accumulated value. This is [`None`] once the iterator has finished.
+    acc: Option<B>,
+    f: F,
+}
+
+impl<I, B, F> AccumulateSome<I, B, F> {
+    pub(crate) fn new(iter: I, acc: B, f: F) -> Self {
+        Self {
+            iter,
+            acc: Some(acc),
+            f,
+        }
+    }
+}
+
+impl<I, B, F> fmt::Debug for AccumulateSome<I, B, F>
+where
+    I: fmt::Debug,
+    B: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("AccumulateSome")
+            .field("iter", &self.iter)
+            .field("acc", &self.acc)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<I, T, B, F> Iterator for AccumulateSome<I, B, F>
+where
+    I: Iterator<Item = Option<T>>,
+    B: Clone,
+    F: FnMut(B, T) -> B,
+{
+    type Item = B;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let acc = self.acc.take()?;
+        let acc = (self.f)(acc, self.iter.next()??);
+        Some(self.acc.insert(acc).clone())
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.acc.is_none() {
+            (0, Some(0))
+        } else {
+            let (_, upper) = self.iter.size_hint();
+            (0, upper)
+        }
+    }
+}
+
+impl<I, T, B, F> FusedIterator for AccumulateSome<I, B, F>
+where
+    I: Iterator<Item = Option<T>>,
+    B: Clone,
+    F: FnMut(B, T) -> B,
+{
+}
+
+/// An iterator adaptor that accumulates the [`Some`] values from a base iterator of [`Option`]s
+/// using the provided closure, and passes [`None`] values through.
+///
+/// This `struct` is created by [`accumulate_some_or_none()`]. See its documentation for more
+/// information.
+///
+/// [`accumulate_some_or_none()`]: crate::IterAccumulate::accumulate_some_or_none
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone)]
+pub struct AccumulateSomeOrNone<I, B, F> {
+    iter: I,
+    acc: Option<B>,
+    f: F,
+    /// Whether the base iterator has returned [`None`] yet.
+    done: bool,
+}
+
+impl<I, B, F> AccumulateSomeOrNone<I, B, F> {
+    pub(crate) fn new(iter: I, acc: B, f: F) -> Self {
+        Self {
+            iter,
+            acc: Some(acc),
+            f,
+            done: false,
+        }
+    }
+}
+
+impl<I, B, F> fmt::Debug for AccumulateSomeOrNone<I, B, F>
+where
+    I: fmt::Debug,
+    B: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("AccumulateSomeOrNone")
+            .field("iter", &self.iter)
+            .field("acc", &self.acc)
+            .field("done", &self.done)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<I, T, B, F> Iterator for AccumulateSomeOrNone<I, B, F>
+where
+    I: Iterator<Item = Option<T>>,
+    B: Clone,
+    F: FnMut(B, T) -> B,
+{
+    type Item = Option<B>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        match self.iter.next() {
+            Some(Some(item)) => {
+                let acc = (self.f)(self.acc.take().expect(POISONED), item);
+                Some(Some(self.acc.insert(acc).clone()))
+            }
+            Some(None) => Some(None),
+            None => {
+                self.done = true;
+                None
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            (0, Some(0))
+        } else {
+            self.iter.size_hint()
+        }
+    }
+}
+
+impl<I, T, B, F> ExactSizeIterator for AccumulateSomeOrNone<I, B, F>
+where
+    I: ExactSizeIterator<Item = Option<T>>,
+    B: Clone,
+    F: FnMut(B, T) -> B,
+{
+}
+
+impl<I, T, B, F> FusedIterator for AccumulateSomeOrNone<I, B, F>
+where
+    I: Iterator<Item = Option<T>>,
+    B: Clone,
+    F: FnMut(B, T) -> B,
+{
+}
@@ -0,0 +1,103 @@
+use crate::{AccumulateOutput, Float};
+
+/// An iterator adaptor that yields the cumulative compounded returns of the per-period returns
+/// from the base iterator.
+///
+/// This is created by [`cumulative_returns()`](crate::IterAccumulate::cumulative_returns). See
+/// its documentation for more information.
+pub type CumulativeReturns<I, T> = AccumulateOutput<I, T, fn(T, T) -> (T, T)>;
+
+/// Compounds the growth factor `wealth` by the return `r`, and returns the new growth factor and
+/// the cumulative return.
+pub(crate) fn compound<T: Float>(wealth: T, r: T) -> (T, T) {
+    let wealth = wealth * (T::ONE + r);
+    (wealth, wealth - T::ONE)
+}
+
+/// The state of a running maximum drawdown, i.e. the largest relative decline from a peak to a
+/// subsequent trough.
+///
+/// This is the accumulated state of [`RunningMaxDrawdown`], see
+/// [`running_max_drawdown()`](crate::IterAccumulate::running_max_drawdown).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Drawdown<T> {
+    /// The highest value so far, or [`None`] if there are none yet.
+    peak: Option<T>,
+    /// The current drawdown from `peak`.
+    drawdown: T,
+    /// The peak and trough of the maximum drawdown, or [`None`] if there has been no decline.
+    max: Option<(T, T)>,
+    max_drawdown: T,
+}
+
+impl<T: Float> Drawdown<T> {
+    /// Creates the state of a drawdown of no values.
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            peak: None,
+            drawdown: T::ZERO,
+            max: None,
+            max_drawdown: T::ZERO,
+        }
+    }
+
+    /// Adds the value `x`, like a price or portfolio value, which has to be positive.
+    #[inline]
+    pub fn push(&mut self, x: T) {
+        let peak = match self.peak {
+            Some(peak) if peak >= x => peak,
+            _ => {
+                self.peak = Some(x);
+                self.drawdown = T::ZERO;
+                return;
+            }
+        };
+        self.drawdown = (peak - x) / peak;
+        if self.drawdown > self.max_drawdown {
+            self.max_drawdown = self.drawdown;
+            self.max = Some((peak, x));
+        }
+    }
+
+    /// Returns the highest value that has been added, or [`None`] if there are none.
+    #[inline]
+    pub fn peak(&self) -> Option<T> {
+        self.peak
+    }
+
+    /// Returns the current drawdown, i.e. the relative decline of the last value from the
+    /// [peak](Self::peak), between zero and one.
+    #[inline]
+    pub fn drawdown(&self) -> T {
+        self.drawdown
+    }
+
+    /// Returns the maximum drawdown so far, between zero and one.
+    #[inline]
+    pub fn max_drawdown(&self) -> T {
+        self.max_drawdown
+    }
+
+    /// Returns the peak and trough values of the maximum drawdown as a `(peak, trough)` pair, or
+    /// [`None`] if the values have never declined.
+    #[inline]
+    pub fn peak_trough(&self) -> Option<(T, T)> {
+        self.max
+    }
+}
+
+impl<T: Float> Default for Drawdown<T> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// An iterator adaptor that yields the running maximum drawdown of the values from the base
+/// iterator.
+///
+/// This is created by [`running_max_drawdown()`](crate::IterAccumulate::running_max_drawdown).
+/// See its documentation for more information.
+pub type RunningMaxDrawdown<I, T> =
+    AccumulateOutput<I, Drawdown<T>, fn(Drawdown<T>, T) -> (Drawdown<T>, T)>;
@@ -0,0 +1,149 @@
+use crate::POISONED;
+use core::{fmt, iter::FusedIterator};
+
+/// An iterator adaptor that accumulates the elements from the base iterator using the provided
+/// closure, starting with the first element.
+///
+/// This `struct` is created by [`accumulate_first()`]. See its documentation for more
+/// information.
+///
+/// [`accumulate_first()`]: crate::IterAccumulate::accumulate_first
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone)]
+pub struct AccumulateFirst<I: Iterator, F> {
+    iter: I,
+    /// The accumulated value. This is [`None`] until the first element has been yielded, and
+    /// after that only while it is moved into the closure, or if the closure panicked.
+    acc: Option<I::Item>,
+    f: F,
+    /// Whether the first element has been yielded yet.
+    started: bool,
+    /// Whether the base iterator has returned [`None`] yet.
+    done: bool,
+}
+
+impl<I: Iterator, F> AccumulateFirst<I, F> {
+    pub(crate) fn new(iter: I, f: F) -> Self {
+        Self {
+            iter,
+            acc: None,
+            f,
+            started: false,
+            done: false,
+        }
+    }
+}
+
+impl<I, F> fmt::Debug for AccumulateFirst<I, F>
+where
+    I: Iterator + fmt::Debug,
+    I::Item: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("AccumulateFirst")
+            .field("iter", &self.iter)
+            .field("acc", &self.acc)
+            .field("started", &self.started)
+            .field("done", &self.done)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<I, F> Iterator for AccumulateFirst<I, F>
+where
+    I: Iterator,
+    I::Item: Clone,
+    F: FnMut(I::Item, I::Item) -> I::Item,
+{
+    type Item = I::Item;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        match self.iter.next() {
+            Some(item) => {
+                let acc = if self.started {
+                    (self.f)(self.acc.take().expect(POISONED), item)
+                } else {
+                    self.started = true;
+                    item
+                };
+                Some(self.acc.insert(acc).clone())
+            }
+            None => {
+                self.done = true;
+                None
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            (0, Some(0))
+        } else {
+            self.iter.size_hint()
+        }
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        if self.done {
+            0
+        } else {
+            self.iter.count()
+        }
+    }
+
+    #[inline]
+    fn fold<Acc, G>(mut self, init: Acc, mut g: G) -> Acc
+    where
+        G: FnMut(Acc, Self::Item) -> Acc,
+    {
+        if self.done {
+            return init;
+        }
+        let (init, state) = if self.started {
+            (init, self.acc.expect(POISONED))
+        } else {
+            // The first element is yielded as is
+            match self.iter.next() {
+                Some(item) => (g(init, item.clone()), item),
+                None => return init,
+            }
+        };
+        let mut f = self.f;
+        self.iter
+            .fold((init, state), move |(acc, state), item| {
+                let state = f(state, item);
+                (g(acc, state.clone()), state)
+            })
+            .0
+    }
+}
+
+impl<I, F> ExactSizeIterator for AccumulateFirst<I, F>
+where
+    I: ExactSizeIterator,
+    I::Item: Clone,
+    F: FnMut(I::Item, I::Item) -> I::Item,
+{
+    #[inline]
+    fn len(&self) -> usize {
+        if self.done {
+            0
+        } else {
+            self.iter.len()
+        }
+    }
+}
+
+impl<I, F> FusedIterator for AccumulateFirst<I, F>
+where
+    I: Iterator,
+    I::Item: Clone,
+    F: FnMut(I::Item, I::Item) -> I::Item,
+{
+}
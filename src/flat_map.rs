@@ -0,0 +1,159 @@
+use crate::POISONED;
+use core::{fmt, iter::FusedIterator};
+
+/// An iterator adaptor that threads an accumulated state through the elements from the base
+/// iterator, and flattens the outputs produced for each element.
+///
+/// This `struct` is created by [`accumulate_flat_map()`]. See its documentation for more
+/// information.
+///
+/// [`accumulate_flat_map()`]: crate::IterAccumulate::accumulate_flat_map
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+pub struct AccumulateFlatMap<I, B, F, U>
+where
+    U: IntoIterator,
+{
+    iter: I,
+    /// The accumulated state. This is only [`None`] while it is moved into the closure, or if
+    /// the closure panicked.
+    acc: Option<B>,
+    f: F,
+    /// The outputs of the last element that have not been yielded yet.
+    front: Option<U::IntoIter>,
+    /// Whether the base iterator has returned [`None`] yet.
+    done: bool,
+}
+
+impl<I, B, F, U> AccumulateFlatMap<I, B, F, U>
+where
+    U: IntoIterator,
+{
+    pub(crate) fn new(iter: I, acc: B, f: F) -> Self {
+        Self {
+            iter,
+            acc: Some(acc),
+            f,
+            front: None,
+            done: false,
+        }
+    }
+
+    /// Returns a reference to the current accumulated state.
+    #[inline]
+    pub fn acc(&self) -> &B {
+        self.acc.as_ref().expect(POISONED)
+    }
+
+    /// Consumes the iterator adaptor and returns the current accumulated state.
+    #[inline]
+    pub fn into_acc(self) -> B {
+        self.acc.expect(POISONED)
+    }
+}
+
+impl<I, B, F, U> Clone for AccumulateFlatMap<I, B, F, U>
+where
+    I: Clone,
+    B: Clone,
+    F: Clone,
+    U: IntoIterator,
+    U::IntoIter: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            iter: self.iter.clone(),
+            acc: self.acc.clone(),
+            f: self.f.clone(),
+            front: self.front.clone(),
+            done: self.done,
+        }
+    }
+}
+
+impl<I, B, F, U> fmt::Debug for AccumulateFlatMap<I, B, F, U>
+where
+    I: fmt::Debug,
+    B: fmt::Debug,
+    U: IntoIterator,
+    U::IntoIter: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("AccumulateFlatMap")
+            .field("iter", &self.iter)
+            .field("acc", &self.acc)
+            .field("front", &self.front)
+            .field("done", &self.done)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<I, B, F, U> Iterator for AccumulateFlatMap<I, B, F, U>
+where
+    I: Iterator,
+    F: FnMut(B, I::Item) -> (B, U),
+    U: IntoIterator,
+{
+    type Item = U::Item;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(front) = &mut self.front {
+                match front.next() {
+                    Some(output) => return Some(output),
+                    None => self.front = None,
+                }
+            }
+            if self.done {
+                return None;
+            }
+            match self.iter.next() {
+                Some(item) => {
+                    let (acc, outputs) = (self.f)(self.acc.take().expect(POISONED), item);
+                    self.acc = Some(acc);
+                    self.front = Some(outputs.into_iter());
+                }
+                None => {
+                    self.done = true;
+                    return None;
+                }
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.front.as_ref().map_or((0, Some(0)), |f| f.size_hint());
+        let rest_empty = self.done || self.iter.size_hint().1 == Some(0);
+        (lower, upper.filter(|_| rest_empty))
+    }
+
+    #[inline]
+    fn fold<Acc, G>(self, mut init: Acc, mut g: G) -> Acc
+    where
+        G: FnMut(Acc, Self::Item) -> Acc,
+    {
+        if let Some(front) = self.front {
+            init = front.fold(init, &mut g);
+        }
+        if self.done {
+            return init;
+        }
+        let mut f = self.f;
+        let state = self.acc.expect(POISONED);
+        self.iter
+            .fold((init, state), move |(acc, state), item| {
+                let (state, outputs) = f(state, item);
+                (outputs.into_iter().fold(acc, &mut g), state)
+            })
+            .0
+    }
+}
+
+impl<I, B, F, U> FusedIterator for AccumulateFlatMap<I, B, F, U>
+where
+    I: Iterator,
+    F: FnMut(B, I::Item) -> (B, U),
+    U: IntoIterator,
+{
+}
@@ -0,0 +1,186 @@
+use core::{
+    cmp::Ordering,
+    ops::{Add, Div, Mul, Neg, Sub},
+};
+
+mod sealed {
+    pub trait Sealed {}
+
+    impl Sealed for f32 {}
+    impl Sealed for f64 {}
+}
+
+/// A floating point type, i.e. [`f32`] or [`f64`].
+///
+/// This trait is sealed and cannot be implemented outside of this crate. It only exists to make
+/// the floating point adaptors generic over both types.
+pub trait Float:
+    Copy
+    + PartialOrd
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Neg<Output = Self>
+    + sealed::Sealed
+{
+    /// The additive identity, `0.0`.
+    const ZERO: Self;
+
+    /// The multiplicative identity, `1.0`.
+    const ONE: Self;
+
+    /// Not a Number (NaN).
+    const NAN: Self;
+
+    /// Negative infinity.
+    const NEG_INFINITY: Self;
+
+    /// Converts a count to this type, rounding to the nearest representable value.
+    fn from_usize(n: usize) -> Self;
+
+    /// Returns the absolute value of this number.
+    fn abs(self) -> Self;
+
+    /// Returns `true` if this value is NaN.
+    fn is_nan(self) -> bool;
+
+    /// Returns the maximum of two numbers, ignoring NaN.
+    fn max(self, other: Self) -> Self;
+
+    /// Returns the minimum of two numbers, ignoring NaN.
+    fn min(self, other: Self) -> Self;
+
+    /// Returns the ordering between two numbers according to the IEEE 754 `totalOrder`
+    /// predicate.
+    fn total_cmp(&self, other: &Self) -> Ordering;
+
+    /// Returns the square root of this number.
+    #[cfg(feature = "std")]
+    fn sqrt(self) -> Self;
+
+    /// Returns `e` to the power of this number.
+    #[cfg(feature = "std")]
+    fn exp(self) -> Self;
+
+    /// Returns the natural logarithm of this number.
+    #[cfg(feature = "std")]
+    fn ln(self) -> Self;
+}
+
+macro_rules! impl_float {
+    ($($t:ty)*) => {$(
+        impl Float for $t {
+            const ZERO: Self = 0.0;
+            const ONE: Self = 1.0;
+            const NAN: Self = <$t>::NAN;
+            const NEG_INFINITY: Self = <$t>::NEG_INFINITY;
+
+            #[inline]
+            fn from_usize(n: usize) -> Self {
+                n as $t
+            }
+
+            #[inline]
+            fn abs(self) -> Self {
+                <$t>::abs(self)
+            }
+
+            #[inline]
+            fn is_nan(self) -> bool {
+                <$t>::is_nan(self)
+            }
+
+            #[inline]
+            fn max(self, other: Self) -> Self {
+                <$t>::max(self, other)
+            }
+
+            #[inline]
+            fn min(self, other: Self) -> Self {
+                <$t>::min(self, other)
+            }
+
+            #[inline]
+            fn total_cmp(&self, other: &Self) -> Ordering {
+                <$t>::total_cmp(self, other)
+            }
+
+            #[cfg(feature = "std")]
+            #[inline]
+            fn sqrt(self) -> Self {
+                <$t>::sqrt(self)
+            }
+
+            #[cfg(feature = "std")]
+            #[inline]
+            fn exp(self) -> Self {
+                <$t>::exp(self)
+            }
+
+            #[cfg(feature = "std")]
+            #[inline]
+            fn ln(self) -> Self {
+                <$t>::ln(self)
+            }
+        }
+    )*};
+}
+
+impl_float!(f32 f64);
+
+/// How NaN values are treated when computing the running maximum or minimum of floating point
+/// numbers.
+///
+/// This is used by [`cummax_float()`] and [`cummin_float()`].
+///
+/// [`cummax_float()`]: crate::IterAccumulate::cummax_float
+/// [`cummin_float()`]: crate::IterAccumulate::cummin_float
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum NanPolicy {
+    /// NaN values are skipped. The running value is only NaN until the first number that is not
+    /// NaN.
+    Ignore,
+    /// Once a NaN value has been seen, the running value stays NaN.
+    Propagate,
+    /// Values are compared using [`total_cmp()`](f64::total_cmp), which orders positive NaN
+    /// above and negative NaN below all other values.
+    TotalOrder,
+}
+
+impl NanPolicy {
+    /// Returns the function that computes the maximum or minimum of two numbers following this
+    /// policy.
+    pub(crate) fn extremum<T: Float>(self, max: bool) -> fn(T, T) -> T {
+        match (self, max) {
+            (NanPolicy::Ignore, true) => T::max,
+            (NanPolicy::Ignore, false) => T::min,
+            (NanPolicy::Propagate, true) => |a, b| {
+                if a.is_nan() {
+                    a
+                } else if b.is_nan() {
+                    b
+                } else {
+                    a.max(b)
+                }
+            },
+            (NanPolicy::Propagate, false) => |a, b| {
+                if a.is_nan() {
+                    a
+                } else if b.is_nan() {
+                    b
+                } else {
+                    a.min(b)
+                }
+            },
+            (NanPolicy::TotalOrder, true) => |a, b| match a.total_cmp(&b) {
+                Ordering::Greater => a,
+                _ => b,
+            },
+            (NanPolicy::TotalOrder, false) => |a, b| match a.total_cmp(&b) {
+                Ordering::Greater => b,
+                _ => a,
+            },
+        }
+    }
+}
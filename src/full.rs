@@ -0,0 +1,107 @@
+use crate::Accumulate;
+use core::{fmt, iter::FusedIterator};
+
+/// An iterator adaptor that yields the initial value, and then accumulates the elements from the
+/// base iterator using the provided closure.
+///
+/// This `struct` is created by [`accumulate_full()`]. See its documentation for more
+/// information.
+///
+/// [`accumulate_full()`]: crate::IterAccumulate::accumulate_full
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone)]
+pub struct AccumulateFull<I, B, F> {
+    inner: Accumulate<I, B, F>,
+    /// Whether the initial value has been yielded yet.
+    started: bool,
+}
+
+impl<I, B, F> AccumulateFull<I, B, F>
+where
+    B: Clone,
+{
+    pub(crate) fn new(iter: I, init: B, f: F) -> Self {
+        Self {
+            inner: Accumulate::new(iter, init, f),
+            started: false,
+        }
+    }
+}
+
+impl<I, B, F> fmt::Debug for AccumulateFull<I, B, F>
+where
+    I: fmt::Debug,
+    B: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("AccumulateFull")
+            .field("inner", &self.inner)
+            .field("started", &self.started)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<I, B, F> Iterator for AccumulateFull<I, B, F>
+where
+    I: Iterator,
+    B: Clone,
+    F: FnMut(B, I::Item) -> B,
+{
+    type Item = B;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if !self.started {
+            self.started = true;
+            return Some(self.inner.acc().clone());
+        }
+        self.inner.next()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.inner.size_hint();
+        if self.started {
+            (lower, upper)
+        } else {
+            (
+                lower.saturating_add(1),
+                upper.and_then(|upper| upper.checked_add(1)),
+            )
+        }
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        self.inner.count() + usize::from(!self.started)
+    }
+
+    #[inline]
+    fn fold<Acc, G>(self, init: Acc, mut g: G) -> Acc
+    where
+        G: FnMut(Acc, Self::Item) -> Acc,
+    {
+        let init = if self.started {
+            init
+        } else {
+            g(init, self.inner.acc().clone())
+        };
+        self.inner.fold(init, g)
+    }
+}
+
+impl<I, B, F> ExactSizeIterator for AccumulateFull<I, B, F>
+where
+    I: ExactSizeIterator,
+    B: Clone,
+    F: FnMut(B, I::Item) -> B,
+{
+}
+
+impl<I, B, F> FusedIterator for AccumulateFull<I, B, F>
+where
+    I: Iterator,
+    B: Clone,
+    F: FnMut(B, I::Item) -> B,
+{
+}
@@ -0,0 +1,171 @@
+use core::{
+    fmt,
+    hash::{BuildHasher, Hash, Hasher},
+    iter::FusedIterator,
+};
+
+/// An iterator adaptor that yields the values of a hash chain over the elements from the base
+/// iterator, computed by the provided closure.
+///
+/// This `struct` is created by [`hash_chain()`]. See its documentation for more information.
+///
+/// [`hash_chain()`]: crate::IterAccumulate::hash_chain
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone)]
+pub struct HashChain<I, H, F> {
+    iter: I,
+    /// The last chain value, or the seed before the first element.
+    head: H,
+    hash: F,
+}
+
+impl<I, H, F> HashChain<I, H, F> {
+    pub(crate) fn new(iter: I, seed: H, hash: F) -> Self {
+        Self {
+            iter,
+            head: seed,
+            hash,
+        }
+    }
+
+    /// Returns a reference to the last chain value, or to the seed before the first element.
+    #[inline]
+    pub fn head(&self) -> &H {
+        &self.head
+    }
+}
+
+impl<I, H, F> fmt::Debug for HashChain<I, H, F>
+where
+    I: fmt::Debug,
+    H: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("HashChain")
+            .field("iter", &self.iter)
+            .field("head", &self.head)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<I, H, F> Iterator for HashChain<I, H, F>
+where
+    I: Iterator,
+    H: Clone,
+    F: FnMut(&H, &I::Item) -> H,
+{
+    type Item = H;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.iter.next()?;
+        self.head = (self.hash)(&self.head, &item);
+        Some(self.head.clone())
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+
+    #[inline]
+    fn fold<Acc, G>(self, init: Acc, mut g: G) -> Acc
+    where
+        G: FnMut(Acc, Self::Item) -> Acc,
+    {
+        let mut hash = self.hash;
+        self.iter
+            .fold((self.head, init), |(head, acc), item| {
+                let head = hash(&head, &item);
+                (head.clone(), g(acc, head))
+            })
+            .1
+    }
+}
+
+impl<I, H, F> ExactSizeIterator for HashChain<I, H, F>
+where
+    I: ExactSizeIterator,
+    H: Clone,
+    F: FnMut(&H, &I::Item) -> H,
+{
+}
+
+impl<I, H, F> FusedIterator for HashChain<I, H, F>
+where
+    I: FusedIterator,
+    H: Clone,
+    F: FnMut(&H, &I::Item) -> H,
+{
+}
+
+/// An iterator adaptor that yields the values of a hash chain over the elements from the base
+/// iterator, computed by a [`Hasher`].
+///
+/// This `struct` is created by [`hash_chain_with()`]. See its documentation for more information.
+///
+/// [`hash_chain_with()`]: crate::IterAccumulate::hash_chain_with
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone, Debug)]
+pub struct HasherChain<I, S> {
+    iter: I,
+    /// The last chain value, or zero before the first element.
+    head: u64,
+    build_hasher: S,
+}
+
+impl<I, S> HasherChain<I, S> {
+    pub(crate) fn new(iter: I, build_hasher: S) -> Self {
+        Self {
+            iter,
+            head: 0,
+            build_hasher,
+        }
+    }
+
+    /// Returns the last chain value, or zero before the first element.
+    #[inline]
+    pub fn head(&self) -> u64 {
+        self.head
+    }
+}
+
+impl<I, S> Iterator for HasherChain<I, S>
+where
+    I: Iterator,
+    I::Item: Hash,
+    S: BuildHasher,
+{
+    type Item = u64;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.iter.next()?;
+        let mut hasher = self.build_hasher.build_hasher();
+        hasher.write_u64(self.head);
+        item.hash(&mut hasher);
+        self.head = hasher.finish();
+        Some(self.head)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I, S> ExactSizeIterator for HasherChain<I, S>
+where
+    I: ExactSizeIterator,
+    I::Item: Hash,
+    S: BuildHasher,
+{
+}
+
+impl<I, S> FusedIterator for HasherChain<I, S>
+where
+    I: FusedIterator,
+    I::Item: Hash,
+    S: BuildHasher,
+{
+}
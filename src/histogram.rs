@@ -0,0 +1,142 @@
+use alloc::vec::Vec;
+
+use crate::IterAccumulate;
+
+/// A histogram that stores the running total of the counts of its buckets, so that the
+/// cumulative count and the cumulative distribution function of any bucket can be looked up in
+/// constant time.
+///
+/// This is created by [`cumulative_histogram()`](crate::IterAccumulate::cumulative_histogram),
+/// or by collecting an iterator of bucket counts into it:
+///
+/// ```
+/// use iter_accumulate::CumulativeHistogram;
+///
+/// let histogram: CumulativeHistogram = [1, 0, 3, 4].into_iter().collect();
+///
+/// assert_eq!(histogram.cumulative_counts(), [1, 1, 4, 8]);
+/// assert_eq!(histogram.count(2), 3);
+/// assert_eq!(histogram.cdf(2), 0.5);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct CumulativeHistogram {
+    cumulative: Vec<u64>,
+}
+
+impl CumulativeHistogram {
+    /// Returns the number of buckets.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.cumulative.len()
+    }
+
+    /// Returns `true` if there are no buckets.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.cumulative.is_empty()
+    }
+
+    /// Returns the total count of all buckets.
+    #[inline]
+    pub fn total(&self) -> u64 {
+        self.cumulative.last().copied().unwrap_or(0)
+    }
+
+    /// Returns the count of the bucket `bucket` alone.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bucket` is out of bounds.
+    #[inline]
+    pub fn count(&self, bucket: usize) -> u64 {
+        match bucket {
+            0 => self.cumulative[0],
+            _ => self.cumulative[bucket] - self.cumulative[bucket - 1],
+        }
+    }
+
+    /// Returns the total count of the bucket `bucket` and all buckets before it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bucket` is out of bounds.
+    #[inline]
+    pub fn cumulative_count(&self, bucket: usize) -> u64 {
+        self.cumulative[bucket]
+    }
+
+    /// Returns the cumulative counts of all buckets.
+    #[inline]
+    pub fn cumulative_counts(&self) -> &[u64] {
+        &self.cumulative
+    }
+
+    /// Returns the fraction of the total count that is in the bucket `bucket` or a bucket before
+    /// it, or NaN if the total count is zero.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bucket` is out of bounds.
+    #[inline]
+    pub fn cdf(&self, bucket: usize) -> f64 {
+        self.cumulative[bucket] as f64 / self.total() as f64
+    }
+
+    /// Returns an iterator over the [`cdf()`](Self::cdf) of every bucket.
+    #[inline]
+    pub fn iter_cdf(&self) -> impl Iterator<Item = f64> + '_ {
+        let total = self.total() as f64;
+        self.cumulative.iter().map(move |&c| c as f64 / total)
+    }
+
+    /// Returns the first bucket whose [`cdf()`](Self::cdf) is at least `p`, i.e. the bucket that
+    /// contains the `p`-quantile, or [`None`] if the total count is zero.
+    ///
+    /// This is a binary search, so it takes `O(log n)` time for `n` buckets.
+    pub fn quantile_bucket(&self, p: f64) -> Option<usize> {
+        let total = self.total();
+        if total == 0 {
+            return None;
+        }
+        let threshold = p * total as f64;
+        let bucket = self.cumulative.partition_point(|&c| (c as f64) < threshold);
+        Some(bucket.min(self.len() - 1))
+    }
+}
+
+impl FromIterator<u64> for CumulativeHistogram {
+    /// Creates a cumulative histogram from an iterator of the counts of its buckets.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the total count overflows a [`u64`].
+    #[inline]
+    fn from_iter<T: IntoIterator<Item = u64>>(iter: T) -> Self {
+        Self {
+            cumulative: iter
+                .into_iter()
+                .checked_cumsum()
+                .map(|c| c.expect("total count of the histogram overflowed"))
+                .collect(),
+        }
+    }
+}
+
+/// Counts `values` into `buckets` buckets, and converts the counts into a cumulative histogram.
+pub(crate) fn cumulative_histogram<I, F>(
+    values: I,
+    buckets: usize,
+    mut bucket: F,
+) -> CumulativeHistogram
+where
+    I: Iterator,
+    F: FnMut(&I::Item) -> usize,
+{
+    let mut counts = alloc::vec![0; buckets];
+    if buckets != 0 {
+        for value in values {
+            counts[bucket(&value).min(buckets - 1)] += 1;
+        }
+    }
+    counts.into_iter().collect()
+}
@@ -0,0 +1,147 @@
+use crate::POISONED;
+use core::{
+    fmt,
+    iter::{Enumerate, FusedIterator},
+};
+
+/// An iterator adaptor that accumulates the elements from the base iterator using the provided
+/// closure, which also receives the index of each element.
+///
+/// This `struct` is created by [`accumulate_with_index()`]. See its documentation for more
+/// information.
+///
+/// [`accumulate_with_index()`]: crate::IterAccumulate::accumulate_with_index
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone)]
+pub struct AccumulateWithIndex<I, B, F> {
+    iter: Enumerate<I>,
+    /// The accumulated value. This is only [`None`] while it is moved into the closure, or if the
+    /// closure panicked.
+    acc: Option<B>,
+    f: F,
+    /// Whether the base iterator has returned [`None`] yet.
+    done: bool,
+}
+
+impl<I, B, F> AccumulateWithIndex<I, B, F> {
+    pub(crate) fn new(iter: Enumerate<I>, acc: B, f: F) -> Self {
+        Self {
+            iter,
+            acc: Some(acc),
+            f,
+            done: false,
+        }
+    }
+
+    /// Returns a reference to the current accumulated value.
+    #[inline]
+    pub fn acc(&self) -> &B {
+        self.acc.as_ref().expect(POISONED)
+    }
+
+    /// Consumes the iterator adaptor and returns the current accumulated value.
+    #[inline]
+    pub fn into_acc(self) -> B {
+        self.acc.expect(POISONED)
+    }
+}
+
+impl<I, B, F> fmt::Debug for AccumulateWithIndex<I, B, F>
+where
+    I: fmt::Debug,
+    B: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("AccumulateWithIndex")
+            .field("iter", &self.iter)
+            .field("acc", &self.acc)
+            .field("done", &self.done)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<I, B, F> Iterator for AccumulateWithIndex<I, B, F>
+where
+    I: Iterator,
+    B: Clone,
+    F: FnMut(B, usize, I::Item) -> B,
+{
+    type Item = B;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        match self.iter.next() {
+            Some((index, item)) => {
+                let acc = (self.f)(self.acc.take().expect(POISONED), index, item);
+                Some(self.acc.insert(acc).clone())
+            }
+            None => {
+                self.done = true;
+                None
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            (0, Some(0))
+        } else {
+            self.iter.size_hint()
+        }
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        if self.done {
+            0
+        } else {
+            self.iter.count()
+        }
+    }
+
+    #[inline]
+    fn fold<Acc, G>(self, init: Acc, mut g: G) -> Acc
+    where
+        G: FnMut(Acc, Self::Item) -> Acc,
+    {
+        if self.done {
+            return init;
+        }
+        let mut f = self.f;
+        let state = self.acc.expect(POISONED);
+        self.iter
+            .fold((init, state), move |(acc, state), (index, item)| {
+                let state = f(state, index, item);
+                (g(acc, state.clone()), state)
+            })
+            .0
+    }
+}
+
+impl<I, B, F> ExactSizeIterator for AccumulateWithIndex<I, B, F>
+where
+    I: ExactSizeIterator,
+    B: Clone,
+    F: FnMut(B, usize, I::Item) -> B,
+{
+    #[inline]
+    fn len(&self) -> usize {
+        if self.done {
+            0
+        } else {
+            self.iter.len()
+        }
+    }
+}
+
+impl<I, B, F> FusedIterator for AccumulateWithIndex<I, B, F>
+where
+    I: Iterator,
+    B: Clone,
+    F: FnMut(B, usize, I::Item) -> B,
+{
+}
@@ -0,0 +1,114 @@
+use crate::POISONED;
+use core::{fmt, iter::FusedIterator};
+
+/// An iterator adaptor that accumulates the elements from the base iterator, calls a closure with
+/// each accumulated value, and yields the elements unchanged.
+///
+/// This `struct` is created by [`inspect_acc()`]. See its documentation for more information.
+///
+/// [`inspect_acc()`]: crate::IterAccumulate::inspect_acc
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone)]
+pub struct InspectAcc<I, B, F, G> {
+    iter: I,
+    /// The accumulated value. This is only [`None`] while it is moved into the closure, or if the
+    /// closure panicked.
+    acc: Option<B>,
+    f: F,
+    inspect: G,
+}
+
+impl<I, B, F, G> InspectAcc<I, B, F, G> {
+    pub(crate) fn new(iter: I, acc: B, f: F, inspect: G) -> Self {
+        Self {
+            iter,
+            acc: Some(acc),
+            f,
+            inspect,
+        }
+    }
+
+    /// Returns a reference to the current accumulated value.
+    #[inline]
+    pub fn acc(&self) -> &B {
+        self.acc.as_ref().expect(POISONED)
+    }
+
+    /// Consumes the iterator adaptor and returns the current accumulated value.
+    #[inline]
+    pub fn into_acc(self) -> B {
+        self.acc.expect(POISONED)
+    }
+}
+
+impl<I, B, F, G> fmt::Debug for InspectAcc<I, B, F, G>
+where
+    I: fmt::Debug,
+    B: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("InspectAcc")
+            .field("iter", &self.iter)
+            .field("acc", &self.acc)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<I, B, F, G> Iterator for InspectAcc<I, B, F, G>
+where
+    I: Iterator,
+    F: FnMut(B, &I::Item) -> B,
+    G: FnMut(&B),
+{
+    type Item = I::Item;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.iter.next()?;
+        let acc = (self.f)(self.acc.take().expect(POISONED), &item);
+        (self.inspect)(self.acc.insert(acc));
+        Some(item)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+
+    #[inline]
+    fn fold<Acc, H>(self, init: Acc, mut h: H) -> Acc
+    where
+        H: FnMut(Acc, Self::Item) -> Acc,
+    {
+        let mut f = self.f;
+        let mut inspect = self.inspect;
+        let state = self.acc.expect(POISONED);
+        self.iter
+            .fold((init, state), move |(acc, state), item| {
+                let state = f(state, &item);
+                inspect(&state);
+                (h(acc, item), state)
+            })
+            .0
+    }
+}
+
+impl<I, B, F, G> ExactSizeIterator for InspectAcc<I, B, F, G>
+where
+    I: ExactSizeIterator,
+    F: FnMut(B, &I::Item) -> B,
+    G: FnMut(&B),
+{
+    #[inline]
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+
+impl<I, B, F, G> FusedIterator for InspectAcc<I, B, F, G>
+where
+    I: FusedIterator,
+    F: FnMut(B, &I::Item) -> B,
+    G: FnMut(&B),
+{
+}
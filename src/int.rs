@@ -0,0 +1,183 @@
+use core::{
+    fmt,
+    ops::{BitAnd, BitOr, BitXor, Not},
+};
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// A primitive integer type.
+///
+/// This trait is sealed and cannot be implemented outside of this crate. It only exists to make
+/// the integer adaptors generic over all primitive integer types.
+pub trait Integer:
+    Copy
+    + Ord
+    + BitAnd<Output = Self>
+    + BitOr<Output = Self>
+    + BitXor<Output = Self>
+    + Not<Output = Self>
+    + sealed::Sealed
+{
+    /// The additive identity, `0`.
+    const ZERO: Self;
+
+    /// The multiplicative identity, `1`.
+    const ONE: Self;
+
+    /// Adds two numbers, returning [`None`] on overflow.
+    fn checked_add(self, other: Self) -> Option<Self>;
+
+    /// Multiplies two numbers, returning [`None`] on overflow.
+    fn checked_mul(self, other: Self) -> Option<Self>;
+
+    /// Adds two numbers, wrapping around on overflow.
+    fn wrapping_add(self, other: Self) -> Self;
+
+    /// Multiplies two numbers, wrapping around on overflow.
+    fn wrapping_mul(self, other: Self) -> Self;
+
+    /// Adds two numbers, saturating at the numeric bounds on overflow.
+    fn saturating_add(self, other: Self) -> Self;
+
+    /// Multiplies two numbers, saturating at the numeric bounds on overflow.
+    fn saturating_mul(self, other: Self) -> Self;
+
+    /// Adds two numbers, returning the wrapped result and whether it overflowed.
+    fn overflowing_add(self, other: Self) -> (Self, bool);
+
+    /// Multiplies two numbers, returning the wrapped result and whether it overflowed.
+    fn overflowing_mul(self, other: Self) -> (Self, bool);
+
+    /// Divides two numbers, returning [`None`] on division by zero or overflow.
+    fn checked_div(self, other: Self) -> Option<Self>;
+
+    /// Returns the remainder of dividing two numbers, wrapping around on overflow.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `other` is zero.
+    fn wrapping_rem(self, other: Self) -> Self;
+
+    /// Returns the absolute value of this number, wrapping around for the minimum value of a
+    /// signed type.
+    fn wrapping_abs(self) -> Self;
+}
+
+macro_rules! impl_integer {
+    ($abs:expr; $($t:ty)*) => {$(
+        impl sealed::Sealed for $t {}
+
+        impl Integer for $t {
+            const ZERO: Self = 0;
+            const ONE: Self = 1;
+
+            #[inline]
+            fn checked_add(self, other: Self) -> Option<Self> {
+                <$t>::checked_add(self, other)
+            }
+
+            #[inline]
+            fn checked_mul(self, other: Self) -> Option<Self> {
+                <$t>::checked_mul(self, other)
+            }
+
+            #[inline]
+            fn wrapping_add(self, other: Self) -> Self {
+                <$t>::wrapping_add(self, other)
+            }
+
+            #[inline]
+            fn wrapping_mul(self, other: Self) -> Self {
+                <$t>::wrapping_mul(self, other)
+            }
+
+            #[inline]
+            fn saturating_add(self, other: Self) -> Self {
+                <$t>::saturating_add(self, other)
+            }
+
+            #[inline]
+            fn saturating_mul(self, other: Self) -> Self {
+                <$t>::saturating_mul(self, other)
+            }
+
+            #[inline]
+            fn overflowing_add(self, other: Self) -> (Self, bool) {
+                <$t>::overflowing_add(self, other)
+            }
+
+            #[inline]
+            fn overflowing_mul(self, other: Self) -> (Self, bool) {
+                <$t>::overflowing_mul(self, other)
+            }
+
+            #[inline]
+            fn checked_div(self, other: Self) -> Option<Self> {
+                <$t>::checked_div(self, other)
+            }
+
+            #[inline]
+            fn wrapping_rem(self, other: Self) -> Self {
+                <$t>::wrapping_rem(self, other)
+            }
+
+            #[inline]
+            fn wrapping_abs(self) -> Self {
+                let abs: fn(Self) -> Self = $abs;
+                abs(self)
+            }
+        }
+    )*};
+}
+
+impl_integer!(|x| x; u8 u16 u32 u64 u128 usize);
+impl_integer!(|x| x.wrapping_abs(); i8 i16 i32 i64 i128 isize);
+
+/// Returns the absolute value of the greatest common divisor of two integers, wrapping around to
+/// `T::MIN` if that is `T::MIN.unsigned_abs()`.
+#[inline]
+pub(crate) fn gcd<T: Integer>(mut a: T, mut b: T) -> T {
+    while b != T::ZERO {
+        (a, b) = (b, a.wrapping_rem(b));
+    }
+    a.wrapping_abs()
+}
+
+/// Returns the least common multiple of two integers, which is never negative, or [`Overflow`]
+/// if it is not representable.
+#[inline]
+pub(crate) fn checked_lcm<T: Integer>(a: T, b: T) -> Result<T, Overflow> {
+    if a == T::ZERO || b == T::ZERO {
+        return Ok(T::ZERO);
+    }
+    let lcm = a
+        .checked_div(gcd(a, b))
+        .and_then(|a| a.checked_mul(b))
+        .ok_or(Overflow)?;
+    let abs = lcm.wrapping_abs();
+    if abs < T::ZERO {
+        Err(Overflow)
+    } else {
+        Ok(abs)
+    }
+}
+
+/// The error yielded by the checked integer adaptors when the accumulated value overflows.
+///
+/// See [`checked_cumsum()`], [`checked_cumprod()`] and [`running_lcm()`].
+///
+/// [`checked_cumsum()`]: crate::IterAccumulate::checked_cumsum
+/// [`checked_cumprod()`]: crate::IterAccumulate::checked_cumprod
+/// [`running_lcm()`]: crate::IterAccumulate::running_lcm
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Overflow;
+
+impl fmt::Display for Overflow {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("accumulated value overflowed")
+    }
+}
+
+impl core::error::Error for Overflow {}
@@ -0,0 +1,367 @@
+use crate::{Accumulate, AccumulateOutput, Float, POISONED};
+use core::{
+    fmt,
+    iter::FusedIterator,
+    ops::{Add, Mul},
+};
+
+/// The state of a running integral of sampled data using the trapezoidal rule.
+///
+/// This is the accumulated state of [`CumulativeTrapezoid`], see
+/// [`cumulative_trapezoid()`](crate::IterAccumulate::cumulative_trapezoid).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Trapezoid<T> {
+    /// The last `(t, y)` sample, or [`None`] if there are none yet.
+    last: Option<(T, T)>,
+    integral: T,
+}
+
+impl<T: Float> Trapezoid<T> {
+    /// Creates the state of an integral over no samples.
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            last: None,
+            integral: T::ZERO,
+        }
+    }
+
+    /// Adds the sample `y` at `t` to the integral.
+    ///
+    /// The area of the trapezoid between the last sample and this one is added to the integral,
+    /// so the samples do not have to be evenly spaced. The first sample only starts the integral.
+    #[inline]
+    pub fn push(&mut self, t: T, y: T) {
+        if let Some((last_t, last_y)) = self.last {
+            self.integral = self.integral + (t - last_t) * (last_y + y) / (T::ONE + T::ONE);
+        }
+        self.last = Some((t, y));
+    }
+
+    /// Returns the last sample that has been added as a `(t, y)` pair, or [`None`] if there are
+    /// none.
+    #[inline]
+    pub fn last(&self) -> Option<(T, T)> {
+        self.last
+    }
+
+    /// Returns the integral from the first to the last sample that has been added, or zero if
+    /// there are fewer than two.
+    #[inline]
+    pub fn integral(&self) -> T {
+        self.integral
+    }
+}
+
+impl<T: Float> Default for Trapezoid<T> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// An iterator adaptor that yields the running integral of the `(t, y)` samples from the base
+/// iterator using the trapezoidal rule.
+///
+/// This is created by [`cumulative_trapezoid()`](crate::IterAccumulate::cumulative_trapezoid).
+/// See its documentation for more information.
+pub type CumulativeTrapezoid<I, T> =
+    AccumulateOutput<I, Trapezoid<T>, fn(Trapezoid<T>, (T, T)) -> (Trapezoid<T>, T)>;
+
+/// An iterator adaptor that integrates the rates from the base iterator over a fixed time step
+/// using the explicit Euler method.
+///
+/// This `struct` is created by [`integrate()`]. See its documentation for more information.
+///
+/// [`integrate()`]: crate::IterAccumulate::integrate
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone, Debug)]
+pub struct Integrate<I, B, D> {
+    iter: I,
+    /// The integrated quantity. This is only [`None`] while it is moved into the addition, or if
+    /// the addition panicked.
+    acc: Option<B>,
+    dt: D,
+}
+
+impl<I, B, D> Integrate<I, B, D> {
+    pub(crate) fn new(iter: I, init: B, dt: D) -> Self {
+        Self {
+            iter,
+            acc: Some(init),
+            dt,
+        }
+    }
+
+    /// Returns a reference to the integrated quantity.
+    #[inline]
+    pub fn acc(&self) -> &B {
+        self.acc.as_ref().expect(POISONED)
+    }
+
+    /// Returns the fixed time step.
+    #[inline]
+    pub fn dt(&self) -> &D {
+        &self.dt
+    }
+}
+
+impl<I, B, D> Iterator for Integrate<I, B, D>
+where
+    I: Iterator,
+    I::Item: Mul<D>,
+    B: Add<<I::Item as Mul<D>>::Output, Output = B> + Clone,
+    D: Clone,
+{
+    type Item = B;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let rate = self.iter.next()?;
+        let acc = self.acc.take().expect(POISONED) + rate * self.dt.clone();
+        Some(self.acc.insert(acc).clone())
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I, B, D> ExactSizeIterator for Integrate<I, B, D>
+where
+    I: ExactSizeIterator,
+    I::Item: Mul<D>,
+    B: Add<<I::Item as Mul<D>>::Output, Output = B> + Clone,
+    D: Clone,
+{
+}
+
+impl<I, B, D> FusedIterator for Integrate<I, B, D>
+where
+    I: FusedIterator,
+    I::Item: Mul<D>,
+    B: Add<<I::Item as Mul<D>>::Output, Output = B> + Clone,
+    D: Clone,
+{
+}
+
+/// An iterator adaptor that integrates the `(dt, rate)` pairs from the base iterator using the
+/// explicit Euler method.
+///
+/// This is created by [`integrate_steps()`](crate::IterAccumulate::integrate_steps). See its
+/// documentation for more information.
+pub type IntegrateSteps<I, B> = Accumulate<I, B, fn(B, <I as Iterator>::Item) -> B>;
+
+/// Advances `x` by one explicit Euler step of `rate` over `dt`.
+pub(crate) fn euler_step<B, D, R>(x: B, (dt, rate): (D, R)) -> B
+where
+    R: Mul<D>,
+    B: Add<R::Output, Output = B>,
+{
+    x + rate * dt
+}
+
+/// An iterator adaptor that integrates a position and velocity over the time steps from the base
+/// iterator using the velocity Verlet method.
+///
+/// This `struct` is created by [`verlet()`]. See its documentation for more information.
+///
+/// [`verlet()`]: crate::IterAccumulate::verlet
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone)]
+pub struct Verlet<I, S, F> {
+    iter: I,
+    position: S,
+    velocity: S,
+    /// The acceleration at `position`, or [`None`] if it has not been computed yet.
+    acceleration: Option<S>,
+    accel: F,
+}
+
+impl<I, S, F> Verlet<I, S, F> {
+    pub(crate) fn new(iter: I, position: S, velocity: S, accel: F) -> Self {
+        Self {
+            iter,
+            position,
+            velocity,
+            acceleration: None,
+            accel,
+        }
+    }
+
+    /// Returns a reference to the current position.
+    #[inline]
+    pub fn position(&self) -> &S {
+        &self.position
+    }
+
+    /// Returns a reference to the current velocity.
+    #[inline]
+    pub fn velocity(&self) -> &S {
+        &self.velocity
+    }
+}
+
+impl<I, S, F> fmt::Debug for Verlet<I, S, F>
+where
+    I: fmt::Debug,
+    S: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Verlet")
+            .field("iter", &self.iter)
+            .field("position", &self.position)
+            .field("velocity", &self.velocity)
+            .field("acceleration", &self.acceleration)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<I, T, S, F> Iterator for Verlet<I, S, F>
+where
+    I: Iterator<Item = T>,
+    T: Float,
+    S: Clone + Add<Output = S> + Mul<T, Output = S>,
+    F: FnMut(&S) -> S,
+{
+    type Item = (S, S);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let dt = self.iter.next()?;
+        let half_dt = dt / (T::ONE + T::ONE);
+        let acceleration = match self.acceleration.take() {
+            Some(acceleration) => acceleration,
+            None => (self.accel)(&self.position),
+        };
+        self.position = self.position.clone()
+            + self.velocity.clone() * dt
+            + acceleration.clone() * (dt * half_dt);
+        let next_acceleration = (self.accel)(&self.position);
+        self.velocity =
+            self.velocity.clone() + (acceleration + next_acceleration.clone()) * half_dt;
+        self.acceleration = Some(next_acceleration);
+        Some((self.position.clone(), self.velocity.clone()))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I, T, S, F> ExactSizeIterator for Verlet<I, S, F>
+where
+    I: ExactSizeIterator<Item = T>,
+    T: Float,
+    S: Clone + Add<Output = S> + Mul<T, Output = S>,
+    F: FnMut(&S) -> S,
+{
+}
+
+impl<I, T, S, F> FusedIterator for Verlet<I, S, F>
+where
+    I: FusedIterator<Item = T>,
+    T: Float,
+    S: Clone + Add<Output = S> + Mul<T, Output = S>,
+    F: FnMut(&S) -> S,
+{
+}
+
+/// An iterator adaptor that solves an ordinary differential equation over the time steps from
+/// the base iterator using the classic fourth-order Runge-Kutta method.
+///
+/// This `struct` is created by [`rk4()`]. See its documentation for more information.
+///
+/// [`rk4()`]: crate::IterAccumulate::rk4
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone)]
+pub struct Rk4<I, T, Y, F> {
+    iter: I,
+    t: T,
+    y: Y,
+    f: F,
+}
+
+impl<I, T, Y, F> Rk4<I, T, Y, F> {
+    pub(crate) fn new(iter: I, t: T, y: Y, f: F) -> Self {
+        Self { iter, t, y, f }
+    }
+
+    /// Returns a reference to the current time.
+    #[inline]
+    pub fn t(&self) -> &T {
+        &self.t
+    }
+
+    /// Returns a reference to the current state.
+    #[inline]
+    pub fn y(&self) -> &Y {
+        &self.y
+    }
+}
+
+impl<I, T, Y, F> fmt::Debug for Rk4<I, T, Y, F>
+where
+    I: fmt::Debug,
+    T: fmt::Debug,
+    Y: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Rk4")
+            .field("iter", &self.iter)
+            .field("t", &self.t)
+            .field("y", &self.y)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<I, T, Y, F> Iterator for Rk4<I, T, Y, F>
+where
+    I: Iterator<Item = T>,
+    T: Float,
+    Y: Clone + Add<Output = Y> + Mul<T, Output = Y>,
+    F: FnMut(T, &Y) -> Y,
+{
+    type Item = (T, Y);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let dt = self.iter.next()?;
+        let two = T::ONE + T::ONE;
+        let half_dt = dt / two;
+        let (t, y) = (self.t, &self.y);
+        let k1 = (self.f)(t, y);
+        let k2 = (self.f)(t + half_dt, &(y.clone() + k1.clone() * half_dt));
+        let k3 = (self.f)(t + half_dt, &(y.clone() + k2.clone() * half_dt));
+        let k4 = (self.f)(t + dt, &(y.clone() + k3.clone() * dt));
+        let slope = k1 + (k2 + k3) * two + k4;
+        self.y = self.y.clone() + slope * (dt / (two + two + two));
+        self.t = t + dt;
+        Some((self.t, self.y.clone()))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I, T, Y, F> ExactSizeIterator for Rk4<I, T, Y, F>
+where
+    I: ExactSizeIterator<Item = T>,
+    T: Float,
+    Y: Clone + Add<Output = Y> + Mul<T, Output = Y>,
+    F: FnMut(T, &Y) -> Y,
+{
+}
+
+impl<I, T, Y, F> FusedIterator for Rk4<I, T, Y, F>
+where
+    I: FusedIterator<Item = T>,
+    T: Float,
+    Y: Clone + Add<Output = Y> + Mul<T, Output = Y>,
+    F: FnMut(T, &Y) -> Y,
+{
+}
@@ -0,0 +1,184 @@
+use crate::POISONED;
+use core::{fmt, iter::FusedIterator};
+
+/// An iterator adaptor that accumulates the elements from the base iterator using the provided
+/// closure, and that can also be iterated from the back using the provided inverse closure.
+///
+/// This `struct` is created by [`accumulate_invertible()`]. See its documentation for more
+/// information.
+///
+/// [`accumulate_invertible()`]: crate::IterAccumulate::accumulate_invertible
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone)]
+pub struct AccumulateInvertible<I, B, F, G> {
+    iter: I,
+    /// The value yielded last from the front. This is only [`None`] while it is moved into a
+    /// closure, or if a closure panicked.
+    front: Option<B>,
+    /// The value that is yielded next from the back, once it has been computed.
+    back: Option<B>,
+    f: F,
+    inverse: G,
+    /// Whether the base iterator has returned [`None`], in which case it is not polled again.
+    done: bool,
+}
+
+impl<I, B, F, G> AccumulateInvertible<I, B, F, G> {
+    pub(crate) fn new(iter: I, acc: B, f: F, inverse: G) -> Self {
+        Self {
+            iter,
+            front: Some(acc),
+            back: None,
+            f,
+            inverse,
+            done: false,
+        }
+    }
+}
+
+impl<I, B, F, G> fmt::Debug for AccumulateInvertible<I, B, F, G>
+where
+    I: fmt::Debug,
+    B: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("AccumulateInvertible")
+            .field("iter", &self.iter)
+            .field("front", &self.front)
+            .field("back", &self.back)
+            .field("done", &self.done)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<I, B, F, G> Iterator for AccumulateInvertible<I, B, F, G>
+where
+    I: Iterator,
+    B: Clone,
+    F: FnMut(B, I::Item) -> B,
+{
+    type Item = B;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        match self.iter.next() {
+            Some(item) => {
+                let front = (self.f)(self.front.take().expect(POISONED), item);
+                Some(self.front.insert(front).clone())
+            }
+            None => {
+                self.done = true;
+                None
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            (0, Some(0))
+        } else {
+            self.iter.size_hint()
+        }
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        if self.done {
+            0
+        } else {
+            self.iter.count()
+        }
+    }
+}
+
+impl<I, B, F, G> ExactSizeIterator for AccumulateInvertible<I, B, F, G>
+where
+    I: ExactSizeIterator,
+    B: Clone,
+    F: FnMut(B, I::Item) -> B,
+{
+    #[inline]
+    fn len(&self) -> usize {
+        if self.done {
+            0
+        } else {
+            self.iter.len()
+        }
+    }
+}
+
+impl<I, B, F, G> FusedIterator for AccumulateInvertible<I, B, F, G>
+where
+    I: Iterator,
+    B: Clone,
+    F: FnMut(B, I::Item) -> B,
+{
+}
+
+impl<I, B, F, G> AccumulateInvertible<I, B, F, G>
+where
+    I: Iterator + Clone,
+    B: Clone,
+    F: FnMut(B, I::Item) -> B,
+{
+    /// Takes the accumulated value of all remaining elements, which is the value that will be
+    /// yielded by the next call to [`next_back()`](DoubleEndedIterator::next_back).
+    ///
+    /// This has to walk a clone of the base iterator once, but only the first time it is needed.
+    fn take_back(&mut self) -> B {
+        match self.back.take() {
+            Some(back) => back,
+            None => {
+                let front = self.front.clone().expect(POISONED);
+                self.iter.clone().fold(front, &mut self.f)
+            }
+        }
+    }
+}
+
+impl<I, B, F, G> DoubleEndedIterator for AccumulateInvertible<I, B, F, G>
+where
+    I: DoubleEndedIterator + Clone,
+    B: Clone,
+    F: FnMut(B, I::Item) -> B,
+    G: FnMut(B, I::Item) -> B,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let back = self.take_back();
+        match self.iter.next_back() {
+            Some(item) => {
+                self.back = Some((self.inverse)(back.clone(), item));
+                Some(back)
+            }
+            None => {
+                self.done = true;
+                None
+            }
+        }
+    }
+
+    #[inline]
+    fn rfold<Acc, Fold>(mut self, init: Acc, mut fold: Fold) -> Acc
+    where
+        Fold: FnMut(Acc, Self::Item) -> Acc,
+    {
+        if self.done {
+            return init;
+        }
+        let back = self.take_back();
+        let mut inverse = self.inverse;
+        self.iter
+            .rfold((init, back), |(acc, back), item| {
+                (fold(acc, back.clone()), inverse(back, item))
+            })
+            .0
+    }
+}
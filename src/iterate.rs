@@ -0,0 +1,148 @@
+use crate::POISONED;
+use core::{fmt, iter::FusedIterator};
+
+/// An iterator that repeatedly applies the provided closure to an accumulated value and yields
+/// each result.
+///
+/// This `struct` is created by [`iterate()`](crate::iterate). See its documentation for more
+/// information.
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+#[derive(Clone)]
+pub struct Iterate<B, F> {
+    /// The accumulated value. This is only [`None`] while it is moved into the closure, or if the
+    /// closure panicked.
+    acc: Option<B>,
+    f: F,
+}
+
+impl<B, F> Iterate<B, F> {
+    pub(crate) fn new(acc: B, f: F) -> Self {
+        Self { acc: Some(acc), f }
+    }
+
+    /// Returns a reference to the current accumulated value.
+    #[inline]
+    pub fn acc(&self) -> &B {
+        self.acc.as_ref().expect(POISONED)
+    }
+
+    /// Consumes the iterator and returns the current accumulated value.
+    #[inline]
+    pub fn into_acc(self) -> B {
+        self.acc.expect(POISONED)
+    }
+}
+
+impl<B, F> fmt::Debug for Iterate<B, F>
+where
+    B: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Iterate")
+            .field("acc", &self.acc)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<B, F> Iterator for Iterate<B, F>
+where
+    B: Clone,
+    F: FnMut(B) -> B,
+{
+    type Item = B;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let acc = (self.f)(self.acc.take().expect(POISONED));
+        Some(self.acc.insert(acc).clone())
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        // Skipped values still have to be computed, but do not have to be cloned
+        let mut acc = self.acc.take().expect(POISONED);
+        for _ in 0..n {
+            acc = (self.f)(acc);
+        }
+        self.acc = Some(acc);
+        self.next()
+    }
+}
+
+impl<B, F> FusedIterator for Iterate<B, F>
+where
+    B: Clone,
+    F: FnMut(B) -> B,
+{
+}
+
+/// An iterator that repeatedly applies the provided closure to an accumulated value and yields
+/// each result, until the closure returns [`None`].
+///
+/// This `struct` is created by [`unfold()`](crate::unfold). See its documentation for more
+/// information.
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+#[derive(Clone)]
+pub struct Unfold<B, F> {
+    /// The accumulated value, which is [`None`] once the closure has returned [`None`].
+    acc: Option<B>,
+    f: F,
+}
+
+impl<B, F> Unfold<B, F> {
+    pub(crate) fn new(acc: B, f: F) -> Self {
+        Self { acc: Some(acc), f }
+    }
+
+    /// Returns a reference to the current accumulated value, or [`None`] if the sequence has
+    /// ended.
+    #[inline]
+    pub fn acc(&self) -> Option<&B> {
+        self.acc.as_ref()
+    }
+}
+
+impl<B, F> fmt::Debug for Unfold<B, F>
+where
+    B: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Unfold")
+            .field("acc", &self.acc)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<B, F> Iterator for Unfold<B, F>
+where
+    B: Clone,
+    F: FnMut(B) -> Option<B>,
+{
+    type Item = B;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let acc = (self.f)(self.acc.take()?)?;
+        Some(self.acc.insert(acc).clone())
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self.acc {
+            Some(_) => (0, None),
+            None => (0, Some(0)),
+        }
+    }
+}
+
+impl<B, F> FusedIterator for Unfold<B, F>
+where
+    B: Clone,
+    F: FnMut(B) -> Option<B>,
+{
+}
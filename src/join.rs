@@ -0,0 +1,98 @@
+use crate::AccumulateFirst;
+use core::ops::{Range, RangeInclusive};
+
+/// A type whose values can be combined into a bound of both, i.e. a join-semilattice.
+///
+/// The join has to be associative, commutative and idempotent, so the running join of a sequence
+/// of values does not depend on how it is grouped, and joining a value that is already included
+/// changes nothing. Examples are the union of bounding boxes, the hull of intervals and the union
+/// of sets of flags.
+///
+/// This trait is used by [`running_join()`](crate::IterAccumulate::running_join).
+///
+/// # Examples
+///
+/// ```
+/// use iter_accumulate::Join;
+///
+/// /// An axis-aligned bounding box.
+/// #[derive(Clone, Copy, Debug, PartialEq)]
+/// struct Aabb {
+///     min: [f32; 2],
+///     max: [f32; 2],
+/// }
+///
+/// impl Join for Aabb {
+///     fn join(self, other: Self) -> Self {
+///         Self {
+///             min: [self.min[0].min(other.min[0]), self.min[1].min(other.min[1])],
+///             max: [self.max[0].max(other.max[0]), self.max[1].max(other.max[1])],
+///         }
+///     }
+/// }
+///
+/// let a = Aabb { min: [0.0, 0.0], max: [1.0, 1.0] };
+/// let b = Aabb { min: [2.0, -1.0], max: [3.0, 0.5] };
+/// assert_eq!(a.join(b), Aabb { min: [0.0, -1.0], max: [3.0, 1.0] });
+/// ```
+pub trait Join {
+    /// Returns the least value that bounds both `self` and `other`.
+    fn join(self, other: Self) -> Self;
+}
+
+/// Logical or, i.e. whether either flag is set.
+impl Join for bool {
+    #[inline]
+    fn join(self, other: Self) -> Self {
+        self | other
+    }
+}
+
+/// [`None`] is the bottom value, which joins to the other value.
+impl<T: Join> Join for Option<T> {
+    #[inline]
+    fn join(self, other: Self) -> Self {
+        match (self, other) {
+            (Some(a), Some(b)) => Some(a.join(b)),
+            (a, None) => a,
+            (None, b) => b,
+        }
+    }
+}
+
+/// The smallest range that contains both ranges, including any gap between them.
+///
+/// Empty ranges are not treated specially, so their bounds still extend the hull.
+impl<T: Ord> Join for Range<T> {
+    #[inline]
+    fn join(self, other: Self) -> Self {
+        self.start.min(other.start)..self.end.max(other.end)
+    }
+}
+
+/// The smallest range that contains both ranges, including any gap between them.
+///
+/// Empty ranges are not treated specially, so their bounds still extend the hull.
+impl<T: Ord> Join for RangeInclusive<T> {
+    #[inline]
+    fn join(self, other: Self) -> Self {
+        let (start, end) = self.into_inner();
+        let (other_start, other_end) = other.into_inner();
+        start.min(other_start)..=end.max(other_end)
+    }
+}
+
+/// Joins both components separately.
+impl<A: Join, B: Join> Join for (A, B) {
+    #[inline]
+    fn join(self, other: Self) -> Self {
+        (self.0.join(other.0), self.1.join(other.1))
+    }
+}
+
+/// An iterator adaptor that yields the running join of the elements from the base iterator.
+///
+/// This is created by [`running_join()`](crate::IterAccumulate::running_join). See its
+/// documentation for more information.
+pub type RunningJoin<I> =
+    AccumulateFirst<I, fn(<I as Iterator>::Item, <I as Iterator>::Item) -> <I as Iterator>::Item>;
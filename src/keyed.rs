@@ -0,0 +1,135 @@
+use crate::POISONED;
+use alloc::collections::BTreeMap;
+use core::{borrow::Borrow, fmt, iter::FusedIterator};
+
+/// An iterator adaptor that accumulates the elements from the base iterator separately for every
+/// key, and yields every key along with its accumulated value so far.
+///
+/// This `struct` is created by [`accumulate_by_key()`]. See its documentation for more
+/// information.
+///
+/// [`accumulate_by_key()`]: crate::IterAccumulate::accumulate_by_key
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone)]
+pub struct AccumulateByKey<I, K, B, G, F> {
+    iter: I,
+    key: G,
+    init: B,
+    /// The accumulated value of every key so far. A value is only [`None`] while it is moved
+    /// into the closure, or if the closure panicked.
+    accs: BTreeMap<K, Option<B>>,
+    f: F,
+}
+
+impl<I, K, B, G, F> AccumulateByKey<I, K, B, G, F> {
+    pub(crate) fn new(iter: I, key: G, init: B, f: F) -> Self {
+        Self {
+            iter,
+            key,
+            init,
+            accs: BTreeMap::new(),
+            f,
+        }
+    }
+
+    /// Returns a reference to the accumulated value of `key` so far, or [`None`] if there has
+    /// not been an element with that key yet.
+    #[inline]
+    pub fn get<Q>(&self, key: &Q) -> Option<&B>
+    where
+        K: Borrow<Q> + Ord,
+        Q: Ord + ?Sized,
+    {
+        let acc = self.accs.get(key)?;
+        Some(acc.as_ref().expect(POISONED))
+    }
+
+    /// Returns an iterator over all keys so far and their accumulated values, ordered by key.
+    #[inline]
+    pub fn accs(&self) -> impl Iterator<Item = (&K, &B)> {
+        self.accs
+            .iter()
+            .map(|(key, acc)| (key, acc.as_ref().expect(POISONED)))
+    }
+
+    /// Consumes the iterator adaptor and returns an iterator over all keys so far and their
+    /// accumulated values, ordered by key.
+    #[inline]
+    pub fn into_accs(self) -> impl Iterator<Item = (K, B)> {
+        self.accs
+            .into_iter()
+            .map(|(key, acc)| (key, acc.expect(POISONED)))
+    }
+}
+
+impl<I, K, B, G, F> fmt::Debug for AccumulateByKey<I, K, B, G, F>
+where
+    I: fmt::Debug,
+    K: fmt::Debug,
+    B: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("AccumulateByKey")
+            .field("iter", &self.iter)
+            .field("init", &self.init)
+            .field("accs", &self.accs)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<I, K, B, G, F> Iterator for AccumulateByKey<I, K, B, G, F>
+where
+    I: Iterator,
+    K: Ord + Clone,
+    B: Clone,
+    G: FnMut(&I::Item) -> K,
+    F: FnMut(B, I::Item) -> B,
+{
+    type Item = (K, B);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.iter.next()?;
+        let key = (self.key)(&item);
+        let slot = self
+            .accs
+            .entry(key.clone())
+            .or_insert_with(|| Some(self.init.clone()));
+        let acc = (self.f)(slot.take().expect(POISONED), item);
+        Some((key, slot.insert(acc).clone()))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        self.iter.count()
+    }
+}
+
+impl<I, K, B, G, F> ExactSizeIterator for AccumulateByKey<I, K, B, G, F>
+where
+    I: ExactSizeIterator,
+    K: Ord + Clone,
+    B: Clone,
+    G: FnMut(&I::Item) -> K,
+    F: FnMut(B, I::Item) -> B,
+{
+    #[inline]
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+
+impl<I, K, B, G, F> FusedIterator for AccumulateByKey<I, K, B, G, F>
+where
+    I: FusedIterator,
+    K: Ord + Clone,
+    B: Clone,
+    G: FnMut(&I::Item) -> K,
+    F: FnMut(B, I::Item) -> B,
+{
+}
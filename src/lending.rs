@@ -0,0 +1,116 @@
+use core::fmt;
+
+/// An iterator whose elements may borrow from the iterator itself.
+///
+/// Unlike with [`Iterator`], an element returned by [`next()`](Self::next) has to be dropped
+/// before `next()` can be called again. This allows iterators like [`AccumulateRef`] to yield
+/// references to their own state.
+pub trait LendingIterator {
+    /// The type of the elements being iterated over.
+    type Item<'a>
+    where
+        Self: 'a;
+
+    /// Advances the iterator and returns the next element.
+    ///
+    /// Returns [`None`] when iteration is finished.
+    fn next(&mut self) -> Option<Self::Item<'_>>;
+
+    /// Returns the bounds on the remaining length of the iterator.
+    ///
+    /// See [`Iterator::size_hint()`] for more information.
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, None)
+    }
+}
+
+/// A lending iterator adaptor that accumulates the elements from the base iterator and yields
+/// references to the accumulated value.
+///
+/// This `struct` is created by [`accumulate_ref()`]. See its documentation for more information.
+///
+/// [`accumulate_ref()`]: crate::IterAccumulate::accumulate_ref
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone)]
+pub struct AccumulateRef<I, B, F> {
+    iter: I,
+    acc: B,
+    f: F,
+    /// Whether the base iterator has returned [`None`] yet.
+    done: bool,
+}
+
+impl<I, B, F> AccumulateRef<I, B, F> {
+    pub(crate) fn new(iter: I, acc: B, f: F) -> Self {
+        Self {
+            iter,
+            acc,
+            f,
+            done: false,
+        }
+    }
+
+    /// Returns a reference to the current accumulated value.
+    #[inline]
+    pub fn acc(&self) -> &B {
+        &self.acc
+    }
+
+    /// Consumes the iterator adaptor and returns the current accumulated value.
+    #[inline]
+    pub fn into_acc(self) -> B {
+        self.acc
+    }
+}
+
+impl<I, B, F> fmt::Debug for AccumulateRef<I, B, F>
+where
+    I: fmt::Debug,
+    B: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("AccumulateRef")
+            .field("iter", &self.iter)
+            .field("acc", &self.acc)
+            .field("done", &self.done)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<I, B, F> LendingIterator for AccumulateRef<I, B, F>
+where
+    I: Iterator,
+    F: FnMut(&mut B, I::Item),
+{
+    type Item<'a>
+        = &'a B
+    where
+        Self: 'a;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item<'_>> {
+        if self.done {
+            return None;
+        }
+        match self.iter.next() {
+            Some(item) => {
+                (self.f)(&mut self.acc, item);
+                Some(&self.acc)
+            }
+            None => {
+                self.done = true;
+                None
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            (0, Some(0))
+        } else {
+            self.iter.size_hint()
+        }
+    }
+}
@@ -1,4 +1,11 @@
 #![no_std]
+#![cfg_attr(feature = "trusted_len", feature(trusted_len))]
+#![cfg_attr(
+    feature = "inplace_iteration",
+    feature(inplace_iteration, min_specialization)
+)]
+#![cfg_attr(feature = "try_trait", feature(try_trait_v2))]
+#![cfg_attr(feature = "iter_advance_by", feature(iter_advance_by))]
 #![warn(missing_docs)]
 
 //! This crate provides [`accumulate()`], an iterator adaptor that accumulates the elements from the
@@ -9,9 +16,13 @@
 //! In other words, the last element yielded by [`accumulate()`] is what would have been returned
 //! by [`fold()`] if it was used instead.
 //!
-//! The returned iterator is **not** fused and it is not specified what happens when the base
-//! iterator returns [`None`].
-//! If you want a fused iterator, use [`fuse()`].
+//! The accumulated value only has to implement [`Clone`]. It is moved into the closure for every
+//! element, so the only clone that is made per element is the yielded one. For [`Copy`] types,
+//! that clone is just a copy.
+//!
+//! The returned iterator is fused: once the base iterator has returned [`None`], it keeps
+//! returning [`None`] without polling the base iterator or calling the closure again, even if the
+//! base iterator would resume yielding elements.
 //!
 //! # Examples
 //!
@@ -29,88 +40,4430 @@
 //! assert_eq!(iter.next(), None);
 //! ```
 //!
+//! If the base iterator is an [`ExactSizeIterator`], so is the returned iterator, because it
+//! yields exactly one element for every element of the base iterator:
+//!
+//! ```
+//! use iter_accumulate::IterAccumulate;
+//!
+//! let input = [1, 2, 3, 4, 5];
+//! let mut iter = input.iter().accumulate(0, |acc, i| acc + i);
+//!
+//! assert_eq!(iter.len(), 5);
+//! iter.next();
+//! assert_eq!(iter.len(), 4);
+//! ```
+//!
+//! Accumulated values that are not [`Copy`] work just the same:
+//!
+//! ```
+//! use iter_accumulate::IterAccumulate;
+//!
+//! let input = ["foo", "bar", "baz"];
+//! let mut iter = input.iter().accumulate(String::new(), |mut acc, s| {
+//!     acc.push_str(s);
+//!     acc
+//! });
+//!
+//! assert_eq!(iter.next().as_deref(), Some("foo"));
+//! assert_eq!(iter.next().as_deref(), Some("foobar"));
+//! assert_eq!(iter.next().as_deref(), Some("foobarbaz"));
+//! assert_eq!(iter.next(), None);
+//! ```
+//!
+//! If the closure panics, the accumulated value that was moved into it is lost, and the returned
+//! iterator panics when it is used again, unless a new value is set with
+//! [`set_acc()`](Accumulate::set_acc) or [`reset()`](Accumulate::reset).
+//!
+//! The closure is never called again after the base iterator has been exhausted:
+//!
+//! ```
+//! use iter_accumulate::IterAccumulate;
+//!
+//! // A base iterator that yields `None` on every other call
+//! let mut n = 0;
+//! let base = std::iter::from_fn(|| {
+//!     n += 1;
+//!     (n % 2 == 1).then_some(n)
+//! });
+//! let mut iter = base.accumulate(0, |acc, i| acc + i);
+//!
+//! assert_eq!(iter.next(), Some(1));
+//! assert_eq!(iter.next(), None);
+//! assert_eq!(iter.next(), None);
+//! ```
+//!
+//! Short-circuiting methods leave the iterator in a state from which it can be resumed:
+//!
+//! ```
+//! use iter_accumulate::IterAccumulate;
+//!
+//! let input = [1, 2, 3, 4, 5];
+//! let mut iter = input.iter().accumulate(0, |acc, i| acc + i);
+//!
+//! assert_eq!(iter.position(|acc| acc > 5), Some(2));
+//! assert_eq!(iter.next(), Some(10));
+//! assert_eq!(iter.find(|&acc| acc > 20), None);
+//! assert_eq!(iter.next(), None);
+//! ```
+//!
+//! Elements that are skipped, e.g. using [`nth()`](Iterator::nth) or [`skip()`](Iterator::skip),
+//! are still passed to the closure, so the accumulated value does not depend on whether elements
+//! are yielded or skipped:
+//!
+//! ```
+//! use iter_accumulate::IterAccumulate;
+//!
+//! let input = [1, 2, 3, 4, 5, 6];
+//! let mut iter = input.iter().accumulate(0, |acc, i| acc + i);
+//!
+//! assert_eq!(iter.nth(1), Some(3));
+//! assert_eq!(iter.nth(2), Some(15));
+//! assert_eq!(iter.nth(1), None);
+//! assert_eq!(iter.next(), None);
+//!
+//! let skipped: Vec<_> = input.iter().accumulate(0, |acc, i| acc + i).skip(4).collect();
+//! assert_eq!(skipped, [15, 21]);
+//! ```
+//!
+//! # Crate features
+//!
+//! * `alloc`: Enables the adaptors that need to allocate, which are:
+//!   * adaptors that buffer elements, like `suffix_accumulate_aligned()`,
+//!     `bidirectional_accumulate()` and `sliding_time_window()`,
+//!   * the rolling window adaptors, like `rolling_sum()`,
+//!   * the running order statistics, like `running_median()`, `running_top_k()` and
+//!     `cumulative_histogram()`,
+//!   * `accumulate_by_key()`, which keeps an accumulated value for every key,
+//!   * `accumulate_boxed()`.
+//! * `std`: Enables `alloc`, as well as adaptors that need floating point functions from the
+//!   standard library, like `running_stddev()`.
+//! * `sketch`: Enables `alloc`, as well as probabilistic data structures that summarize huge
+//!   streams in bounded memory, like `running_distinct_count()` and `running_frequency()`.
+//! * `finance`: Enables `std`, as well as adaptors for financial time series, like
+//!   `cumulative_returns()`, `running_max_drawdown()` and `rolling_volatility()`.
+//!
+//! The following features require a nightly compiler:
+//!
+//! * `trusted_len`: Implements [`TrustedLen`](core::iter::TrustedLen) for [`Accumulate`] if the
+//!   base iterator implements it.
+//! * `inplace_iteration`: Implements [`SourceIter`](core::iter::SourceIter) and
+//!   [`InPlaceIterable`](core::iter::InPlaceIterable) for [`Accumulate`], so that collecting an
+//!   accumulated [`vec::IntoIter`] into a [`Vec`] can reuse the allocation of the source vector.
+//! * `try_trait`: Overrides [`Iterator::try_fold()`] for [`Accumulate`], so that short-circuiting
+//!   methods like [`find()`](Iterator::find) and [`position()`](Iterator::position) use the
+//!   internal iteration of the base iterator.
+//! * `iter_advance_by`: Overrides [`Iterator::advance_by()`] for [`Accumulate`], so that skipping
+//!   elements uses the internal iteration of the base iterator.
+//!
+//! [`vec::IntoIter`]: https://doc.rust-lang.org/std/vec/struct.IntoIter.html
+//! [`Vec`]: https://doc.rust-lang.org/std/vec/struct.Vec.html
+//!
 //! [`accumulate()`]: IterAccumulate::accumulate
 //! [`fold()`]: Iterator::fold
-//! [`fuse()`]: Iterator::fuse
 
-use core::fmt;
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
 
-/// An iterator adaptor that accumulates the elements from the base iterator using the provided
-/// closure.
-///
-/// See the [crate-level documentation](crate) for more information.
-#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
-#[derive(Clone)]
-pub struct Accumulate<I, B, F> {
-    iter: I,
-    acc: B,
-    f: F,
-}
+mod accumulate;
+mod accumulate_mut;
+mod accumulator;
+mod arg;
+#[cfg(feature = "alloc")]
+mod bidirectional;
+mod chunk;
+mod compensated;
+mod count;
+mod cum;
+mod dedup;
+mod delta;
+mod every;
+mod exclusive;
+mod fallible;
+#[cfg(feature = "finance")]
+mod finance;
+mod first;
+mod flat_map;
+mod float;
+mod full;
+mod hash_chain;
+#[cfg(feature = "alloc")]
+mod histogram;
+mod index;
+mod inspect;
+mod int;
+mod integrate;
+mod invertible;
+mod iterate;
+mod join;
+#[cfg(feature = "alloc")]
+mod keyed;
+mod lending;
+mod map;
+#[cfg(feature = "alloc")]
+mod median;
+mod nesting;
+mod output;
+mod path;
+mod quantile;
+mod recurrence;
+mod reset;
+mod rolling;
+#[cfg(feature = "sketch")]
+mod sketch;
+mod sorted;
+mod stats;
+mod stop;
+mod subarray;
+#[cfg(feature = "alloc")]
+mod suffix;
+mod text;
+mod time;
+#[cfg(feature = "alloc")]
+mod top_k;
+mod with;
+mod zip;
 
-impl<I, B, F> Accumulate<I, B, F> {
-    fn new(iter: I, acc: B, f: F) -> Self {
-        Self { iter, acc, f }
-    }
+use core::{
+    iter,
+    ops::{Add, ControlFlow, Mul, Range},
+};
+
+/// The panic message for when an accumulated value was moved into a closure that panicked.
+const POISONED: &str = "accumulated value lost because the closure panicked";
+
+#[cfg(feature = "alloc")]
+pub use accumulate::BoxedAccumulate;
+pub use accumulate::{Accumulate, AccumulateParts, PeekableAccumulate};
+pub use accumulate_mut::AccumulateMut;
+pub use accumulator::{
+    Accumulator, AccumulatorPair, Count, FilterAccumulator, MapAccumulator, Maximum, Minimum,
+    Running, Total,
+};
+pub use arg::RunningArgBest;
+#[cfg(feature = "alloc")]
+pub use bidirectional::BidirectionalAccumulate;
+pub use chunk::{ChunkFold, PartialChunk};
+pub use compensated::CumSumCompensated;
+pub use count::{RunningCountWhere, RunningFractionWhere, RunningStreak};
+pub use cum::{
+    CheckedCum, CumBitwise, CumExtremum, CumProd, CumSum, OverflowingCum, RunningExtrema,
+    RunningGcd,
+};
+pub use dedup::DedupAcc;
+pub use delta::{Deltas, Undeltas};
+pub use every::AccumulateEvery;
+pub use exclusive::ExclusiveAccumulate;
+pub use fallible::{AccumulateOk, AccumulateSome, AccumulateSomeOrNone, TryAccumulate};
+#[cfg(feature = "finance")]
+pub use finance::{CumulativeReturns, Drawdown, RunningMaxDrawdown};
+pub use first::AccumulateFirst;
+pub use flat_map::AccumulateFlatMap;
+pub use float::{Float, NanPolicy};
+pub use full::AccumulateFull;
+pub use hash_chain::{HashChain, HasherChain};
+#[cfg(feature = "alloc")]
+pub use histogram::CumulativeHistogram;
+pub use index::AccumulateWithIndex;
+pub use inspect::InspectAcc;
+pub use int::{Integer, Overflow};
+pub use integrate::{CumulativeTrapezoid, Integrate, IntegrateSteps, Rk4, Trapezoid, Verlet};
+pub use invertible::AccumulateInvertible;
+pub use iterate::{Iterate, Unfold};
+pub use join::{Join, RunningJoin};
+#[cfg(feature = "alloc")]
+pub use keyed::AccumulateByKey;
+pub use lending::{AccumulateRef, LendingIterator};
+pub use map::MapAccumulate;
+#[cfg(feature = "alloc")]
+pub use median::{Median, RunningMedian};
+pub use nesting::{Delimiter, NestingDepth};
+pub use output::AccumulateOutput;
+pub use path::ArcLength;
+pub use quantile::{Quantile, RunningQuantile};
+pub use recurrence::AccumulateRecurrence;
+pub use reset::{AccumulateResetWhen, AccumulateRunsByKey, AccumulateTumbling, ResetPosition};
+#[cfg(feature = "std")]
+pub use rolling::RollingStddev;
+pub use rolling::{RollingArray, WarmUp, Window};
+#[cfg(feature = "alloc")]
+pub use rolling::{RollingExtremum, RollingFold, RollingMean, RollingSum};
+#[cfg(feature = "sketch")]
+pub use sketch::{CountMinSketch, HyperLogLog, RunningDistinctCount, RunningFrequency};
+pub use sorted::{Compare, RunningIsSorted};
+pub use stats::{
+    Covariance, Ewma, Mean, Moments, RunningCovariance, RunningEwma, RunningLinearRegression,
+    RunningMean, RunningMoments, RunningVariance, Variance, VarianceKind,
+};
+#[cfg(feature = "std")]
+pub use stats::{LogSumExp, RunningLogSumExp, RunningZScore};
+pub use stop::{AccumulateConverge, AccumulateUntil, AccumulateWhile, Boundary};
+pub use subarray::{MaxSubarray, RunningMaxSubarraySum};
+#[cfg(feature = "alloc")]
+pub use suffix::SuffixAccumulateAligned;
+pub use text::{Position, Positions, TextOffset, TextOffsets, TextUnit};
+#[cfg(feature = "alloc")]
+pub use time::SlidingTimeWindow;
+pub use time::TimeWindows;
+#[cfg(feature = "alloc")]
+pub use top_k::{RunningTopK, TopK};
+pub use with::AccumulateWith;
+pub use zip::ZipAccumulate;
+
+/// Creates an iterator that accumulates the elements of `iterable` using the provided closure.
+///
+/// This is the same as `iterable.into_iter().accumulate(init, f)`, see
+/// [`IterAccumulate::accumulate()`], but can be called without importing the extension trait,
+/// and with any [`IntoIterator`], like a collection or a reference to one.
+///
+/// # Examples
+///
+/// ```
+/// let input = vec![1, 2, 3];
+/// let mut iter = iter_accumulate::accumulate(&input, 0, |acc, i| acc + i);
+///
+/// assert_eq!(iter.next(), Some(1));
+/// assert_eq!(iter.next(), Some(3));
+/// assert_eq!(iter.next(), Some(6));
+/// assert_eq!(iter.next(), None);
+/// ```
+#[inline]
+pub fn accumulate<I, B, F>(iterable: I, init: B, f: F) -> Accumulate<I::IntoIter, B, F>
+where
+    I: IntoIterator,
+    B: Clone,
+    F: FnMut(B, I::Item) -> B,
+{
+    Accumulate::new(iterable.into_iter(), init, f)
 }
 
-impl<I, B, F> fmt::Debug for Accumulate<I, B, F>
+/// Creates an iterator that starts with `init` and repeatedly applies the provided closure to the
+/// accumulated value, yielding each result.
+///
+/// This is like accumulating an infinite iterator of `()` elements: the first value yielded is
+/// `f(init)`, not `init` itself. Unlike with [`iter::successors()`], the closure takes the value
+/// by value and cannot end the iteration, so the returned iterator is infinite.
+///
+/// # Examples
+///
+/// Compound growth of 10% per period:
+///
+/// ```
+/// let mut iter = iter_accumulate::iterate(100, |balance| balance + balance / 10);
+///
+/// assert_eq!(iter.next(), Some(110));
+/// assert_eq!(iter.next(), Some(121));
+/// assert_eq!(iter.nth(1), Some(146));
+/// ```
+///
+/// The number of steps the Collatz sequence takes to reach 1:
+///
+/// ```
+/// let collatz = |n| if n % 2 == 0 { n / 2 } else { 3 * n + 1 };
+/// let steps = iter_accumulate::iterate(6, collatz).position(|n| n == 1);
+///
+/// assert_eq!(steps, Some(7));
+/// ```
+#[inline]
+pub fn iterate<B, F>(init: B, f: F) -> Iterate<B, F>
 where
-    I: fmt::Debug,
-    B: fmt::Debug,
+    B: Clone,
+    F: FnMut(B) -> B,
 {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        f.debug_struct("Accumulate")
-            .field("iter", &self.iter)
-            .field("acc", &self.acc)
-            .finish_non_exhaustive()
-    }
+    Iterate::new(init, f)
 }
 
-impl<I, B, F> Iterator for Accumulate<I, B, F>
+/// Creates an iterator that starts with `init` and repeatedly applies the provided closure to the
+/// accumulated value, yielding each result, until the closure returns [`None`].
+///
+/// This is the terminating variant of [`iterate()`]. The accumulated value is moved into the
+/// closure, so it is gone once the closure has returned [`None`], and the returned iterator keeps
+/// returning [`None`] from then on.
+///
+/// # Examples
+///
+/// An exponential backoff schedule with a cap:
+///
+/// ```
+/// let delays: Vec<_> = iter_accumulate::unfold(100, |delay| {
+///     let next = delay * 2;
+///     (next <= 1000).then_some(next)
+/// })
+/// .collect();
+///
+/// assert_eq!(delays, [200, 400, 800]);
+/// ```
+#[inline]
+pub fn unfold<B, F>(init: B, f: F) -> Unfold<B, F>
 where
-    I: Iterator,
-    B: Copy,
-    F: FnMut(B, I::Item) -> B,
+    B: Clone,
+    F: FnMut(B) -> Option<B>,
 {
-    type Item = B;
+    Unfold::new(init, f)
+}
 
+/// An [`Iterator`] blanket implementation that provides the [`accumulate()`](Self::accumulate)
+/// function.
+pub trait IterAccumulate: Iterator {
+    /// Creates an iterator adaptor that accumulates the elements from the base iterator using the
+    /// provided closure.
+    ///
+    /// See the [crate-level documentation](crate) for more information.
     #[inline]
-    fn next(&mut self) -> Option<Self::Item> {
-        match self.iter.next() {
-            Some(item) => {
-                self.acc = (self.f)(self.acc, item);
-                Some(self.acc)
-            }
-            None => None,
-        }
+    fn accumulate<B, F>(self, init: B, f: F) -> Accumulate<Self, B, F>
+    where
+        Self: Sized,
+        B: Clone,
+        F: FnMut(B, Self::Item) -> B,
+    {
+        Accumulate::new(self, init, f)
     }
 
+    /// Creates an iterator adaptor that accumulates the elements from the base iterator using the
+    /// provided closure, starting with the [default value](Default::default) of the accumulated
+    /// type.
+    ///
+    /// This is the same as `accumulate(B::default(), f)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iter_accumulate::IterAccumulate;
+    ///
+    /// let input = [1, 2, 3, 4, 5];
+    /// let mut iter = input.iter().accumulate_default(|acc: i32, i| acc + i);
+    ///
+    /// assert_eq!(iter.next(), Some(1));
+    /// assert_eq!(iter.next(), Some(3));
+    /// assert_eq!(iter.next(), Some(6));
+    /// assert_eq!(iter.next(), Some(10));
+    /// assert_eq!(iter.next(), Some(15));
+    /// assert_eq!(iter.next(), None);
+    /// ```
     #[inline]
-    fn size_hint(&self) -> (usize, Option<usize>) {
-        self.iter.size_hint()
+    fn accumulate_default<B, F>(self, f: F) -> Accumulate<Self, B, F>
+    where
+        Self: Sized,
+        B: Clone + Default,
+        F: FnMut(B, Self::Item) -> B,
+    {
+        Accumulate::new(self, B::default(), f)
     }
 
+    /// Creates an iterator adaptor that yields the initial value, and then accumulates the
+    /// elements from the base iterator using the provided closure.
+    ///
+    /// This is like [`accumulate()`](Self::accumulate), but `init` is yielded first, so the
+    /// returned iterator yields one element more than the base iterator. This matches Haskell's
+    /// `scanl` and Python's `itertools.accumulate()` with an `initial` value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iter_accumulate::IterAccumulate;
+    ///
+    /// let input = [1, 2, 3];
+    /// let mut iter = input.iter().accumulate_full(0, |acc, i| acc + i);
+    ///
+    /// assert_eq!(iter.len(), 4);
+    /// assert_eq!(iter.next(), Some(0));
+    /// assert_eq!(iter.next(), Some(1));
+    /// assert_eq!(iter.next(), Some(3));
+    /// assert_eq!(iter.next(), Some(6));
+    /// assert_eq!(iter.next(), None);
+    ///
+    /// let empty: [i32; 0] = [];
+    /// let full: Vec<_> = empty.iter().accumulate_full(0, |acc, i| acc + i).collect();
+    /// assert_eq!(full, [0]);
+    /// ```
     #[inline]
-    fn count(self) -> usize {
-        self.iter.count()
+    fn accumulate_full<B, F>(self, init: B, f: F) -> AccumulateFull<Self, B, F>
+    where
+        Self: Sized,
+        B: Clone,
+        F: FnMut(B, Self::Item) -> B,
+    {
+        AccumulateFull::new(self, init, f)
     }
-}
 
-/// An [`Iterator`] blanket implementation that provides the [`accumulate()`](Self::accumulate)
-/// function.
-pub trait IterAccumulate: Iterator {
     /// Creates an iterator adaptor that accumulates the elements from the base iterator using the
-    /// provided closure.
+    /// provided closure, and yields the accumulated value *before* each element is accumulated.
     ///
-    /// See the [crate-level documentation](crate) for more information.
+    /// This is an exclusive scan, like C++'s `std::exclusive_scan`: the first element yielded is
+    /// `init`, and the accumulation of all elements is not yielded at all. It can still be
+    /// retrieved with [`into_acc()`](ExclusiveAccumulate::into_acc) once the iterator is
+    /// exhausted.
+    ///
+    /// # Examples
+    ///
+    /// Computing the offsets of consecutive chunks from their lengths:
+    ///
+    /// ```
+    /// use iter_accumulate::IterAccumulate;
+    ///
+    /// let lengths = [3, 1, 4];
+    /// let mut iter = lengths.iter().exclusive_accumulate(0, |acc, len| acc + len);
+    ///
+    /// assert_eq!(iter.next(), Some(0));
+    /// assert_eq!(iter.next(), Some(3));
+    /// assert_eq!(iter.next(), Some(4));
+    /// assert_eq!(iter.next(), None);
+    /// assert_eq!(iter.into_acc(), 8);
+    /// ```
     #[inline]
-    fn accumulate<B, F>(self, init: B, f: F) -> Accumulate<Self, B, F>
+    fn exclusive_accumulate<B, F>(self, init: B, f: F) -> ExclusiveAccumulate<Self, B, F>
     where
         Self: Sized,
-        B: Copy,
+        B: Clone,
         F: FnMut(B, Self::Item) -> B,
     {
-        Accumulate::new(self, init, f)
+        ExclusiveAccumulate::new(self, init, f)
+    }
+
+    /// Creates an iterator adaptor that maps the elements from the base iterator using `map`, and
+    /// accumulates the results using `f`.
+    ///
+    /// This is equivalent to `map(map).accumulate(init, f)`, like C++'s
+    /// `std::transform_inclusive_scan`, but results in a single adaptor.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iter_accumulate::IterAccumulate;
+    ///
+    /// let words = ["an", "iterator", "adaptor"];
+    /// let mut iter = words.iter().map_accumulate(0, |word| word.len(), |acc, len| acc + len);
+    ///
+    /// assert_eq!(iter.next(), Some(2));
+    /// assert_eq!(iter.next(), Some(10));
+    /// assert_eq!(iter.next(), Some(17));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    #[inline]
+    fn map_accumulate<B, M, T, F>(self, init: B, map: M, f: F) -> MapAccumulate<Self, B, M, F>
+    where
+        Self: Sized,
+        B: Clone,
+        M: FnMut(Self::Item) -> T,
+        F: FnMut(B, T) -> B,
+    {
+        MapAccumulate::new(self.map(map), init, f)
+    }
+
+    /// Creates an iterator adaptor that threads an accumulated state through the elements from
+    /// the base iterator, and yields a separate output value for each element.
+    ///
+    /// The closure takes the current state and an element, and returns the next state together
+    /// with the value to yield, which makes the returned iterator a Mealy machine. Unlike with
+    /// [`Iterator::scan()`], the state is passed by value, and the closure cannot end the
+    /// iteration early. The state does not have to be [`Clone`], since it is never yielded.
+    ///
+    /// # Examples
+    ///
+    /// Detecting rising edges in a signal:
+    ///
+    /// ```
+    /// use iter_accumulate::IterAccumulate;
+    ///
+    /// let signal = [false, true, true, false, true];
+    /// let edges: Vec<_> = signal
+    ///     .iter()
+    ///     .accumulate_output(false, |prev, &level| (level, level && !prev))
+    ///     .collect();
+    ///
+    /// assert_eq!(edges, [false, true, false, false, true]);
+    /// ```
+    #[inline]
+    fn accumulate_output<B, F, O>(self, init: B, f: F) -> AccumulateOutput<Self, B, F>
+    where
+        Self: Sized,
+        F: FnMut(B, Self::Item) -> (B, O),
+    {
+        AccumulateOutput::new(self, init, f)
+    }
+
+    /// Creates an iterator adaptor that accumulates the elements from the base iterator using the
+    /// provided closure, which also receives the index of each element.
+    ///
+    /// This is like [`accumulate()`](Self::accumulate), but the closure is called as
+    /// `f(acc, index, item)`, with `index` counting from zero like with
+    /// [`enumerate()`](Iterator::enumerate).
+    ///
+    /// # Examples
+    ///
+    /// Computing the running mean:
+    ///
+    /// ```
+    /// use iter_accumulate::IterAccumulate;
+    ///
+    /// let input = [2.0, 4.0, 9.0];
+    /// let mut iter = input
+    ///     .iter()
+    ///     .accumulate_with_index(0.0, |mean, i, x| mean + (x - mean) / (i + 1) as f64);
+    ///
+    /// assert_eq!(iter.next(), Some(2.0));
+    /// assert_eq!(iter.next(), Some(3.0));
+    /// assert_eq!(iter.next(), Some(5.0));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    #[inline]
+    fn accumulate_with_index<B, F>(self, init: B, f: F) -> AccumulateWithIndex<Self, B, F>
+    where
+        Self: Sized,
+        B: Clone,
+        F: FnMut(B, usize, Self::Item) -> B,
+    {
+        AccumulateWithIndex::new(self.enumerate(), init, f)
+    }
+
+    /// Creates an iterator adaptor that accumulates the elements from the base iterator using the
+    /// provided closure, and yields each element together with the accumulated value.
+    ///
+    /// The closure only gets a reference to each element, so that the element itself can be
+    /// yielded as part of an `(item, acc)` pair without having to be cloned.
+    ///
+    /// # Examples
+    ///
+    /// Computing the end offset of each event:
+    ///
+    /// ```
+    /// use iter_accumulate::IterAccumulate;
+    ///
+    /// let events = [("open", 3), ("read", 10), ("close", 2)];
+    /// let mut iter = events
+    ///     .into_iter()
+    ///     .accumulate_with(0, |offset, (_, len)| offset + len);
+    ///
+    /// assert_eq!(iter.next(), Some((("open", 3), 3)));
+    /// assert_eq!(iter.next(), Some((("read", 10), 13)));
+    /// assert_eq!(iter.next(), Some((("close", 2), 15)));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    #[inline]
+    fn accumulate_with<B, F>(self, init: B, f: F) -> AccumulateWith<Self, B, F>
+    where
+        Self: Sized,
+        B: Clone,
+        F: FnMut(B, &Self::Item) -> B,
+    {
+        AccumulateWith::new(self, init, f)
+    }
+
+    /// Creates an iterator adaptor that accumulates the elements from the base iterator using `f`,
+    /// calls `inspect` with each accumulated value, and yields the elements unchanged.
+    ///
+    /// This is like [`Iterator::inspect()`], but for an accumulated value instead of the
+    /// elements themselves, which makes it useful for debugging or for collecting metrics without
+    /// restructuring an iterator chain. The accumulated value is never cloned, and `f` only gets a
+    /// reference to each element.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iter_accumulate::IterAccumulate;
+    ///
+    /// let mut seen = Vec::new();
+    /// let input = ["a", "bb", "ccc"];
+    /// let output: Vec<_> = input
+    ///     .iter()
+    ///     .inspect_acc(0, |bytes, s| bytes + s.len(), |&bytes| seen.push(bytes))
+    ///     .collect();
+    ///
+    /// assert_eq!(output, input.iter().collect::<Vec<_>>());
+    /// assert_eq!(seen, [1, 3, 6]);
+    /// ```
+    #[inline]
+    fn inspect_acc<B, F, G>(self, init: B, f: F, inspect: G) -> InspectAcc<Self, B, F, G>
+    where
+        Self: Sized,
+        F: FnMut(B, &Self::Item) -> B,
+        G: FnMut(&B),
+    {
+        InspectAcc::new(self, init, f, inspect)
+    }
+
+    /// Creates an iterator adaptor that accumulates the elements from the base iterator using the
+    /// provided closure, and only yields the accumulated value when it changes.
+    ///
+    /// This is like [`accumulate()`](Self::accumulate), but consecutive equal accumulated values
+    /// are only yielded once. The first accumulated value is always yielded, even if it is equal
+    /// to `init`.
+    ///
+    /// # Examples
+    ///
+    /// Turning a stream of severities into change events of the highest severity seen so far:
+    ///
+    /// ```
+    /// use iter_accumulate::IterAccumulate;
+    ///
+    /// let severities = [1, 0, 2, 2, 1, 3, 0];
+    /// let changes: Vec<_> = severities
+    ///     .iter()
+    ///     .dedup_acc(0, |max, &severity| max.max(severity))
+    ///     .collect();
+    ///
+    /// assert_eq!(changes, [1, 2, 3]);
+    /// ```
+    #[inline]
+    fn dedup_acc<B, F>(self, init: B, f: F) -> DedupAcc<Self, B, F>
+    where
+        Self: Sized,
+        B: Clone + PartialEq,
+        F: FnMut(B, Self::Item) -> B,
+    {
+        DedupAcc::new(self, init, f)
+    }
+
+    /// Creates an iterator adaptor that accumulates all elements from the base iterator using the
+    /// provided closure, but only yields every `k`-th accumulated value.
+    ///
+    /// The first value yielded is the accumulation of the first `k` elements, the second one the
+    /// accumulation of the first `2 * k` elements, and so on. Elements after the last full
+    /// stride of `k` elements are still accumulated when the iterator is exhausted, and the result
+    /// can be retrieved with [`into_acc()`](AccumulateEvery::into_acc).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `k` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iter_accumulate::IterAccumulate;
+    ///
+    /// let mut iter = (1..11).accumulate_every(3, 0, |acc, i| acc + i);
+    ///
+    /// assert_eq!(iter.len(), 3);
+    /// assert_eq!(iter.next(), Some(6));
+    /// assert_eq!(iter.next(), Some(21));
+    /// assert_eq!(iter.next(), Some(45));
+    /// assert_eq!(iter.next(), None);
+    /// assert_eq!(iter.into_acc(), 55);
+    /// ```
+    #[inline]
+    fn accumulate_every<B, F>(self, k: usize, init: B, f: F) -> AccumulateEvery<Self, B, F>
+    where
+        Self: Sized,
+        B: Clone,
+        F: FnMut(B, Self::Item) -> B,
+    {
+        AccumulateEvery::new(self, k, init, f)
+    }
+
+    /// Creates an iterator adaptor that threads an accumulated state through the elements from
+    /// the base iterator, and flattens the outputs produced for each element.
+    ///
+    /// This is like [`accumulate_output()`](IterAccumulate::accumulate_output), except that the
+    /// closure returns any number of outputs per element as an [`IntoIterator`], which makes it
+    /// suitable for stateful expanders and tokenizers. The state does not have to be [`Clone`].
+    ///
+    /// # Examples
+    ///
+    /// Expanding tabs to the next tab stop, which depends on the current column:
+    ///
+    /// ```
+    /// use iter_accumulate::IterAccumulate;
+    /// use std::iter;
+    ///
+    /// let expanded: String = "a\tbc\td"
+    ///     .chars()
+    ///     .accumulate_flat_map(0, |column, c| {
+    ///         let width = if c == '\t' { 4 - column % 4 } else { 1 };
+    ///         let c = if c == '\t' { ' ' } else { c };
+    ///         (column + width, iter::repeat(c).take(width))
+    ///     })
+    ///     .collect();
+    ///
+    /// assert_eq!(expanded, "a   bc  d");
+    /// ```
+    #[inline]
+    fn accumulate_flat_map<B, F, U>(self, init: B, f: F) -> AccumulateFlatMap<Self, B, F, U>
+    where
+        Self: Sized,
+        F: FnMut(B, Self::Item) -> (B, U),
+        U: IntoIterator,
+    {
+        AccumulateFlatMap::new(self, init, f)
+    }
+
+    /// Creates an iterator adaptor that accumulates the elements from the base iterator using the
+    /// provided closure, which is passed the last `N` accumulated values.
+    ///
+    /// The closure takes the last `N` accumulated values, ordered from oldest to newest, and an
+    /// element, and returns the next accumulated value, which is then yielded. `init` provides
+    /// the `N` values that precede the first element. This makes it possible to express
+    /// higher-order recurrences, like the Fibonacci sequence or second-order filters, without
+    /// keeping a buffer of previous values by hand.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iter_accumulate::IterAccumulate;
+    ///
+    /// let fibonacci: Vec<_> = (0..8)
+    ///     .accumulate_recurrence([0, 1], |&[a, b], _| a + b)
+    ///     .collect();
+    ///
+    /// assert_eq!(fibonacci, [1, 2, 3, 5, 8, 13, 21, 34]);
+    /// ```
+    #[inline]
+    fn accumulate_recurrence<const N: usize, B, F>(
+        self,
+        init: [B; N],
+        f: F,
+    ) -> AccumulateRecurrence<Self, B, F, N>
+    where
+        Self: Sized,
+        B: Clone,
+        F: FnMut(&[B; N], Self::Item) -> B,
+    {
+        AccumulateRecurrence::new(self, init, f)
+    }
+
+    /// Creates an iterator adaptor that advances the base iterator and `other` in lock-step and
+    /// accumulates both of their elements using the provided closure.
+    ///
+    /// The closure takes the current accumulated value and one element from each iterator, and
+    /// returns the next accumulated value, which is then yielded. Like with [`Iterator::zip()`],
+    /// the returned iterator stops as soon as either iterator returns [`None`], and it is the
+    /// same as `self.zip(other).accumulate(init, |acc, (a, b)| f(acc, a, b))`, without the tuple.
+    ///
+    /// # Examples
+    ///
+    /// A running dot product:
+    ///
+    /// ```
+    /// use iter_accumulate::IterAccumulate;
+    ///
+    /// let xs = [1, 2, 3];
+    /// let ys = [4, 5, 6, 7];
+    /// let mut iter = xs.iter().zip_accumulate(&ys, 0, |acc, x, y| acc + x * y);
+    ///
+    /// assert_eq!(iter.len(), 3);
+    /// assert_eq!(iter.next(), Some(4));
+    /// assert_eq!(iter.next(), Some(14));
+    /// assert_eq!(iter.next(), Some(32));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    #[inline]
+    fn zip_accumulate<U, B, F>(
+        self,
+        other: U,
+        init: B,
+        f: F,
+    ) -> ZipAccumulate<Self, U::IntoIter, B, F>
+    where
+        Self: Sized,
+        U: IntoIterator,
+        B: Clone,
+        F: FnMut(B, Self::Item, U::Item) -> B,
+    {
+        ZipAccumulate::new(self, other.into_iter(), init, f)
+    }
+
+    /// Creates an iterator adaptor that accumulates copies of the referenced elements from the
+    /// base iterator using the provided closure.
+    ///
+    /// This is the same as calling [`copied()`](Iterator::copied) before
+    /// [`accumulate()`](Self::accumulate), and saves writing `|acc, &i|` in the closure when
+    /// accumulating over e.g. [`slice::iter()`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iter_accumulate::IterAccumulate;
+    ///
+    /// let input = [1, 2, 3];
+    /// let mut iter = input.iter().accumulate_copied(0, |acc, i| acc + i);
+    ///
+    /// assert_eq!(iter.next(), Some(1));
+    /// assert_eq!(iter.next(), Some(3));
+    /// assert_eq!(iter.next(), Some(6));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    #[inline]
+    fn accumulate_copied<'a, T, B, F>(self, init: B, f: F) -> Accumulate<iter::Copied<Self>, B, F>
+    where
+        Self: Sized + Iterator<Item = &'a T>,
+        T: Copy + 'a,
+        B: Clone,
+        F: FnMut(B, T) -> B,
+    {
+        Accumulate::new(self.copied(), init, f)
+    }
+
+    /// Creates an iterator adaptor that accumulates clones of the referenced elements from the
+    /// base iterator using the provided closure.
+    ///
+    /// This is the same as calling [`cloned()`](Iterator::cloned) before
+    /// [`accumulate()`](Self::accumulate). Prefer
+    /// [`accumulate_copied()`](Self::accumulate_copied) for [`Copy`] types.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iter_accumulate::IterAccumulate;
+    ///
+    /// let input = [String::from("foo"), String::from("bar")];
+    /// let mut iter = input.iter().accumulate_cloned_items(Vec::new(), |mut acc, s| {
+    ///     acc.push(s);
+    ///     acc
+    /// });
+    ///
+    /// assert_eq!(iter.next(), Some(vec![String::from("foo")]));
+    /// assert_eq!(iter.next(), Some(vec![String::from("foo"), String::from("bar")]));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    #[inline]
+    fn accumulate_cloned_items<'a, T, B, F>(
+        self,
+        init: B,
+        f: F,
+    ) -> Accumulate<iter::Cloned<Self>, B, F>
+    where
+        Self: Sized + Iterator<Item = &'a T>,
+        T: Clone + 'a,
+        B: Clone,
+        F: FnMut(B, T) -> B,
+    {
+        Accumulate::new(self.cloned(), init, f)
+    }
+
+    /// Creates an iterator adaptor that accumulates the elements from the base iterator using the
+    /// provided closure, which is boxed.
+    ///
+    /// This is the same as [`accumulate()`](Self::accumulate), but the returned
+    /// [`BoxedAccumulate`] has a nameable type, so it can be stored in struct fields or returned
+    /// from functions without generics.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iter_accumulate::{BoxedAccumulate, IterAccumulate};
+    ///
+    /// struct Totals<'a> {
+    ///     iter: BoxedAccumulate<'a, std::slice::Iter<'a, u32>, u32>,
+    /// }
+    ///
+    /// let input = [1, 2, 3];
+    /// let mut totals = Totals {
+    ///     iter: input.iter().accumulate_boxed(0, |acc, i| acc + i),
+    /// };
+    ///
+    /// assert_eq!(totals.iter.next(), Some(1));
+    /// assert_eq!(totals.iter.next(), Some(3));
+    /// assert_eq!(totals.iter.next(), Some(6));
+    /// assert_eq!(totals.iter.next(), None);
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn accumulate_boxed<'a, B, F>(self, init: B, f: F) -> BoxedAccumulate<'a, Self, B>
+    where
+        Self: Sized,
+        B: Clone,
+        F: FnMut(B, Self::Item) -> B + 'a,
+    {
+        Accumulate::new(self, init, alloc::boxed::Box::new(f))
+    }
+
+    /// Creates an iterator adaptor that accumulates the elements from the base iterator using the
+    /// provided closure `f`, until two successive accumulated values have converged.
+    ///
+    /// The predicate `converged` takes the previous and the next accumulated value. Once it
+    /// returns `true`, the next value is yielded as the last element, and the returned iterator is
+    /// finished afterwards. The first accumulated value is compared with `init`. Since both values
+    /// are needed at the same time, the previous value is cloned before it is passed to `f`.
+    ///
+    /// Pass [`PartialEq::eq`] as the predicate to stop at a fixed point, or compare the difference
+    /// against a tolerance for floating point computations.
+    ///
+    /// # Examples
+    ///
+    /// Approximating the square root of 2 using Newton's method:
+    ///
+    /// ```
+    /// use iter_accumulate::IterAccumulate;
+    /// use std::iter;
+    ///
+    /// let sqrt_2 = iter::repeat(())
+    ///     .accumulate_converge(1.0, |x: f64, ()| (x + 2.0 / x) / 2.0, |a, b| (a - b).abs() < 1e-9)
+    ///     .last()
+    ///     .unwrap();
+    ///
+    /// assert!((sqrt_2 - std::f64::consts::SQRT_2).abs() < 1e-12);
+    /// ```
+    ///
+    /// Stopping at a fixed point:
+    ///
+    /// ```
+    /// use iter_accumulate::IterAccumulate;
+    ///
+    /// let input = [1, 2, 0, 0, 7];
+    /// let sums: Vec<_> = input
+    ///     .iter()
+    ///     .accumulate_converge(0, |acc, i| acc + i, PartialEq::eq)
+    ///     .collect();
+    ///
+    /// assert_eq!(sums, [1, 3, 3]);
+    /// ```
+    #[inline]
+    fn accumulate_converge<B, F, P>(
+        self,
+        init: B,
+        f: F,
+        converged: P,
+    ) -> AccumulateConverge<Self, B, F, P>
+    where
+        Self: Sized,
+        B: Clone,
+        F: FnMut(B, Self::Item) -> B,
+        P: FnMut(&B, &B) -> bool,
+    {
+        AccumulateConverge::new(self, init, f, converged)
+    }
+
+    /// Creates an iterator adaptor that yields the running sums of the elements from the base
+    /// iterator, starting from the [default value](Default::default) of the sum type.
+    ///
+    /// This is the same as `accumulate(B::default(), |acc, i| acc + i)`. Like with
+    /// [`Iterator::sum()`], the sum type can differ from the element type, e.g. to sum up
+    /// references.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iter_accumulate::IterAccumulate;
+    ///
+    /// let input = [1, 2, 3, 4];
+    /// let sums: Vec<i32> = input.iter().cumsum().collect();
+    ///
+    /// assert_eq!(sums, [1, 3, 6, 10]);
+    /// ```
+    #[inline]
+    fn cumsum<B>(self) -> CumSum<Self, B>
+    where
+        Self: Sized,
+        B: Add<Self::Item, Output = B> + Clone + Default,
+    {
+        self.cumsum_from(B::default())
+    }
+
+    /// Creates an iterator adaptor that yields the running sums of the elements from the base
+    /// iterator, starting from `zero`.
+    ///
+    /// This is the same as `accumulate(zero, |acc, i| acc + i)`, see also
+    /// [`cumsum()`](Self::cumsum).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iter_accumulate::IterAccumulate;
+    ///
+    /// let deposits = [20, 5, 15];
+    /// let mut balances = deposits.iter().cumsum_from(100);
+    ///
+    /// assert_eq!(balances.next(), Some(120));
+    /// assert_eq!(balances.next(), Some(125));
+    /// assert_eq!(balances.next(), Some(140));
+    /// assert_eq!(balances.next(), None);
+    /// ```
+    #[inline]
+    fn cumsum_from<B>(self, zero: B) -> CumSum<Self, B>
+    where
+        Self: Sized,
+        B: Add<Self::Item, Output = B> + Clone,
+    {
+        Accumulate::new(self, zero, B::add)
+    }
+
+    /// Creates an iterator adaptor that yields the running products of the elements from the base
+    /// iterator, starting from one.
+    ///
+    /// One is taken to be the [product](iter::Product) of no elements, which is implemented for
+    /// all primitive number types. Like with [`Iterator::product()`], the product type can differ
+    /// from the element type, e.g. to multiply references.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iter_accumulate::IterAccumulate;
+    ///
+    /// let survival_rates = [0.5, 0.5, 0.25];
+    /// let survival: Vec<f64> = survival_rates.iter().cumprod().collect();
+    ///
+    /// assert_eq!(survival, [0.5, 0.25, 0.0625]);
+    /// ```
+    #[inline]
+    fn cumprod<B>(self) -> CumProd<Self, B>
+    where
+        Self: Sized,
+        B: Mul<Self::Item, Output = B> + Clone + iter::Product,
+    {
+        self.cumprod_from(iter::empty().product())
+    }
+
+    /// Creates an iterator adaptor that yields the running products of the elements from the base
+    /// iterator, starting from `one`.
+    ///
+    /// This is the same as `accumulate(one, |acc, i| acc * i)`, see also
+    /// [`cumprod()`](Self::cumprod).
+    ///
+    /// # Examples
+    ///
+    /// Scaling an initial amount by successive growth factors:
+    ///
+    /// ```
+    /// use iter_accumulate::IterAccumulate;
+    ///
+    /// let growth_factors = [2, 3, 2];
+    /// let amounts: Vec<_> = growth_factors.iter().cumprod_from(100).collect();
+    ///
+    /// assert_eq!(amounts, [200, 600, 1200]);
+    /// ```
+    ///
+    /// The accumulated value is always the left operand, so this also composes non-commutative
+    /// transforms, like the joints of a kinematic chain that are each relative to the one before
+    /// it. Every yielded value is the world transform of that joint:
+    ///
+    /// ```
+    /// use iter_accumulate::IterAccumulate;
+    /// use std::ops::Mul;
+    ///
+    /// /// A 1D transform that scales and then offsets a coordinate.
+    /// #[derive(Clone, Copy, Debug, PartialEq)]
+    /// struct Transform {
+    ///     scale: i32,
+    ///     offset: i32,
+    /// }
+    ///
+    /// impl Mul for Transform {
+    ///     type Output = Self;
+    ///
+    ///     fn mul(self, local: Self) -> Self {
+    ///         let scale = self.scale * local.scale;
+    ///         let offset = self.scale * local.offset + self.offset;
+    ///         Self { scale, offset }
+    ///     }
+    /// }
+    ///
+    /// let root = Transform { scale: 1, offset: 10 };
+    /// let joints = [
+    ///     Transform { scale: 2, offset: 1 },
+    ///     Transform { scale: 1, offset: 3 },
+    /// ];
+    /// let world: Vec<_> = joints.into_iter().cumprod_from(root).collect();
+    ///
+    /// assert_eq!(
+    ///     world,
+    ///     [
+    ///         Transform { scale: 2, offset: 11 },
+    ///         Transform { scale: 2, offset: 17 },
+    ///     ]
+    /// );
+    /// ```
+    #[inline]
+    fn cumprod_from<B>(self, one: B) -> CumProd<Self, B>
+    where
+        Self: Sized,
+        B: Mul<Self::Item, Output = B> + Clone,
+    {
+        Accumulate::new(self, one, B::mul)
+    }
+
+    /// Creates an iterator adaptor that yields the running products of the elements from the base
+    /// iterator, starting from `one`, multiplying by reference.
+    ///
+    /// This is like [`cumprod_from()`](Self::cumprod_from), but computes `&acc * &item`, for
+    /// types that are not [`Copy`] and only implement multiplication of references, like
+    /// dynamically sized matrices.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iter_accumulate::IterAccumulate;
+    /// use std::ops::Mul;
+    ///
+    /// #[derive(Clone, Debug, PartialEq)]
+    /// struct Matrix(Vec<[i32; 2]>);
+    ///
+    /// impl Mul for &Matrix {
+    ///     type Output = Matrix;
+    ///
+    ///     fn mul(self, rhs: Self) -> Matrix {
+    ///         let cell = |i: usize, j| self.0[i][0] * rhs.0[0][j] + self.0[i][1] * rhs.0[1][j];
+    ///         Matrix(vec![[cell(0, 0), cell(0, 1)], [cell(1, 0), cell(1, 1)]])
+    ///     }
+    /// }
+    ///
+    /// let identity = Matrix(vec![[1, 0], [0, 1]]);
+    /// let rotate = Matrix(vec![[0, -1], [1, 0]]);
+    /// let shear = Matrix(vec![[1, 1], [0, 1]]);
+    /// let world: Vec<_> = [rotate, shear]
+    ///     .into_iter()
+    ///     .cumprod_from_ref(identity)
+    ///     .collect();
+    ///
+    /// assert_eq!(world[0], Matrix(vec![[0, -1], [1, 0]]));
+    /// assert_eq!(world[1], Matrix(vec![[0, -1], [1, 1]]));
+    /// ```
+    #[inline]
+    fn cumprod_from_ref<B>(self, one: B) -> CumProd<Self, B>
+    where
+        Self: Sized,
+        B: Clone,
+        for<'a> &'a B: Mul<&'a Self::Item, Output = B>,
+    {
+        Accumulate::new(self, one, cum::mul_ref)
+    }
+
+    /// Creates an iterator adaptor that yields the running maximum of the elements from the base
+    /// iterator.
+    ///
+    /// This is the same as `accumulate_first(Ord::max)`. If several elements are equally maximal,
+    /// the last one is yielded, like with [`Iterator::max()`].
+    ///
+    /// # Examples
+    ///
+    /// Computing the drawdown of a price series, i.e. how far each price is below the highest
+    /// price so far:
+    ///
+    /// ```
+    /// use iter_accumulate::IterAccumulate;
+    ///
+    /// let prices = [10, 12, 9, 11, 15, 13];
+    /// let drawdowns: Vec<_> = prices
+    ///     .iter()
+    ///     .cummax()
+    ///     .zip(&prices)
+    ///     .map(|(peak, price)| peak - price)
+    ///     .collect();
+    ///
+    /// assert_eq!(drawdowns, [0, 0, 3, 1, 0, 2]);
+    /// ```
+    #[inline]
+    fn cummax(self) -> CumExtremum<Self>
+    where
+        Self: Sized,
+        Self::Item: Ord + Clone,
+    {
+        self.accumulate_first(Ord::max)
+    }
+
+    /// Creates an iterator adaptor that yields the running minimum of the elements from the base
+    /// iterator.
+    ///
+    /// This is the same as `accumulate_first(Ord::min)`. If several elements are equally minimal,
+    /// the first one is yielded, like with [`Iterator::min()`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iter_accumulate::IterAccumulate;
+    ///
+    /// let input = [5, 3, 4, 1, 2];
+    /// let minima: Vec<_> = input.into_iter().cummin().collect();
+    ///
+    /// assert_eq!(minima, [5, 3, 3, 1, 1]);
+    /// ```
+    #[inline]
+    fn cummin(self) -> CumExtremum<Self>
+    where
+        Self: Sized,
+        Self::Item: Ord + Clone,
+    {
+        self.accumulate_first(Ord::min)
+    }
+
+    /// Creates an iterator adaptor that yields the running [`Join`] of the elements from the base
+    /// iterator, i.e. the least bound of all elements so far.
+    ///
+    /// This is the same as `accumulate_first(Join::join)`, e.g. for running bounding boxes or
+    /// interval hulls.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iter_accumulate::IterAccumulate;
+    ///
+    /// let spans = [3..5, 1..2, 4..8];
+    /// let hulls: Vec<_> = spans.into_iter().running_join().collect();
+    ///
+    /// assert_eq!(hulls, [3..5, 1..5, 1..8]);
+    /// ```
+    #[inline]
+    fn running_join(self) -> RunningJoin<Self>
+    where
+        Self: Sized,
+        Self::Item: Join + Clone,
+    {
+        self.accumulate_first(Join::join)
+    }
+
+    /// Creates an iterator adaptor that yields the running minimum and maximum of the elements
+    /// from the base iterator as a `(min, max)` pair.
+    ///
+    /// This computes the values of both [`cummin()`](Self::cummin) and
+    /// [`cummax()`](Self::cummax) in a single pass, with the same behavior for equal elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iter_accumulate::IterAccumulate;
+    ///
+    /// let input = [3, 1, 4, 1, 5];
+    /// let extrema: Vec<_> = input.into_iter().running_extrema().collect();
+    ///
+    /// assert_eq!(extrema, [(3, 3), (1, 3), (1, 4), (1, 4), (1, 5)]);
+    /// ```
+    #[inline]
+    fn running_extrema(self) -> RunningExtrema<Self>
+    where
+        Self: Sized,
+        Self::Item: Ord + Clone,
+    {
+        self.map(cum::pair as fn(_) -> _)
+            .accumulate_first(|(min, max), (x, y)| (min.min(x), max.max(y)))
+    }
+
+    /// Creates an iterator adaptor that yields the index and value of the element with the
+    /// maximum key so far.
+    ///
+    /// The key of every element is computed once using `f`. If several elements have equally
+    /// maximal keys, the last one is yielded, like with [`Iterator::max_by_key()`].
+    ///
+    /// # Examples
+    ///
+    /// Tracking the leader of a game:
+    ///
+    /// ```
+    /// use iter_accumulate::IterAccumulate;
+    ///
+    /// let scores = [("ann", 3), ("bob", 5), ("cid", 4), ("dee", 5)];
+    /// let leaders: Vec<_> = scores
+    ///     .iter()
+    ///     .running_argmax_by_key(|(_, score)| *score)
+    ///     .map(|(index, (name, _))| (index, *name))
+    ///     .collect();
+    ///
+    /// assert_eq!(leaders, [(0, "ann"), (1, "bob"), (1, "bob"), (3, "dee")]);
+    /// ```
+    #[inline]
+    fn running_argmax_by_key<K, F>(self, f: F) -> RunningArgBest<Self, K, F>
+    where
+        Self: Sized,
+        Self::Item: Clone,
+        K: Ord,
+        F: FnMut(&Self::Item) -> K,
+    {
+        RunningArgBest::new(self, f, true)
+    }
+
+    /// Creates an iterator adaptor that yields the index and value of the element with the
+    /// minimum key so far.
+    ///
+    /// The key of every element is computed once using `f`. If several elements have equally
+    /// minimal keys, the first one is yielded, like with [`Iterator::min_by_key()`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iter_accumulate::IterAccumulate;
+    ///
+    /// let latencies = [12, 9, 15, 9, 7];
+    /// let fastest: Vec<_> = latencies
+    ///     .iter()
+    ///     .running_argmin_by_key(|&&latency| latency)
+    ///     .map(|(index, _)| index)
+    ///     .collect();
+    ///
+    /// assert_eq!(fastest, [0, 1, 1, 1, 4]);
+    /// ```
+    #[inline]
+    fn running_argmin_by_key<K, F>(self, f: F) -> RunningArgBest<Self, K, F>
+    where
+        Self: Sized,
+        Self::Item: Clone,
+        K: Ord,
+        F: FnMut(&Self::Item) -> K,
+    {
+        RunningArgBest::new(self, f, false)
+    }
+
+    /// Creates an iterator adaptor that yields the running maximum of the floating point numbers
+    /// from the base iterator, treating NaN values according to `policy`.
+    ///
+    /// This is the floating point counterpart of [`cummax()`](Self::cummax). See [`NanPolicy`]
+    /// for the available policies.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iter_accumulate::{IterAccumulate, NanPolicy};
+    ///
+    /// let input = [1.0, f64::NAN, 3.0, 2.0];
+    ///
+    /// let ignored: Vec<_> = input.into_iter().cummax_float(NanPolicy::Ignore).collect();
+    /// assert_eq!(ignored, [1.0, 1.0, 3.0, 3.0]);
+    ///
+    /// let propagated: Vec<_> = input.into_iter().cummax_float(NanPolicy::Propagate).collect();
+    /// assert_eq!(propagated[0], 1.0);
+    /// assert!(propagated[1..].iter().all(|x| x.is_nan()));
+    /// ```
+    #[inline]
+    fn cummax_float(self, policy: NanPolicy) -> CumExtremum<Self>
+    where
+        Self: Sized,
+        Self::Item: Float,
+    {
+        self.accumulate_first(policy.extremum(true))
+    }
+
+    /// Creates an iterator adaptor that yields the running minimum of the floating point numbers
+    /// from the base iterator, treating NaN values according to `policy`.
+    ///
+    /// This is the floating point counterpart of [`cummin()`](Self::cummin). See [`NanPolicy`]
+    /// for the available policies.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iter_accumulate::{IterAccumulate, NanPolicy};
+    ///
+    /// let input = [2.0, -f64::NAN, 1.0, 3.0];
+    ///
+    /// let ignored: Vec<_> = input.into_iter().cummin_float(NanPolicy::Ignore).collect();
+    /// assert_eq!(ignored, [2.0, 2.0, 1.0, 1.0]);
+    ///
+    /// // Negative NaN is below all numbers in the total order
+    /// let total: Vec<_> = input.into_iter().cummin_float(NanPolicy::TotalOrder).collect();
+    /// assert_eq!(total[0], 2.0);
+    /// assert!(total[1..].iter().all(|x| x.is_nan()));
+    /// ```
+    #[inline]
+    fn cummin_float(self, policy: NanPolicy) -> CumExtremum<Self>
+    where
+        Self: Sized,
+        Self::Item: Float,
+    {
+        self.accumulate_first(policy.extremum(false))
+    }
+
+    /// Creates an iterator adaptor that yields the running sums of the integers from the base
+    /// iterator wrapped in [`Ok`], and stops when the sum overflows.
+    ///
+    /// On overflow, [`Err(Overflow)`](Overflow) is yielded, and afterwards, the returned iterator
+    /// is finished. This is the same as
+    /// `try_accumulate(0, |acc, i| acc.checked_add(i).ok_or(Overflow))`, see
+    /// [`try_accumulate()`](Self::try_accumulate).
+    ///
+    /// # Examples
+    ///
+    /// Computing the offsets of length-prefixed records from untrusted input:
+    ///
+    /// ```
+    /// use iter_accumulate::{IterAccumulate, Overflow};
+    ///
+    /// let lengths = [100u8, 100, 100];
+    /// let mut offsets = lengths.into_iter().checked_cumsum();
+    ///
+    /// assert_eq!(offsets.next(), Some(Ok(100)));
+    /// assert_eq!(offsets.next(), Some(Ok(200)));
+    /// assert_eq!(offsets.next(), Some(Err(Overflow)));
+    /// assert_eq!(offsets.next(), None);
+    ///
+    /// let offsets: Result<Vec<_>, _> = lengths.into_iter().checked_cumsum().collect();
+    /// assert_eq!(offsets, Err(Overflow));
+    /// ```
+    #[inline]
+    fn checked_cumsum(self) -> CheckedCum<Self>
+    where
+        Self: Sized,
+        Self::Item: Integer,
+    {
+        TryAccumulate::new(self, Integer::ZERO, |acc: Self::Item, i| {
+            acc.checked_add(i).ok_or(Overflow)
+        })
+    }
+
+    /// Creates an iterator adaptor that yields the running products of the integers from the
+    /// base iterator wrapped in [`Ok`], and stops when the product overflows.
+    ///
+    /// On overflow, [`Err(Overflow)`](Overflow) is yielded, and afterwards, the returned iterator
+    /// is finished. See also [`checked_cumsum()`](Self::checked_cumsum).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iter_accumulate::{IterAccumulate, Overflow};
+    ///
+    /// let factors = [10i8, 10, 10];
+    /// let mut products = factors.into_iter().checked_cumprod();
+    ///
+    /// assert_eq!(products.next(), Some(Ok(10)));
+    /// assert_eq!(products.next(), Some(Ok(100)));
+    /// assert_eq!(products.next(), Some(Err(Overflow)));
+    /// assert_eq!(products.next(), None);
+    /// ```
+    #[inline]
+    fn checked_cumprod(self) -> CheckedCum<Self>
+    where
+        Self: Sized,
+        Self::Item: Integer,
+    {
+        TryAccumulate::new(self, Integer::ONE, |acc: Self::Item, i| {
+            acc.checked_mul(i).ok_or(Overflow)
+        })
+    }
+
+    /// Creates an iterator adaptor that yields the running sums of the integers from the base
+    /// iterator, wrapping around on overflow.
+    ///
+    /// This is useful for modular arithmetic, like sequence numbers or checksums.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iter_accumulate::IterAccumulate;
+    ///
+    /// let increments = [200u8, 50, 10];
+    /// let sums: Vec<_> = increments.into_iter().wrapping_cumsum().collect();
+    ///
+    /// assert_eq!(sums, [200, 250, 4]);
+    /// ```
+    #[inline]
+    fn wrapping_cumsum(self) -> CumSum<Self, Self::Item>
+    where
+        Self: Sized,
+        Self::Item: Integer,
+    {
+        Accumulate::new(self, Integer::ZERO, Integer::wrapping_add)
+    }
+
+    /// Creates an iterator adaptor that yields the running products of the integers from the
+    /// base iterator, wrapping around on overflow.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iter_accumulate::IterAccumulate;
+    ///
+    /// let factors = [16u8, 4, 8];
+    /// let products: Vec<_> = factors.into_iter().wrapping_cumprod().collect();
+    ///
+    /// assert_eq!(products, [16, 64, 0]);
+    /// ```
+    #[inline]
+    fn wrapping_cumprod(self) -> CumProd<Self, Self::Item>
+    where
+        Self: Sized,
+        Self::Item: Integer,
+    {
+        Accumulate::new(self, Integer::ONE, Integer::wrapping_mul)
+    }
+
+    /// Creates an iterator adaptor that yields the running sums of the integers from the base
+    /// iterator, saturating at the numeric bounds instead of overflowing.
+    ///
+    /// Once the sum has saturated, it can still move away from the bound again.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iter_accumulate::IterAccumulate;
+    ///
+    /// let samples = [100i8, 50, -30];
+    /// let sums: Vec<_> = samples.into_iter().saturating_cumsum().collect();
+    ///
+    /// assert_eq!(sums, [100, 127, 97]);
+    /// ```
+    #[inline]
+    fn saturating_cumsum(self) -> CumSum<Self, Self::Item>
+    where
+        Self: Sized,
+        Self::Item: Integer,
+    {
+        Accumulate::new(self, Integer::ZERO, Integer::saturating_add)
+    }
+
+    /// Creates an iterator adaptor that yields the running products of the integers from the
+    /// base iterator, saturating at the numeric bounds instead of overflowing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iter_accumulate::IterAccumulate;
+    ///
+    /// let factors = [10u8, 10, 10];
+    /// let products: Vec<_> = factors.into_iter().saturating_cumprod().collect();
+    ///
+    /// assert_eq!(products, [10, 100, 255]);
+    /// ```
+    #[inline]
+    fn saturating_cumprod(self) -> CumProd<Self, Self::Item>
+    where
+        Self: Sized,
+        Self::Item: Integer,
+    {
+        Accumulate::new(self, Integer::ONE, Integer::saturating_mul)
+    }
+
+    /// Creates an iterator adaptor that yields the wrapping running sums of the integers from the
+    /// base iterator, together with whether each addition overflowed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iter_accumulate::IterAccumulate;
+    ///
+    /// let increments = [200u8, 50, 10];
+    /// let sums: Vec<_> = increments.into_iter().overflowing_cumsum().collect();
+    ///
+    /// assert_eq!(sums, [(200, false), (250, false), (4, true)]);
+    /// ```
+    #[inline]
+    fn overflowing_cumsum(self) -> OverflowingCum<Self>
+    where
+        Self: Sized,
+        Self::Item: Integer,
+    {
+        AccumulateOutput::new(self, Integer::ZERO, |acc: Self::Item, i| {
+            let (sum, overflowed) = acc.overflowing_add(i);
+            (sum, (sum, overflowed))
+        })
+    }
+
+    /// Creates an iterator adaptor that yields the wrapping running products of the integers from
+    /// the base iterator, together with whether each multiplication overflowed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iter_accumulate::IterAccumulate;
+    ///
+    /// let factors = [16u8, 4, 8];
+    /// let products: Vec<_> = factors.into_iter().overflowing_cumprod().collect();
+    ///
+    /// assert_eq!(products, [(16, false), (64, false), (0, true)]);
+    /// ```
+    #[inline]
+    fn overflowing_cumprod(self) -> OverflowingCum<Self>
+    where
+        Self: Sized,
+        Self::Item: Integer,
+    {
+        AccumulateOutput::new(self, Integer::ONE, |acc: Self::Item, i| {
+            let (product, overflowed) = acc.overflowing_mul(i);
+            (product, (product, overflowed))
+        })
+    }
+
+    /// Creates an iterator adaptor that yields the running bitwise XOR of the integers from the
+    /// base iterator, starting from zero.
+    ///
+    /// The XOR of a range of elements is the XOR of the prefixes before and at its ends, which
+    /// makes the prefix XORs a building block for range queries.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iter_accumulate::IterAccumulate;
+    ///
+    /// let input = [0b0011u8, 0b0101, 0b1001, 0b0110];
+    /// let prefix: Vec<_> = input.into_iter().cumxor().collect();
+    /// assert_eq!(prefix, [0b0011, 0b0110, 0b1111, 0b1001]);
+    ///
+    /// // The XOR of `input[1..=3]`
+    /// assert_eq!(prefix[3] ^ prefix[0], 0b0101 ^ 0b1001 ^ 0b0110);
+    /// ```
+    #[inline]
+    fn cumxor(self) -> CumBitwise<Self>
+    where
+        Self: Sized,
+        Self::Item: Integer,
+    {
+        Accumulate::new(self, Integer::ZERO, |acc, i| acc ^ i)
+    }
+
+    /// Creates an iterator adaptor that yields the running bitwise AND of the integers from the
+    /// base iterator, starting with all bits set.
+    ///
+    /// # Examples
+    ///
+    /// Tracking which flags were set in every element so far:
+    ///
+    /// ```
+    /// use iter_accumulate::IterAccumulate;
+    ///
+    /// let input = [0b1110u8, 0b0111, 0b1101];
+    /// let common: Vec<_> = input.into_iter().cumand().collect();
+    ///
+    /// assert_eq!(common, [0b1110, 0b0110, 0b0100]);
+    /// ```
+    #[inline]
+    fn cumand(self) -> CumBitwise<Self>
+    where
+        Self: Sized,
+        Self::Item: Integer,
+    {
+        Accumulate::new(self, !Self::Item::ZERO, |acc, i| acc & i)
+    }
+
+    /// Creates an iterator adaptor that yields the running bitwise OR of the integers from the
+    /// base iterator, starting from zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iter_accumulate::IterAccumulate;
+    ///
+    /// let input = [0b0001u8, 0b0100, 0b0101, 0b1000];
+    /// let seen: Vec<_> = input.into_iter().cumor().collect();
+    ///
+    /// assert_eq!(seen, [0b0001, 0b0101, 0b0101, 0b1101]);
+    /// ```
+    #[inline]
+    fn cumor(self) -> CumBitwise<Self>
+    where
+        Self: Sized,
+        Self::Item: Integer,
+    {
+        Accumulate::new(self, Integer::ZERO, |acc, i| acc | i)
+    }
+
+    /// Creates an iterator adaptor that yields the number of elements from the base iterator so
+    /// far that satisfy the predicate `pred`.
+    ///
+    /// This is the same as `map(|i| usize::from(pred(&i))).cumsum()`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iter_accumulate::IterAccumulate;
+    ///
+    /// let responses = [200, 500, 200, 503, 404];
+    /// let errors: Vec<_> = responses
+    ///     .iter()
+    ///     .running_count_where(|&&status| status >= 500)
+    ///     .collect();
+    ///
+    /// assert_eq!(errors, [0, 1, 1, 2, 2]);
+    /// ```
+    #[inline]
+    fn running_count_where<P>(self, pred: P) -> RunningCountWhere<Self, P>
+    where
+        Self: Sized,
+        P: FnMut(&Self::Item) -> bool,
+    {
+        RunningCountWhere::new(self, pred)
+    }
+
+    /// Creates an iterator adaptor that yields the fraction of elements from the base iterator so
+    /// far that satisfy the predicate `pred`, as a floating-point number between zero and one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iter_accumulate::IterAccumulate;
+    ///
+    /// let gc_content: Vec<f64> = "GATC"
+    ///     .chars()
+    ///     .running_fraction_where(|&c| c == 'G' || c == 'C')
+    ///     .collect();
+    ///
+    /// assert_eq!(gc_content, [1.0, 0.5, 1.0 / 3.0, 0.5]);
+    /// ```
+    #[inline]
+    fn running_fraction_where<P, T>(self, pred: P) -> RunningFractionWhere<Self, P, T>
+    where
+        Self: Sized,
+        P: FnMut(&Self::Item) -> bool,
+        T: Float,
+    {
+        self.running_count_where(pred)
+            .enumerate()
+            .map(count::fraction as fn(_) -> _)
+    }
+
+    /// Creates an iterator adaptor that yields the length of the current run of consecutive
+    /// elements from the base iterator that satisfy the predicate `pred`, resetting to zero
+    /// whenever an element does not.
+    ///
+    /// The longest run so far is available through [`RunningStreak::longest()`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iter_accumulate::IterAccumulate;
+    ///
+    /// let health_checks = [true, false, false, true, false, false, false];
+    /// let mut failures = health_checks.iter().running_streak(|&&ok| !ok);
+    ///
+    /// assert_eq!(failures.by_ref().collect::<Vec<_>>(), [0, 1, 2, 0, 1, 2, 3]);
+    /// assert_eq!(failures.longest(), 3);
+    /// ```
+    #[inline]
+    fn running_streak<P>(self, pred: P) -> RunningStreak<Self, P>
+    where
+        Self: Sized,
+        P: FnMut(&Self::Item) -> bool,
+    {
+        RunningStreak::new(self, pred)
+    }
+
+    /// Creates an iterator adaptor that yields the largest sum of any non-empty contiguous run of
+    /// the elements from the base iterator so far, using Kadane's algorithm.
+    ///
+    /// The largest sum of a run ending at the latest element, as well as the index range of the
+    /// best run, are available through the [`MaxSubarray`] returned by
+    /// [`acc()`](AccumulateOutput::acc). Use [`max_subarray_sum()`](Self::max_subarray_sum) if
+    /// only the final answer is needed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iter_accumulate::IterAccumulate;
+    ///
+    /// let input = [-2, 1, -3, 4, -1, 2, 1, -5, 4];
+    /// let mut best = input.into_iter().running_max_subarray_sum();
+    ///
+    /// assert_eq!(best.by_ref().collect::<Vec<_>>(), [-2, 1, 1, 4, 4, 5, 6, 6, 6]);
+    /// assert_eq!(best.acc().ending_here(), Some(5));
+    /// assert_eq!(best.acc().range(), Some(3..7));
+    /// ```
+    #[inline]
+    fn running_max_subarray_sum(self) -> RunningMaxSubarraySum<Self, Self::Item>
+    where
+        Self: Sized,
+        Self::Item: Copy + PartialOrd + Add<Output = Self::Item>,
+    {
+        AccumulateOutput::new(self, MaxSubarray::new(), subarray::step)
+    }
+
+    /// Consumes the iterator and returns the largest sum of any non-empty contiguous run of its
+    /// elements along with the index range of that run, or [`None`] if the iterator is empty.
+    ///
+    /// If several runs have the largest sum, the range of the one that ends first is returned.
+    /// See [`running_max_subarray_sum()`](Self::running_max_subarray_sum) for the running
+    /// version.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iter_accumulate::IterAccumulate;
+    ///
+    /// let input = [-2, 1, -3, 4, -1, 2, 1, -5, 4];
+    ///
+    /// assert_eq!(input.into_iter().max_subarray_sum(), Some((6, 3..7)));
+    /// assert_eq!(core::iter::empty::<i32>().max_subarray_sum(), None);
+    /// ```
+    #[inline]
+    fn max_subarray_sum(self) -> Option<(Self::Item, Range<usize>)>
+    where
+        Self: Sized,
+        Self::Item: Copy + PartialOrd + Add<Output = Self::Item>,
+    {
+        let state = self.fold(MaxSubarray::new(), |mut state, x| {
+            state.push(x);
+            state
+        });
+        Some((state.best()?, state.range()?))
+    }
+
+    /// Creates an iterator adaptor that yields whether the elements from the base iterator so far
+    /// are sorted in non-decreasing order.
+    ///
+    /// This is the running version of [`Iterator::is_sorted()`]. Once an element is smaller than
+    /// the one before it, every following element yields `false`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iter_accumulate::IterAccumulate;
+    ///
+    /// let timestamps = [3, 5, 5, 9, 7, 12];
+    /// let sorted: Vec<_> = timestamps.iter().running_is_sorted().collect();
+    ///
+    /// assert_eq!(sorted, [true, true, true, true, false, false]);
+    /// ```
+    #[inline]
+    fn running_is_sorted(self) -> RunningIsSorted<Self, Compare<Self::Item>>
+    where
+        Self: Sized,
+        Self::Item: PartialOrd,
+    {
+        RunningIsSorted::new(self, PartialOrd::le)
+    }
+
+    /// Creates an iterator adaptor that yields whether the elements from the base iterator so far
+    /// are sorted according to the closure `compare`.
+    ///
+    /// This is the running version of [`Iterator::is_sorted_by()`]: `compare` is called with
+    /// every pair of consecutive elements and should return whether they are in order. After it
+    /// returns `false` once, it is not called again and every following element yields `false`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iter_accumulate::IterAccumulate;
+    ///
+    /// let timestamps = [3, 5, 5, 9, 12];
+    /// let strictly_increasing: Vec<_> = timestamps
+    ///     .iter()
+    ///     .running_is_sorted_by(|a, b| a < b)
+    ///     .collect();
+    ///
+    /// assert_eq!(strictly_increasing, [true, true, false, false, false]);
+    /// ```
+    #[inline]
+    fn running_is_sorted_by<F>(self, compare: F) -> RunningIsSorted<Self, F>
+    where
+        Self: Sized,
+        F: FnMut(&Self::Item, &Self::Item) -> bool,
+    {
+        RunningIsSorted::new(self, compare)
+    }
+
+    /// Creates an iterator adaptor that yields the running median of the floating point numbers
+    /// from the base iterator.
+    ///
+    /// Unlike the [mean](Self::running_mean), the median is not skewed by a few outliers. All
+    /// elements are kept in the [`Median`] state, available using
+    /// [`acc()`](AccumulateOutput::acc), and each one is added in `O(log n)` time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iter_accumulate::IterAccumulate;
+    ///
+    /// let latencies = [12.0, 15.0, 11.0, 900.0, 14.0];
+    /// let medians: Vec<_> = latencies.into_iter().running_median().collect();
+    ///
+    /// assert_eq!(medians, [12.0, 13.5, 12.0, 13.5, 14.0]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn running_median(self) -> RunningMedian<Self, Self::Item>
+    where
+        Self: Sized,
+        Self::Item: Float,
+    {
+        AccumulateOutput::new(self, Median::new(), median::step)
+    }
+
+    /// Creates an iterator adaptor that yields a running estimate of the `p`-quantile of the
+    /// floating point numbers from the base iterator, e.g. the 95th percentile for `p = 0.95`.
+    ///
+    /// The estimate uses the P² algorithm, which takes constant time and memory per element
+    /// without storing the elements, see [`Quantile`]. It is exact for the first five elements
+    /// and an approximation after that.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `p` is not in the range `[0, 1]`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iter_accumulate::IterAccumulate;
+    ///
+    /// let latencies = (1..=1000).map(|i| ((i * 7919) % 1000) as f64);
+    /// let p95 = latencies.running_quantile(0.95).last().unwrap();
+    ///
+    /// assert!((p95 - 950.0).abs() < 10.0);
+    /// ```
+    #[inline]
+    fn running_quantile(self, p: Self::Item) -> RunningQuantile<Self, Self::Item>
+    where
+        Self: Sized,
+        Self::Item: Float,
+    {
+        AccumulateOutput::new(self, Quantile::new(p), quantile::step)
+    }
+
+    /// Creates an iterator adaptor that keeps track of the `k` largest elements from the base
+    /// iterator so far, and yields whether each element is among them.
+    ///
+    /// The current top `k` are available through the [`TopK`] returned by
+    /// [`acc()`](AccumulateOutput::acc). Elements that compare equal to the smallest of them are
+    /// not added, so earlier elements win ties. To keep the smallest elements instead, map them to
+    /// [`Reverse`](core::cmp::Reverse), and to rank them by a key, map them to `(key, element)`
+    /// tuples. Use [`top_k()`](Self::top_k) if only the final result is needed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iter_accumulate::IterAccumulate;
+    ///
+    /// let scores = [30, 10, 50, 5, 40];
+    /// let mut leaderboard = scores.into_iter().running_top_k(3);
+    ///
+    /// let entered: Vec<_> = leaderboard.by_ref().collect();
+    /// assert_eq!(entered, [true, true, true, false, true]);
+    /// assert_eq!(leaderboard.acc().min(), Some(&30));
+    /// assert_eq!(leaderboard.into_acc().into_sorted_vec(), [50, 40, 30]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn running_top_k(self, k: usize) -> RunningTopK<Self, Self::Item>
+    where
+        Self: Sized,
+        Self::Item: Ord,
+    {
+        AccumulateOutput::new(self, TopK::new(k), top_k::step)
+    }
+
+    /// Consumes the iterator and returns its `k` largest elements, sorted from largest to
+    /// smallest.
+    ///
+    /// Of elements that compare equal, the earliest ones are kept. See
+    /// [`running_top_k()`](Self::running_top_k) for the running version.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iter_accumulate::IterAccumulate;
+    /// use std::cmp::Reverse;
+    ///
+    /// let words = ["apple", "fig", "banana", "kiwi", "cherry"];
+    ///
+    /// let longest = words.iter().map(|w| (w.len(), Reverse(*w))).top_k(2);
+    /// assert_eq!(longest, [(6, Reverse("banana")), (6, Reverse("cherry"))]);
+    ///
+    /// let shortest = words.iter().map(|w| Reverse(w.len())).top_k(2);
+    /// assert_eq!(shortest, [Reverse(3), Reverse(4)]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn top_k(self, k: usize) -> alloc::vec::Vec<Self::Item>
+    where
+        Self: Sized,
+        Self::Item: Ord,
+    {
+        let mut top = TopK::new(k);
+        self.for_each(|x| {
+            top.push(x);
+        });
+        top.into_sorted_vec()
+    }
+
+    /// Creates an iterator adaptor that yields a running estimate of the number of distinct
+    /// elements from the base iterator, using a [`HyperLogLog`] sketch with `2^precision`
+    /// registers that hashes the elements using `build_hasher`.
+    ///
+    /// The sketch takes `2^precision` bytes of memory however many elements there are, and the
+    /// standard error of the estimate is about `1.04 / sqrt(2^precision)`. It is available using
+    /// [`acc()`](AccumulateOutput::acc), e.g. to [merge](HyperLogLog::merge) it with the sketches
+    /// of other streams.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `precision` is not in the range `4..=18`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iter_accumulate::IterAccumulate;
+    /// use std::hash::RandomState;
+    ///
+    /// let user_ids = (0..100_000).map(|i| i % 20_000);
+    /// let estimate = user_ids
+    ///     .running_distinct_count(12, RandomState::new())
+    ///     .last()
+    ///     .unwrap();
+    ///
+    /// assert!((estimate - 20_000.0).abs() < 20_000.0 * 0.05);
+    /// ```
+    #[cfg(feature = "sketch")]
+    #[inline]
+    fn running_distinct_count<S>(
+        self,
+        precision: u32,
+        build_hasher: S,
+    ) -> RunningDistinctCount<Self, S>
+    where
+        Self: Sized,
+        Self::Item: core::hash::Hash,
+        S: core::hash::BuildHasher,
+    {
+        AccumulateOutput::new(
+            self,
+            HyperLogLog::with_hasher(precision, build_hasher),
+            sketch::distinct_step,
+        )
+    }
+
+    /// Creates an iterator adaptor that yields the estimated number of times each element from
+    /// the base iterator has occurred so far, using a [`CountMinSketch`] with `depth` rows of
+    /// `width` counters that hashes the elements using `build_hasher`.
+    ///
+    /// The estimates are never too low, and too high by at most `e / width` times the number of
+    /// elements so far with probability `1 - e^-depth`. The sketch is available using
+    /// [`acc()`](AccumulateOutput::acc) and [`into_acc()`](AccumulateOutput::into_acc), to
+    /// [estimate](CountMinSketch::estimate) the count of any value during or after the iteration.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `width` or `depth` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iter_accumulate::IterAccumulate;
+    /// use std::hash::RandomState;
+    ///
+    /// let words = "the cat and the dog and the bird".split(' ');
+    /// let mut frequencies = words.running_frequency(1024, 4, RandomState::new());
+    ///
+    /// assert_eq!(frequencies.by_ref().max(), Some(3));
+    /// assert!(frequencies.acc().estimate("and") >= 2);
+    /// assert_eq!(frequencies.acc().total(), 8);
+    /// ```
+    #[cfg(feature = "sketch")]
+    #[inline]
+    fn running_frequency<S>(
+        self,
+        width: usize,
+        depth: usize,
+        build_hasher: S,
+    ) -> RunningFrequency<Self, S>
+    where
+        Self: Sized,
+        Self::Item: core::hash::Hash,
+        S: core::hash::BuildHasher,
+    {
+        AccumulateOutput::new(
+            self,
+            CountMinSketch::with_hasher(width, depth, build_hasher),
+            sketch::frequency_step,
+        )
+    }
+
+    /// Consumes the iterator, counts its elements into `buckets` buckets using the closure
+    /// `bucket`, and returns the resulting [`CumulativeHistogram`].
+    ///
+    /// `bucket` is called with every element and returns the index of its bucket. Indices past
+    /// the last bucket are counted in the last bucket, so it can serve as an overflow bucket. If
+    /// the counts are already known, collect them into a [`CumulativeHistogram`] instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iter_accumulate::IterAccumulate;
+    ///
+    /// let latencies_ms = [3, 14, 7, 22, 9, 41, 5, 12];
+    /// let histogram = latencies_ms.iter().cumulative_histogram(4, |&&ms| ms / 10);
+    ///
+    /// assert_eq!(histogram.cumulative_counts(), [4, 6, 7, 8]);
+    /// assert_eq!(histogram.cdf(1), 0.75);
+    /// assert_eq!(histogram.quantile_bucket(0.9), Some(3));
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn cumulative_histogram<F>(self, buckets: usize, bucket: F) -> CumulativeHistogram
+    where
+        Self: Sized,
+        F: FnMut(&Self::Item) -> usize,
+    {
+        histogram::cumulative_histogram(self, buckets, bucket)
+    }
+
+    /// Creates an iterator adaptor that adds the elements from the base iterator to
+    /// `accumulator`, and yields its [output](Accumulator::current) after every element.
+    ///
+    /// Accumulators can be composed, so this computes several running results in one pass
+    /// without writing a closure over a tuple of states. The accumulator is available using
+    /// [`accumulator()`](Running::accumulator) and
+    /// [`into_accumulator()`](Running::into_accumulator).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iter_accumulate::{Accumulator, Count, IterAccumulate, Maximum, Mean};
+    ///
+    /// let readings = [4.0, 8.0, -1.0, 6.0];
+    /// let stats: Vec<_> = readings
+    ///     .into_iter()
+    ///     .running(
+    ///         Mean::new()
+    ///             .pair(Maximum::new())
+    ///             .pair(Count::new().filter(|&x: &f64| x < 0.0)),
+    ///     )
+    ///     .collect();
+    ///
+    /// assert_eq!(
+    ///     stats,
+    ///     [
+    ///         ((4.0, Some(4.0)), 0),
+    ///         ((6.0, Some(8.0)), 0),
+    ///         ((11.0 / 3.0, Some(8.0)), 1),
+    ///         ((4.25, Some(8.0)), 1),
+    ///     ]
+    /// );
+    /// ```
+    #[inline]
+    fn running<A>(self, accumulator: A) -> Running<Self, A>
+    where
+        Self: Sized,
+        A: Accumulator<Self::Item>,
+    {
+        Running::new(self, accumulator)
+    }
+
+    /// Creates an iterator adaptor that accumulates the last `window` elements from the base
+    /// iterator, by adding every element to the accumulated value using the closure `add` and
+    /// removing it again using the closure `remove` once it leaves the window.
+    ///
+    /// `remove` has to undo `add`, so this works for any invertible operation, like addition,
+    /// XOR or multiplication modulo a prime, and takes constant time per element regardless of
+    /// the window size. The elements in the window are buffered. `warm_up` determines whether the
+    /// accumulated values of the first elements are yielded before the window is full. For a
+    /// fixed window size that does not need the `alloc` feature, see
+    /// [`rolling()`](Self::rolling).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `window` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iter_accumulate::{IterAccumulate, WarmUp};
+    ///
+    /// let input = [0b0001, 0b0011, 0b0100, 0b0001, 0b1000];
+    /// let parities: Vec<_> = input
+    ///     .into_iter()
+    ///     .rolling_fold(3, WarmUp::Skip, 0, |acc, x| acc ^ x, |acc, x| acc ^ x)
+    ///     .collect();
+    ///
+    /// assert_eq!(parities, [0b0110, 0b0110, 0b1101]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn rolling_fold<B, F, G>(
+        self,
+        window: usize,
+        warm_up: WarmUp,
+        init: B,
+        add: F,
+        remove: G,
+    ) -> RollingFold<Self, B, F, G>
+    where
+        Self: Sized,
+        Self::Item: Clone,
+        B: Clone,
+        F: FnMut(B, Self::Item) -> B,
+        G: FnMut(B, Self::Item) -> B,
+    {
+        RollingFold::new(self, window, warm_up, init, add, remove)
+    }
+
+    /// Creates an iterator adaptor that yields the sums of the last `window` elements from the
+    /// base iterator, starting from the [default value](Default::default) of the sum type.
+    ///
+    /// The elements in the window are buffered, and for every element, it is added to the sum
+    /// and the element that leaves the window is subtracted from it. `warm_up` determines
+    /// whether sums of the first elements are yielded before the window is full. Like with
+    /// [`cumsum()`](Self::cumsum), the sum type can differ from the element type.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `window` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iter_accumulate::{IterAccumulate, WarmUp};
+    ///
+    /// let input = [1, 2, 3, 4, 5];
+    ///
+    /// let partial: Vec<i32> = input.iter().rolling_sum(3, WarmUp::Partial).collect();
+    /// assert_eq!(partial, [1, 3, 6, 9, 12]);
+    ///
+    /// let full: Vec<i32> = input.iter().rolling_sum(3, WarmUp::Skip).collect();
+    /// assert_eq!(full, [6, 9, 12]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn rolling_sum<B>(self, window: usize, warm_up: WarmUp) -> RollingSum<Self, B>
+    where
+        Self: Sized,
+        Self::Item: Clone,
+        B: Add<Self::Item, Output = B> + core::ops::Sub<Self::Item, Output = B> + Clone + Default,
+    {
+        RollingFold::new(
+            self,
+            window,
+            warm_up,
+            B::default(),
+            |acc, i| acc + i,
+            |acc, i| acc - i,
+        )
+    }
+
+    /// Creates an iterator adaptor that yields the maximum of the last `window` elements from the
+    /// base iterator.
+    ///
+    /// The window is tracked with a monotonic deque of the elements that could still become the
+    /// maximum, so this takes amortized constant time per element instead of rescanning the
+    /// window. `warm_up` determines whether the maximum of the first elements is yielded before
+    /// the window is full.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `window` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iter_accumulate::{IterAccumulate, WarmUp};
+    ///
+    /// let input = [1, 3, -1, -3, 5, 3, 6, 7];
+    /// let maxima: Vec<_> = input.into_iter().rolling_max(3, WarmUp::Skip).collect();
+    ///
+    /// assert_eq!(maxima, [3, 3, 5, 5, 6, 7]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn rolling_max(self, window: usize, warm_up: WarmUp) -> RollingExtremum<Self>
+    where
+        Self: Sized,
+        Self::Item: Ord + Clone,
+    {
+        RollingExtremum::new(self, window, warm_up, true)
+    }
+
+    /// Creates an iterator adaptor that yields the minimum of the last `window` elements from the
+    /// base iterator.
+    ///
+    /// Like [`rolling_max()`](Self::rolling_max), this takes amortized constant time per
+    /// element. `warm_up` determines whether the minimum of the first elements is yielded before
+    /// the window is full.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `window` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iter_accumulate::{IterAccumulate, WarmUp};
+    ///
+    /// let input = [4, 2, 12, 3, 8, 1];
+    /// let minima: Vec<_> = input.into_iter().rolling_min(2, WarmUp::Partial).collect();
+    ///
+    /// assert_eq!(minima, [4, 2, 2, 3, 3, 1]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn rolling_min(self, window: usize, warm_up: WarmUp) -> RollingExtremum<Self>
+    where
+        Self: Sized,
+        Self::Item: Ord + Clone,
+    {
+        RollingExtremum::new(self, window, warm_up, false)
+    }
+
+    /// Creates an iterator adaptor that yields the arithmetic means of the last `window` floating
+    /// point numbers from the base iterator.
+    ///
+    /// The numbers in the window are buffered, and the [`Mean`] is updated incrementally as
+    /// numbers enter and [leave](Mean::remove) the window, so this takes constant time per
+    /// element. `warm_up` determines whether the means of the first numbers are yielded before
+    /// the window is full.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `window` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iter_accumulate::{IterAccumulate, WarmUp};
+    ///
+    /// let input = [2.0, 4.0, 6.0, 10.0, 2.0];
+    /// let means: Vec<_> = input.into_iter().rolling_mean(2, WarmUp::Partial).collect();
+    ///
+    /// assert_eq!(means, [2.0, 3.0, 5.0, 8.0, 6.0]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn rolling_mean(self, window: usize, warm_up: WarmUp) -> RollingMean<Self, Self::Item>
+    where
+        Self: Sized,
+        Self::Item: Float,
+    {
+        rolling::rolling_mean(self, window, warm_up)
+    }
+
+    /// Creates an iterator adaptor that yields the standard deviations of the given kind of the
+    /// last `window` floating point numbers from the base iterator.
+    ///
+    /// Like with [`rolling_mean()`](Self::rolling_mean), the [`Variance`] is updated
+    /// incrementally as numbers enter and [leave](Variance::remove) the window. `warm_up`
+    /// determines whether the standard deviations of the first numbers are yielded before the
+    /// window is full.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `window` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iter_accumulate::{IterAccumulate, VarianceKind, WarmUp};
+    ///
+    /// let input = [1.0, 3.0, 3.0, 7.0];
+    /// let stddevs: Vec<_> = input
+    ///     .into_iter()
+    ///     .rolling_stddev(2, WarmUp::Skip, VarianceKind::Population)
+    ///     .collect();
+    ///
+    /// assert_eq!(stddevs, [1.0, 0.0, 2.0]);
+    /// ```
+    #[cfg(feature = "std")]
+    #[inline]
+    fn rolling_stddev(
+        self,
+        window: usize,
+        warm_up: WarmUp,
+        kind: VarianceKind,
+    ) -> RollingStddev<Self, Self::Item>
+    where
+        Self: Sized,
+        Self::Item: Float,
+    {
+        rolling::rolling_stddev(self, window, warm_up, kind)
+    }
+
+    /// Creates an iterator adaptor that accumulates the last `N` elements from the base iterator,
+    /// by adding every element to the accumulated value using the closure `add` and removing it
+    /// again using the closure `remove` once it leaves the window.
+    ///
+    /// The window size is given as [`Window::<N>`](Window). The window is an array of `N` elements
+    /// inside the adaptor, so unlike the other rolling adaptors, this does not need the `alloc`
+    /// feature. `remove` has to undo `add`, like subtraction undoes addition. `warm_up` determines
+    /// whether the accumulated values of the first elements are yielded before the window is full.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N` is zero.
+    ///
+    /// # Examples
+    ///
+    /// A moving average of the last four samples of a sensor:
+    ///
+    /// ```
+    /// use iter_accumulate::{IterAccumulate, WarmUp, Window};
+    ///
+    /// let samples = [10, 12, 14, 16, 30, 8];
+    /// let averages: Vec<_> = samples
+    ///     .iter()
+    ///     .rolling(Window::<4>, WarmUp::Skip, 0, |sum, &x| sum + x, |sum, &x| sum - x)
+    ///     .map(|sum| sum / 4)
+    ///     .collect();
+    ///
+    /// assert_eq!(averages, [13, 18, 17]);
+    /// ```
+    #[inline]
+    fn rolling<const N: usize, B, F, G>(
+        self,
+        _size: Window<N>,
+        warm_up: WarmUp,
+        init: B,
+        add: F,
+        remove: G,
+    ) -> RollingArray<Self, B, F, G, N>
+    where
+        Self: Sized,
+        Self::Item: Clone,
+        B: Clone,
+        F: FnMut(B, Self::Item) -> B,
+        G: FnMut(B, Self::Item) -> B,
+    {
+        RollingArray::new(self, warm_up, init, add, remove)
+    }
+
+    /// Creates an iterator adaptor that yields the differences between consecutive elements from
+    /// the base iterator, i.e. every element minus the one before it.
+    ///
+    /// This is the inverse of a prefix sum. There is no difference for the first element, so one
+    /// fewer value than there are elements is yielded. Use [`undeltas()`](Self::undeltas) with
+    /// the first element to decode the differences again.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iter_accumulate::IterAccumulate;
+    ///
+    /// let ids = [1000, 1003, 1004, 1010];
+    /// let deltas: Vec<_> = ids.into_iter().deltas().collect();
+    /// assert_eq!(deltas, [3, 1, 6]);
+    ///
+    /// let decoded: Vec<_> = deltas.into_iter().undeltas(ids[0]).collect();
+    /// assert_eq!(decoded, ids);
+    /// ```
+    #[inline]
+    fn deltas<D>(self) -> Deltas<Self>
+    where
+        Self: Sized,
+        Self::Item: Clone + core::ops::Sub<Output = D>,
+    {
+        Deltas::new(self)
+    }
+
+    /// Creates an iterator adaptor that yields `init`, followed by `init` plus the running sums of
+    /// the differences from the base iterator.
+    ///
+    /// This decodes the differences yielded by [`deltas()`](Self::deltas), given the first
+    /// element as `init`, so one more value than there are differences is yielded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iter_accumulate::IterAccumulate;
+    ///
+    /// let deltas = [5, -2, 0, 4];
+    /// let values: Vec<_> = deltas.iter().undeltas(10).collect();
+    ///
+    /// assert_eq!(values, [10, 15, 13, 13, 17]);
+    /// ```
+    #[inline]
+    fn undeltas<B>(self, init: B) -> Undeltas<Self, B>
+    where
+        Self: Sized,
+        B: Add<Self::Item, Output = B> + Clone,
+    {
+        Undeltas::new(self, init)
+    }
+
+    /// Creates an iterator adaptor that yields the characters or bytes from the base iterator,
+    /// together with their line and column [`Position`]s.
+    ///
+    /// `'\n'`, `'\r\n'` and a lone `'\r'` each end a line, and are positioned at the end of the
+    /// line they end. A `'\t'` advances the column to the next multiple of `tab_width` columns
+    /// (plus one, as columns start at one). Every other unit advances the column by one.
+    ///
+    /// The position after the last unit is available through [`Positions::position()`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `tab_width` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iter_accumulate::{IterAccumulate, Position};
+    ///
+    /// let mut positions = "ab\r\n\tc".chars().text_positions(4);
+    /// let pos = |line, column| Position { line, column };
+    ///
+    /// assert_eq!(positions.next(), Some(('a', pos(1, 1))));
+    /// assert_eq!(positions.next(), Some(('b', pos(1, 2))));
+    /// assert_eq!(positions.next(), Some(('\r', pos(1, 3))));
+    /// assert_eq!(positions.next(), Some(('\n', pos(1, 4))));
+    /// assert_eq!(positions.next(), Some(('\t', pos(2, 1))));
+    /// assert_eq!(positions.next(), Some(('c', pos(2, 5))));
+    /// assert_eq!(positions.next(), None);
+    /// assert_eq!(positions.position(), pos(2, 6));
+    /// ```
+    #[inline]
+    fn text_positions(self, tab_width: usize) -> Positions<Self>
+    where
+        Self: Sized,
+        Self::Item: TextUnit,
+    {
+        Positions::new(self, tab_width)
+    }
+
+    /// Creates an iterator adaptor that yields the characters from the base iterator, together
+    /// with their [`TextOffset`]s in both UTF-8 bytes and UTF-16 code units.
+    ///
+    /// This converts between [`str`] byte indices and UTF-16 based positions, like those of the
+    /// Language Server Protocol, in a single pass. The length of the whole text is available
+    /// through [`TextOffsets::offset()`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iter_accumulate::{IterAccumulate, TextOffset};
+    ///
+    /// let text = "a\u{e9}\u{1f600}b";
+    /// let offsets: Vec<_> = text
+    ///     .chars()
+    ///     .text_offsets()
+    ///     .map(|(_, offset)| (offset.utf8, offset.utf16))
+    ///     .collect();
+    ///
+    /// assert_eq!(offsets, [(0, 0), (1, 1), (3, 2), (7, 4)]);
+    ///
+    /// let mut iter = text.chars().text_offsets();
+    /// iter.by_ref().for_each(drop);
+    /// assert_eq!(iter.offset(), TextOffset { utf8: 8, utf16: 5 });
+    /// ```
+    #[inline]
+    fn text_offsets(self) -> TextOffsets<Self>
+    where
+        Self: Sized + Iterator<Item = char>,
+    {
+        TextOffsets::new(self)
+    }
+
+    /// Creates an iterator adaptor that yields the elements from the base iterator, together with
+    /// the nesting depth they are at.
+    ///
+    /// The closure decides whether each element is an opening or closing [`Delimiter`], or
+    /// neither. Delimiters are at the depth outside of the level they open or close, so an
+    /// opening delimiter and its matching closing delimiter are at the same depth, and the
+    /// elements between them are one level deeper.
+    ///
+    /// A closing delimiter without a matching opening one makes the depth negative. Whether that
+    /// has happened, and whether every level has been closed, can be checked through
+    /// [`NestingDepth::underflowed()`] and [`NestingDepth::is_balanced()`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iter_accumulate::{Delimiter, IterAccumulate};
+    ///
+    /// let delimiter = |c: &char| match c {
+    ///     '(' | '[' => Some(Delimiter::Open),
+    ///     ')' | ']' => Some(Delimiter::Close),
+    ///     _ => None,
+    /// };
+    ///
+    /// let mut depths = "f([a], b)".chars().nesting_depth(delimiter);
+    /// let chars: Vec<_> = depths.by_ref().collect();
+    /// assert_eq!(
+    ///     chars,
+    ///     [
+    ///         ('f', 0),
+    ///         ('(', 0),
+    ///         ('[', 1),
+    ///         ('a', 2),
+    ///         (']', 1),
+    ///         (',', 1),
+    ///         (' ', 1),
+    ///         ('b', 1),
+    ///         (')', 0),
+    ///     ]
+    /// );
+    /// assert!(depths.is_balanced());
+    ///
+    /// let mut depths = "a)(".chars().nesting_depth(delimiter);
+    /// let levels: Vec<_> = depths.by_ref().map(|(_, depth)| depth).collect();
+    /// assert_eq!(levels, [0, -1, -1]);
+    /// assert!(depths.underflowed());
+    /// assert_eq!(depths.depth(), 0);
+    /// ```
+    #[inline]
+    fn nesting_depth<F>(self, f: F) -> NestingDepth<Self, F>
+    where
+        Self: Sized,
+        F: FnMut(&Self::Item) -> Option<Delimiter>,
+    {
+        NestingDepth::new(self, f)
+    }
+
+    /// Creates an iterator adaptor that yields the running greatest common divisors of the
+    /// integers from the base iterator.
+    ///
+    /// The running value starts at zero, which is the greatest common divisor of no numbers. Every
+    /// yielded value is the absolute value of the greatest common divisor, except when that is
+    /// the absolute value of the minimum of a signed type, which does not fit into the type and
+    /// wraps around to that minimum instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iter_accumulate::IterAccumulate;
+    ///
+    /// let input = [24, -36, 60, 7];
+    /// let gcds: Vec<_> = input.into_iter().running_gcd().collect();
+    ///
+    /// assert_eq!(gcds, [24, 12, 12, 1]);
+    ///
+    /// let input = [i32::MIN, 0, 6];
+    /// let gcds: Vec<_> = input.into_iter().running_gcd().collect();
+    ///
+    /// assert_eq!(gcds, [i32::MIN, i32::MIN, 2]);
+    /// ```
+    #[inline]
+    fn running_gcd(self) -> RunningGcd<Self>
+    where
+        Self: Sized,
+        Self::Item: Integer,
+    {
+        Accumulate::new(self, Integer::ZERO, int::gcd)
+    }
+
+    /// Creates an iterator adaptor that yields the running least common multiples of the integers
+    /// from the base iterator wrapped in [`Ok`], and stops when they overflow.
+    ///
+    /// The running value starts at one, which is the least common multiple of no numbers, and is
+    /// never negative. Once it does not fit into the integer type anymore,
+    /// [`Err(Overflow)`](Overflow) is yielded, and afterwards, the returned iterator is finished.
+    ///
+    /// # Examples
+    ///
+    /// Computing the hyperperiod of a set of periodic tasks:
+    ///
+    /// ```
+    /// use iter_accumulate::{IterAccumulate, Overflow};
+    ///
+    /// let periods = [4u8, 6, 10];
+    /// let hyperperiods: Vec<_> = periods.into_iter().running_lcm().collect();
+    /// assert_eq!(hyperperiods, [Ok(4), Ok(12), Ok(60)]);
+    ///
+    /// let periods = [16u8, 9, 5];
+    /// let hyperperiods: Vec<_> = periods.into_iter().running_lcm().collect();
+    /// assert_eq!(hyperperiods, [Ok(16), Ok(144), Err(Overflow)]);
+    /// ```
+    #[inline]
+    fn running_lcm(self) -> CheckedCum<Self>
+    where
+        Self: Sized,
+        Self::Item: Integer,
+    {
+        TryAccumulate::new(self, Integer::ONE, int::checked_lcm)
+    }
+
+    /// Creates an iterator adaptor that yields the running sums of the floating point numbers from
+    /// the base iterator, using compensated summation.
+    ///
+    /// The rounding error of every addition is tracked separately using Neumaier's variant of
+    /// Kahan summation, and each yielded sum is corrected by it, so that long streams of numbers
+    /// do not drift like with [`cumsum()`](Self::cumsum).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iter_accumulate::IterAccumulate;
+    ///
+    /// let input = [1.0, 1e100, 1.0, -1e100];
+    ///
+    /// let naive: Vec<f64> = input.into_iter().cumsum().collect();
+    /// assert_eq!(naive[3], 0.0);
+    ///
+    /// let compensated: Vec<_> = input.into_iter().cumsum_compensated().collect();
+    /// assert_eq!(compensated, [1.0, 1e100, 1e100, 2.0]);
+    /// ```
+    #[inline]
+    fn cumsum_compensated(self) -> CumSumCompensated<Self, Self::Item>
+    where
+        Self: Sized,
+        Self::Item: Float,
+    {
+        CumSumCompensated::new(self)
+    }
+
+    /// Creates an iterator adaptor that yields the running integral of the `(t, y)` samples from
+    /// the base iterator using the trapezoidal rule.
+    ///
+    /// The integral at every sample is the integral from the first sample to that one, so the
+    /// first value yielded is zero. The samples do not have to be evenly spaced, see
+    /// [`Trapezoid::push()`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iter_accumulate::IterAccumulate;
+    ///
+    /// let samples = [(0.0, 1.0), (1.0, 3.0), (1.5, 3.0), (3.5, 1.0)];
+    /// let integral: Vec<_> = samples.into_iter().cumulative_trapezoid().collect();
+    ///
+    /// assert_eq!(integral, [0.0, 2.0, 3.5, 7.5]);
+    /// ```
+    #[inline]
+    fn cumulative_trapezoid<T>(self) -> CumulativeTrapezoid<Self, T>
+    where
+        Self: Sized + Iterator<Item = (T, T)>,
+        T: Float,
+    {
+        AccumulateOutput::new(self, Trapezoid::new(), |mut trapezoid, (t, y)| {
+            trapezoid.push(t, y);
+            (trapezoid, trapezoid.integral())
+        })
+    }
+
+    /// Creates an iterator adaptor that integrates the rates from the base iterator over the
+    /// fixed time step `dt`, starting from `init`, using the explicit Euler method.
+    ///
+    /// Every element is a rate of change, like a velocity, and the quantity it changes, like a
+    /// position, is yielded after every step: the quantity after an element is the previous
+    /// quantity plus `rate * dt`. For time steps that vary per element, use
+    /// [`integrate_steps()`](Self::integrate_steps).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iter_accumulate::IterAccumulate;
+    ///
+    /// let velocities = [2.0, 4.0, -2.0, 0.0];
+    /// let positions: Vec<_> = velocities.iter().integrate(10.0, 0.5).collect();
+    ///
+    /// assert_eq!(positions, [11.0, 13.0, 12.0, 12.0]);
+    /// ```
+    #[inline]
+    fn integrate<B, D>(self, init: B, dt: D) -> Integrate<Self, B, D>
+    where
+        Self: Sized,
+        Self::Item: Mul<D>,
+        B: Add<<Self::Item as Mul<D>>::Output, Output = B> + Clone,
+        D: Clone,
+    {
+        Integrate::new(self, init, dt)
+    }
+
+    /// Creates an iterator adaptor that integrates the `(dt, rate)` pairs from the base iterator,
+    /// starting from `init`, using the explicit Euler method.
+    ///
+    /// This is like [`integrate()`](Self::integrate), but every element carries its own time
+    /// step, e.g. the frame time of a game loop. The quantity after an element is the previous
+    /// quantity plus `rate * dt`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iter_accumulate::IterAccumulate;
+    ///
+    /// let steps = [(0.5, 2.0), (0.25, 4.0), (1.0, -1.0)];
+    /// let positions: Vec<_> = steps.into_iter().integrate_steps(0.0).collect();
+    ///
+    /// assert_eq!(positions, [1.0, 2.0, 1.0]);
+    /// ```
+    #[inline]
+    fn integrate_steps<B, D, R>(self, init: B) -> IntegrateSteps<Self, B>
+    where
+        Self: Sized + Iterator<Item = (D, R)>,
+        R: Mul<D>,
+        B: Add<R::Output, Output = B> + Clone,
+    {
+        Accumulate::new(self, init, integrate::euler_step)
+    }
+
+    /// Creates an iterator adaptor that integrates a position and velocity over the time steps
+    /// from the base iterator using the velocity Verlet method, and yields both after every step.
+    ///
+    /// The closure computes the acceleration at a position, e.g. from a force field. Velocity
+    /// Verlet is second-order accurate and, unlike [`integrate()`](Self::integrate), keeps the
+    /// energy of oscillating systems bounded. The acceleration is computed once per step, as the
+    /// acceleration at the new position is reused for the next step.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iter_accumulate::IterAccumulate;
+    ///
+    /// // A ball thrown upwards, under constant gravity.
+    /// let dts = [0.5; 3];
+    /// let states: Vec<_> = dts.into_iter().verlet(0.0, 4.0, |_| -8.0).collect();
+    ///
+    /// assert_eq!(states, [(1.0, 0.0), (0.0, -4.0), (-3.0, -8.0)]);
+    /// ```
+    #[inline]
+    fn verlet<T, S, F>(self, position: S, velocity: S, accel: F) -> Verlet<Self, S, F>
+    where
+        Self: Sized + Iterator<Item = T>,
+        T: Float,
+        S: Clone + Add<Output = S> + Mul<T, Output = S>,
+        F: FnMut(&S) -> S,
+    {
+        Verlet::new(self, position, velocity, accel)
+    }
+
+    /// Creates an iterator adaptor that solves the ordinary differential equation `y' = f(t, y)`
+    /// over the time steps from the base iterator, starting from `y` at `t`, using the classic
+    /// fourth-order Runge-Kutta method.
+    ///
+    /// The time and state are yielded as a `(t, y)` pair after every step. The closure is called
+    /// four times per step. For a position and velocity, the state can be a vector type holding
+    /// both.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iter_accumulate::IterAccumulate;
+    /// use std::iter;
+    ///
+    /// // Exponential growth, `y' = y`, from `y(0) = 1`.
+    /// let (t, y) = iter::repeat(0.1)
+    ///     .take(10)
+    ///     .rk4(0.0, 1.0, |_, y: &f64| *y)
+    ///     .last()
+    ///     .unwrap();
+    ///
+    /// assert!((t - 1.0f64).abs() < 1e-9);
+    /// assert!((y - 1.0f64.exp()).abs() < 1e-5);
+    /// ```
+    #[inline]
+    fn rk4<T, Y, F>(self, t: T, y: Y, f: F) -> Rk4<Self, T, Y, F>
+    where
+        Self: Sized + Iterator<Item = T>,
+        T: Float,
+        Y: Clone + Add<Output = Y> + Mul<T, Output = Y>,
+        F: FnMut(T, &Y) -> Y,
+    {
+        Rk4::new(self, t, y, f)
+    }
+
+    /// Creates an iterator adaptor that yields the running length of the path through the points
+    /// from the base iterator, using the provided closure to compute the distance between two
+    /// consecutive points.
+    ///
+    /// The length at every point is the length of the path from the first point to that one, so
+    /// the first value yielded is zero. The closure can compute any distance, e.g. the Euclidean
+    /// distance, or the haversine distance for geographic coordinates.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iter_accumulate::IterAccumulate;
+    ///
+    /// let points = [(0.0, 0.0), (3.0, 4.0), (3.0, 6.0), (0.0, 2.0)];
+    /// let euclidean = |a: &(f64, f64), b: &(f64, f64)| (b.0 - a.0).hypot(b.1 - a.1);
+    /// let lengths: Vec<_> = points.into_iter().arc_length(euclidean).collect();
+    ///
+    /// assert_eq!(lengths, [0.0, 5.0, 7.0, 12.0]);
+    /// ```
+    #[inline]
+    fn arc_length<D, F>(self, distance: F) -> ArcLength<Self, D, F>
+    where
+        Self: Sized,
+        D: Float,
+        F: FnMut(&Self::Item, &Self::Item) -> D,
+    {
+        ArcLength::new(self, distance)
+    }
+
+    /// Creates an iterator adaptor that yields the running arithmetic mean of the floating point
+    /// numbers from the base iterator.
+    ///
+    /// The mean is updated incrementally for every element, see [`Mean::push()`]. The current
+    /// [`Mean`], including the number of elements, is available using
+    /// [`acc()`](AccumulateOutput::acc).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iter_accumulate::IterAccumulate;
+    ///
+    /// let input = [2.0, 4.0, 9.0, 1.0];
+    /// let mut means = input.into_iter().running_mean();
+    ///
+    /// assert_eq!(means.next(), Some(2.0));
+    /// assert_eq!(means.next(), Some(3.0));
+    /// assert_eq!(means.next(), Some(5.0));
+    /// assert_eq!(means.next(), Some(4.0));
+    /// assert_eq!(means.acc().count(), 4);
+    /// ```
+    #[inline]
+    fn running_mean(self) -> RunningMean<Self, Self::Item>
+    where
+        Self: Sized,
+        Self::Item: Float,
+    {
+        AccumulateOutput::new(self, Mean::new(), |mut mean, x| {
+            mean.push(x);
+            (mean, mean.mean())
+        })
+    }
+
+    /// Creates an iterator adaptor that yields the running variance of the floating point numbers
+    /// from the base iterator.
+    ///
+    /// The variance is computed using Welford's online algorithm, which is numerically stable,
+    /// and is either the population or the sample variance depending on `kind`. The current
+    /// [`Variance`] state is available using [`acc()`](AccumulateOutput::acc).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iter_accumulate::{IterAccumulate, VarianceKind};
+    ///
+    /// let input = [2.0_f64, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0];
+    ///
+    /// let population = input.into_iter().running_variance(VarianceKind::Population);
+    /// assert_eq!(population.last(), Some(4.0));
+    ///
+    /// let mut sample = input.into_iter().running_variance(VarianceKind::Sample);
+    /// assert!(sample.next().unwrap().is_nan());
+    /// assert_eq!(sample.next(), Some(2.0));
+    /// assert_eq!(sample.last(), Some(32.0 / 7.0));
+    /// ```
+    #[inline]
+    fn running_variance(self, kind: VarianceKind) -> RunningVariance<Self, Self::Item>
+    where
+        Self: Sized,
+        Self::Item: Float,
+    {
+        AccumulateOutput::new(self, Variance::new(), kind.variance())
+    }
+
+    /// Creates an iterator adaptor that yields the running standard deviation of the floating
+    /// point numbers from the base iterator.
+    ///
+    /// This is the square root of the values yielded by
+    /// [`running_variance()`](Self::running_variance).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iter_accumulate::{IterAccumulate, VarianceKind};
+    ///
+    /// let input = [2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0];
+    /// let stddev = input.into_iter().running_stddev(VarianceKind::Population);
+    ///
+    /// assert_eq!(stddev.last(), Some(2.0));
+    /// ```
+    #[cfg(feature = "std")]
+    #[inline]
+    fn running_stddev(self, kind: VarianceKind) -> RunningVariance<Self, Self::Item>
+    where
+        Self: Sized,
+        Self::Item: Float,
+    {
+        AccumulateOutput::new(self, Variance::new(), kind.stddev())
+    }
+
+    /// Creates an iterator adaptor that yields the z-score of every floating point number from the
+    /// base iterator relative to the preceding numbers.
+    ///
+    /// The z-score of an element is its distance from the running mean of the preceding elements,
+    /// in units of their running standard deviation of the given `kind`. It is NaN while there
+    /// are not enough preceding elements to compute a standard deviation, and infinite if all
+    /// preceding elements are equal but the element is not.
+    ///
+    /// # Examples
+    ///
+    /// Flagging outliers in a stream of measurements:
+    ///
+    /// ```
+    /// use iter_accumulate::{IterAccumulate, VarianceKind};
+    ///
+    /// let input = [10.0_f64, 12.0, 10.0, 12.0, 30.0];
+    /// let outliers: Vec<_> = input
+    ///     .into_iter()
+    ///     .running_zscore(VarianceKind::Sample)
+    ///     .map(|z| z.abs() > 3.0)
+    ///     .collect();
+    ///
+    /// assert_eq!(outliers, [false, false, false, false, true]);
+    /// ```
+    #[cfg(feature = "std")]
+    #[inline]
+    fn running_zscore(self, kind: VarianceKind) -> RunningZScore<Self, Self::Item>
+    where
+        Self: Sized,
+        Self::Item: Float,
+    {
+        AccumulateOutput::new(self, Variance::new(), kind.zscore())
+    }
+
+    /// Creates an iterator adaptor that yields the running covariance of the pairs of floating
+    /// point numbers from the base iterator.
+    ///
+    /// The covariance is either the population or the sample covariance depending on `kind`. The
+    /// current [`Covariance`] state, which also contains the running statistics of both
+    /// variables, is available using [`acc()`](AccumulateOutput::acc).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iter_accumulate::{IterAccumulate, VarianceKind};
+    ///
+    /// let input = [(1.0_f64, 2.0), (2.0, 4.0), (3.0, 3.0)];
+    /// let mut iter = input.into_iter().running_covariance(VarianceKind::Sample);
+    ///
+    /// assert!(iter.next().unwrap().is_nan());
+    /// assert_eq!(iter.next(), Some(1.0));
+    /// assert_eq!(iter.next(), Some(0.5));
+    /// assert_eq!(iter.acc().y().mean(), 3.0);
+    /// ```
+    #[inline]
+    fn running_covariance<T>(self, kind: VarianceKind) -> RunningCovariance<Self, T>
+    where
+        Self: Sized + Iterator<Item = (T, T)>,
+        T: Float,
+    {
+        AccumulateOutput::new(self, Covariance::new(), kind.covariance())
+    }
+
+    /// Creates an iterator adaptor that yields the running Pearson correlation coefficient of the
+    /// pairs of floating point numbers from the base iterator.
+    ///
+    /// The correlation is NaN until there are two pairs, and while either variable is constant.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iter_accumulate::IterAccumulate;
+    ///
+    /// let input = [(1.0, 10.0), (2.0, 20.0), (3.0, 30.0), (4.0, 0.0)];
+    /// let correlations: Vec<f64> = input.into_iter().running_correlation().skip(1).collect();
+    ///
+    /// assert_eq!(correlations[..2], [1.0, 1.0]);
+    /// assert!(correlations[2] < 0.0);
+    /// ```
+    #[cfg(feature = "std")]
+    #[inline]
+    fn running_correlation<T>(self) -> RunningCovariance<Self, T>
+    where
+        Self: Sized + Iterator<Item = (T, T)>,
+        T: Float,
+    {
+        AccumulateOutput::new(self, Covariance::new(), |mut c, (x, y)| {
+            c.push(x, y);
+            (c, c.correlation())
+        })
+    }
+
+    /// Creates an iterator adaptor that yields the slope and intercept of the running least
+    /// squares regression line through the `(x, y)` pairs of floating point numbers from the base
+    /// iterator.
+    ///
+    /// Both values are NaN until there are two pairs with different `x` values. The coefficient
+    /// of determination of the current line is available using
+    /// [`acc()`](AccumulateOutput::acc) and [`Covariance::r_squared()`].
+    ///
+    /// # Examples
+    ///
+    /// Detecting the trend of a metric:
+    ///
+    /// ```
+    /// use iter_accumulate::IterAccumulate;
+    ///
+    /// let input = [(0.0, 1.0), (1.0, 3.0), (2.0, 5.0), (3.0, 7.0)];
+    /// let mut fits = input.into_iter().running_linear_regression();
+    ///
+    /// fits.next();
+    /// assert_eq!(fits.next(), Some((2.0, 1.0)));
+    /// assert_eq!(fits.last(), Some((2.0, 1.0)));
+    /// ```
+    #[inline]
+    fn running_linear_regression<T>(self) -> RunningLinearRegression<Self, T>
+    where
+        Self: Sized + Iterator<Item = (T, T)>,
+        T: Float,
+    {
+        AccumulateOutput::new(self, Covariance::new(), |mut c, (x, y)| {
+            c.push(x, y);
+            (c, (c.slope(), c.intercept()))
+        })
+    }
+
+    /// Creates an iterator adaptor that yields the exponentially weighted moving average of the
+    /// floating point numbers from the base iterator.
+    ///
+    /// The average starts at the first element, and every following element `x` moves it by
+    /// `alpha * (x - average)`, so a larger `alpha` discounts older elements faster. To start at
+    /// zero and correct for that instead, use [`ewma_bias_corrected()`](Self::ewma_bias_corrected).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `alpha` is not in the range `(0, 1]`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iter_accumulate::IterAccumulate;
+    ///
+    /// let input = [10.0, 20.0, 20.0, 0.0];
+    /// let smoothed: Vec<_> = input.into_iter().ewma(0.5).collect();
+    ///
+    /// assert_eq!(smoothed, [10.0, 15.0, 17.5, 8.75]);
+    /// ```
+    #[inline]
+    fn ewma(self, alpha: Self::Item) -> RunningEwma<Self, Self::Item>
+    where
+        Self: Sized,
+        Self::Item: Float,
+    {
+        AccumulateOutput::new(self, Ewma::new(alpha, false), stats::ewma_step)
+    }
+
+    /// Creates an iterator adaptor that yields the bias corrected exponentially weighted moving
+    /// average of the floating point numbers from the base iterator.
+    ///
+    /// The average starts at zero, and after `n` elements, it is divided by
+    /// `1 - (1 - alpha)^n`, which corrects its bias towards zero like in the Adam optimizer. This
+    /// weights the early elements more evenly than [`ewma()`](Self::ewma), which gives the first
+    /// element the weight of everything before it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `alpha` is not in the range `(0, 1]`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iter_accumulate::IterAccumulate;
+    ///
+    /// let input = [10.0, 40.0];
+    ///
+    /// let corrected: Vec<_> = input.into_iter().ewma_bias_corrected(0.5).collect();
+    /// assert_eq!(corrected, [10.0, 30.0]);
+    ///
+    /// let uncorrected: Vec<_> = input.into_iter().ewma(0.5).collect();
+    /// assert_eq!(uncorrected, [10.0, 25.0]);
+    /// ```
+    #[inline]
+    fn ewma_bias_corrected(self, alpha: Self::Item) -> RunningEwma<Self, Self::Item>
+    where
+        Self: Sized,
+        Self::Item: Float,
+    {
+        AccumulateOutput::new(self, Ewma::new(alpha, true), stats::ewma_step)
+    }
+
+    /// Creates an iterator adaptor that yields the running log-sum-exp of the floating point
+    /// numbers from the base iterator, i.e. the logarithm of the sum of their exponentials.
+    ///
+    /// The exponentials are scaled by the running maximum, so this neither overflows nor
+    /// underflows for large or small numbers, which makes it suitable for summing up
+    /// probabilities in log space, like softmax denominators or log-likelihoods.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iter_accumulate::IterAccumulate;
+    ///
+    /// let log_probs = [1000.0_f64, 1000.0];
+    /// let totals: Vec<_> = log_probs.into_iter().running_log_sum_exp().collect();
+    ///
+    /// assert_eq!(totals[0], 1000.0);
+    /// assert!((totals[1] - (1000.0 + 2.0_f64.ln())).abs() < 1e-9);
+    /// ```
+    #[cfg(feature = "std")]
+    #[inline]
+    fn running_log_sum_exp(self) -> RunningLogSumExp<Self, Self::Item>
+    where
+        Self: Sized,
+        Self::Item: Float,
+    {
+        AccumulateOutput::new(self, LogSumExp::new(), |mut lse, x| {
+            lse.push(x);
+            (lse, lse.value())
+        })
+    }
+
+    /// Creates an iterator adaptor that yields the running central moments of the floating point
+    /// numbers from the base iterator.
+    ///
+    /// Every yielded [`Moments`] value describes the distribution of the elements so far, and
+    /// provides its mean, variance, [kurtosis](Moments::kurtosis) and, with the `std` feature,
+    /// skewness.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iter_accumulate::IterAccumulate;
+    ///
+    /// let input = [1.0_f64, 2.0, 3.0, 4.0];
+    /// let moments = input.into_iter().running_moments().last().unwrap();
+    ///
+    /// assert_eq!(moments.mean(), 2.5);
+    /// assert!((moments.kurtosis() - -1.36).abs() < 1e-12);
+    /// ```
+    #[inline]
+    fn running_moments(self) -> RunningMoments<Self, Self::Item>
+    where
+        Self: Sized,
+        Self::Item: Float,
+    {
+        Accumulate::new(self, Moments::new(), |mut moments, x| {
+            moments.push(x);
+            moments
+        })
+    }
+
+    /// Creates an iterator adaptor that accumulates the elements from the base iterator using the
+    /// provided closure, and restarts from `init` after every `n` elements.
+    ///
+    /// Every value is yielded, so this is like calling [`accumulate()`](Self::accumulate) on
+    /// every chunk of `n` elements separately. See [`chunk_fold()`](Self::chunk_fold) for only
+    /// the final value of every chunk.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is zero.
+    ///
+    /// # Examples
+    ///
+    /// The offset of every line within its page of three lines:
+    ///
+    /// ```
+    /// use iter_accumulate::IterAccumulate;
+    ///
+    /// let line_lengths = [10, 4, 7, 12, 3, 8, 5];
+    /// let offsets: Vec<_> = line_lengths
+    ///     .iter()
+    ///     .accumulate_tumbling(3, 0, |acc, len| acc + len)
+    ///     .collect();
+    ///
+    /// assert_eq!(offsets, [10, 14, 21, 12, 15, 23, 5]);
+    /// ```
+    #[inline]
+    fn accumulate_tumbling<B, F>(self, n: usize, init: B, f: F) -> AccumulateTumbling<Self, B, F>
+    where
+        Self: Sized,
+        B: Clone,
+        F: FnMut(B, Self::Item) -> B,
+    {
+        AccumulateTumbling::new(self, n, init, f)
+    }
+
+    /// Creates an iterator adaptor that accumulates the elements from the base iterator using the
+    /// provided closure, and restarts from `init` whenever the predicate `pred` is satisfied.
+    ///
+    /// `pred` is called with every element and an accumulated value, and decides whether the
+    /// element is a boundary between runs. Depending on `position`:
+    ///
+    /// * With [`ResetPosition::Before`], `pred` gets the value accumulated before the element. If
+    ///   it is satisfied, the element is accumulated into `init` instead, so that it starts the
+    ///   next run.
+    /// * With [`ResetPosition::After`], `pred` gets the new value that includes the element. That
+    ///   value is yielded either way, and if `pred` is satisfied, the next element is accumulated
+    ///   into `init`, so that this element ends the current run. The element is cloned before it
+    ///   is accumulated, so that `pred` can still see it.
+    ///
+    /// # Examples
+    ///
+    /// Counting the fields of every record, where records start with a `#` header:
+    ///
+    /// ```
+    /// use iter_accumulate::{IterAccumulate, ResetPosition};
+    ///
+    /// let lines = ["#a", "x", "y", "#b", "z"];
+    /// let positions: Vec<_> = lines
+    ///     .iter()
+    ///     .accumulate_reset_when(
+    ///         0,
+    ///         |acc, _| acc + 1,
+    ///         |line, _| line.starts_with('#'),
+    ///         ResetPosition::Before,
+    ///     )
+    ///     .collect();
+    ///
+    /// assert_eq!(positions, [1, 2, 3, 1, 2]);
+    /// ```
+    ///
+    /// Summing the lengths of the words in every sentence, where sentences end with a `.`:
+    ///
+    /// ```
+    /// use iter_accumulate::{IterAccumulate, ResetPosition};
+    ///
+    /// let words = "One two. Three four five.".split(' ');
+    /// let lengths: Vec<_> = words
+    ///     .accumulate_reset_when(
+    ///         0,
+    ///         |acc, word| acc + word.len(),
+    ///         |word, _| word.ends_with('.'),
+    ///         ResetPosition::After,
+    ///     )
+    ///     .collect();
+    ///
+    /// assert_eq!(lengths, [3, 7, 5, 9, 14]);
+    /// ```
+    ///
+    /// Closing a batch once its total reaches a threshold:
+    ///
+    /// ```
+    /// use iter_accumulate::{IterAccumulate, ResetPosition};
+    ///
+    /// let totals: Vec<_> = [4; 5]
+    ///     .into_iter()
+    ///     .accumulate_reset_when(0, |acc, x| acc + x, |_, &acc| acc >= 10, ResetPosition::After)
+    ///     .collect();
+    ///
+    /// assert_eq!(totals, [4, 8, 12, 4, 8]);
+    /// ```
+    #[inline]
+    fn accumulate_reset_when<B, F, P>(
+        self,
+        init: B,
+        f: F,
+        pred: P,
+        position: ResetPosition,
+    ) -> AccumulateResetWhen<Self, B, F, P>
+    where
+        Self: Sized,
+        Self::Item: Clone,
+        B: Clone,
+        F: FnMut(B, Self::Item) -> B,
+        P: FnMut(&Self::Item, &B) -> bool,
+    {
+        AccumulateResetWhen::new(self, init, f, pred, position)
+    }
+
+    /// Creates an iterator adaptor that accumulates every run of consecutive elements from the
+    /// base iterator with the same key, and yields the key of every element along with the
+    /// accumulated value of its run so far.
+    ///
+    /// The closure `key` is called with every element, and the accumulated value restarts from
+    /// `init` whenever the key differs from that of the previous element. For elements that are
+    /// sorted or grouped by the key, this is like `SUM(...) OVER (PARTITION BY ...)` in SQL.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iter_accumulate::IterAccumulate;
+    ///
+    /// let sales = [("mon", 3), ("mon", 5), ("tue", 2), ("wed", 1), ("wed", 4)];
+    /// let totals: Vec<_> = sales
+    ///     .iter()
+    ///     .accumulate_runs_by_key(|&&(day, _)| day, 0, |acc, &(_, n)| acc + n)
+    ///     .collect();
+    ///
+    /// assert_eq!(
+    ///     totals,
+    ///     [("mon", 3), ("mon", 8), ("tue", 2), ("wed", 1), ("wed", 5)]
+    /// );
+    /// ```
+    #[inline]
+    fn accumulate_runs_by_key<K, B, G, F>(
+        self,
+        key: G,
+        init: B,
+        f: F,
+    ) -> AccumulateRunsByKey<Self, K, B, G, F>
+    where
+        Self: Sized,
+        K: PartialEq + Clone,
+        B: Clone,
+        G: FnMut(&Self::Item) -> K,
+        F: FnMut(B, Self::Item) -> B,
+    {
+        AccumulateRunsByKey::new(self, key, init, f)
+    }
+
+    /// Creates an iterator adaptor that accumulates the elements from the base iterator
+    /// separately for every key, and yields the key of every element along with the accumulated
+    /// value of that key so far.
+    ///
+    /// The closure `key` is called with every element, and every key starts from `init`. Unlike
+    /// with [`accumulate_runs_by_key()`](Self::accumulate_runs_by_key), the elements of different
+    /// keys can be interleaved. The accumulated value of a key is available using
+    /// [`get()`](AccumulateByKey::get), and those of all keys using
+    /// [`accs()`](AccumulateByKey::accs) and [`into_accs()`](AccumulateByKey::into_accs).
+    ///
+    /// The accumulated values are kept in a [`BTreeMap`], as there is no hash map without the
+    /// standard library. The keys therefore have to implement [`Ord`] instead of [`Hash`], and
+    /// `accs()` and `into_accs()` yield the keys in ascending order.
+    ///
+    /// [`BTreeMap`]: alloc::collections::BTreeMap
+    /// [`Hash`]: core::hash::Hash
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iter_accumulate::IterAccumulate;
+    ///
+    /// let packets = [("alice", 100), ("bob", 40), ("alice", 20), ("bob", 5)];
+    /// let mut totals = packets
+    ///     .iter()
+    ///     .accumulate_by_key(|&&(user, _)| user, 0, |acc, &(_, bytes)| acc + bytes);
+    ///
+    /// assert_eq!(
+    ///     totals.by_ref().collect::<Vec<_>>(),
+    ///     [("alice", 100), ("bob", 40), ("alice", 120), ("bob", 45)]
+    /// );
+    /// assert_eq!(totals.get("bob"), Some(&45));
+    /// assert_eq!(totals.accs().count(), 2);
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn accumulate_by_key<K, B, G, F>(
+        self,
+        key: G,
+        init: B,
+        f: F,
+    ) -> AccumulateByKey<Self, K, B, G, F>
+    where
+        Self: Sized,
+        K: Ord + Clone,
+        B: Clone,
+        G: FnMut(&Self::Item) -> K,
+        F: FnMut(B, Self::Item) -> B,
+    {
+        AccumulateByKey::new(self, key, init, f)
+    }
+
+    /// Creates an iterator adaptor that groups the `(timestamp, value)` elements from the base
+    /// iterator into consecutive windows of the duration `width`, and yields the start of every
+    /// window, the timestamp of its last element and its accumulated value once it is complete.
+    ///
+    /// The windows are aligned to the timestamp of the first element: the `k`-th window covers the
+    /// timestamps from `first + k * width` up to, but excluding, `first + (k + 1) * width`. Windows
+    /// without any elements are skipped, which takes time logarithmic in the number of skipped
+    /// windows. The values of every window are accumulated starting from `init`. The timestamps can
+    /// be anything that a duration can be added to and that can be subtracted to get one, like
+    /// integers or `std::time::Instant`s, and have to be sorted.
+    ///
+    /// # Panics
+    ///
+    /// The returned iterator panics if `width` is not positive.
+    ///
+    /// # Examples
+    ///
+    /// Counting the requests per minute, where a minute without requests yields nothing:
+    ///
+    /// ```
+    /// use iter_accumulate::IterAccumulate;
+    ///
+    /// let requests = [(0, 1), (12, 1), (59, 1), (60, 1), (130, 1), (170, 1)];
+    /// let per_minute: Vec<_> = requests
+    ///     .into_iter()
+    ///     .tumbling_time_windows(60, 0, |count, n| count + n)
+    ///     .collect();
+    ///
+    /// assert_eq!(per_minute, [(0, 59, 3), (60, 60, 1), (120, 170, 2)]);
+    ///
+    /// let sparse = [(0, 1), (59, 1), (61, 1), (185, 1)];
+    /// let per_minute: Vec<_> = sparse
+    ///     .into_iter()
+    ///     .tumbling_time_windows(60, 0, |count, n| count + n)
+    ///     .collect();
+    ///
+    /// assert_eq!(per_minute, [(0, 59, 2), (60, 61, 1), (180, 185, 1)]);
+    /// ```
+    ///
+    /// Counting events per millisecond, with an hour without events in between:
+    ///
+    /// ```
+    /// use iter_accumulate::IterAccumulate;
+    /// use std::time::{Duration, Instant};
+    ///
+    /// let start = Instant::now();
+    /// let later = start + Duration::from_secs(3600);
+    /// let events = [(start, ()), (later, ()), (later, ())];
+    /// let per_millisecond: Vec<_> = events
+    ///     .into_iter()
+    ///     .tumbling_time_windows(Duration::from_millis(1), 0, |count, ()| count + 1)
+    ///     .collect();
+    ///
+    /// assert_eq!(per_millisecond, [(start, start, 1), (later, later, 2)]);
+    /// ```
+    #[inline]
+    fn tumbling_time_windows<T, V, D, B, F>(
+        self,
+        width: D,
+        init: B,
+        f: F,
+    ) -> TimeWindows<Self, T, D, B, F>
+    where
+        Self: Sized + Iterator<Item = (T, V)>,
+        T: Copy + core::ops::Add<D, Output = T> + core::ops::Sub<Output = D>,
+        D: Clone + PartialOrd + core::ops::Add<Output = D> + core::ops::Sub<Output = D>,
+        B: Clone,
+        F: FnMut(B, V) -> B,
+    {
+        TimeWindows::new(self, width, false, time::tumbling_start, init, f)
+    }
+
+    /// Creates an iterator adaptor that groups the `(timestamp, value)` elements from the base
+    /// iterator into sessions, and yields the first and last timestamp and the accumulated value
+    /// of every session once it is complete.
+    ///
+    /// A session ends when the gap between the timestamps of two consecutive elements is larger
+    /// than `gap`, and the next session starts with the later element. The values of every
+    /// session are accumulated starting from `init`. Like with
+    /// [`tumbling_time_windows()`](Self::tumbling_time_windows), the timestamps have to be
+    /// sorted.
+    ///
+    /// # Examples
+    ///
+    /// Splitting the page views of a user into visits with no more than 30 minutes between two
+    /// page views:
+    ///
+    /// ```
+    /// use iter_accumulate::IterAccumulate;
+    ///
+    /// let page_views = [(0, "/"), (5, "/docs"), (20, "/api"), (90, "/"), (95, "/blog")];
+    /// let visits: Vec<_> = page_views
+    ///     .into_iter()
+    ///     .session_windows(30, 0, |pages, _| pages + 1)
+    ///     .collect();
+    ///
+    /// assert_eq!(visits, [(0, 20, 3), (90, 95, 2)]);
+    /// ```
+    #[inline]
+    fn session_windows<T, V, D, B, F>(self, gap: D, init: B, f: F) -> TimeWindows<Self, T, D, B, F>
+    where
+        Self: Sized + Iterator<Item = (T, V)>,
+        T: Copy + core::ops::Sub<Output = D>,
+        D: PartialOrd,
+        B: Clone,
+        F: FnMut(B, V) -> B,
+    {
+        TimeWindows::new(self, gap, true, time::session_start, init, f)
+    }
+
+    /// Creates an iterator adaptor that accumulates the values of the `(timestamp, value)`
+    /// elements from the base iterator within the trailing `duration`, and yields the timestamp of
+    /// every element along with the accumulated value of its window.
+    ///
+    /// The window of an element contains all elements whose timestamps are less than `duration`
+    /// before it. Like with [`rolling_fold()`](Self::rolling_fold), values are added to the
+    /// accumulated value using the closure `add` as they enter the window and removed again using
+    /// the closure `remove` as they leave it, so `remove` has to undo `add`. The timestamps have
+    /// to be sorted.
+    ///
+    /// # Examples
+    ///
+    /// The number of bytes received in the last ten seconds:
+    ///
+    /// ```
+    /// use iter_accumulate::IterAccumulate;
+    /// use std::time::Duration;
+    ///
+    /// let s = Duration::from_secs;
+    /// let packets = [(s(0), 500), (s(4), 200), (s(9), 100), (s(12), 50), (s(30), 10)];
+    /// let throughput: Vec<_> = packets
+    ///     .into_iter()
+    ///     .sliding_time_window(s(10), 0, |acc, n| acc + n, |acc, n| acc - n)
+    ///     .map(|(_, bytes)| bytes)
+    ///     .collect();
+    ///
+    /// assert_eq!(throughput, [500, 700, 800, 350, 10]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn sliding_time_window<T, V, D, B, F, G>(
+        self,
+        duration: D,
+        init: B,
+        add: F,
+        remove: G,
+    ) -> SlidingTimeWindow<Self, T, V, D, B, F, G>
+    where
+        Self: Sized + Iterator<Item = (T, V)>,
+        T: Copy + core::ops::Sub<Output = D>,
+        V: Clone,
+        D: PartialOrd,
+        B: Clone,
+        F: FnMut(B, V) -> B,
+        G: FnMut(B, V) -> B,
+    {
+        SlidingTimeWindow::new(self, duration, init, add, remove)
+    }
+
+    /// Creates an iterator adaptor that folds every chunk of `n` consecutive elements from the base
+    /// iterator into one value, starting from `init` for every chunk.
+    ///
+    /// This downsamples or aggregates the elements without buffering them. `partial` determines
+    /// whether the last chunk is folded if there are fewer than `n` elements left for it. See
+    /// [`accumulate_tumbling()`](Self::accumulate_tumbling) for all the intermediate values.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iter_accumulate::{IterAccumulate, PartialChunk};
+    ///
+    /// let samples = [3, 1, 4, 1, 5, 9, 2];
+    ///
+    /// let sums: Vec<_> = samples
+    ///     .iter()
+    ///     .chunk_fold(3, PartialChunk::Keep, 0, |acc, x| acc + x)
+    ///     .collect();
+    /// assert_eq!(sums, [8, 15, 2]);
+    ///
+    /// let maxima: Vec<_> = samples
+    ///     .iter()
+    ///     .chunk_fold(3, PartialChunk::Discard, 0, |acc, &x| acc.max(x))
+    ///     .collect();
+    /// assert_eq!(maxima, [4, 9]);
+    /// ```
+    #[inline]
+    fn chunk_fold<B, F>(
+        self,
+        n: usize,
+        partial: PartialChunk,
+        init: B,
+        f: F,
+    ) -> ChunkFold<Self, B, F>
+    where
+        Self: Sized,
+        B: Clone,
+        F: FnMut(B, Self::Item) -> B,
+    {
+        ChunkFold::new(self, n, partial, init, f)
+    }
+
+    /// Creates an iterator adaptor that yields the cumulative compounded returns of the
+    /// per-period returns from the base iterator.
+    ///
+    /// The returns are simple returns, like `0.05` for a gain of 5%, and the cumulative return
+    /// after every period is the product of all `1 + r` so far, minus one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iter_accumulate::IterAccumulate;
+    ///
+    /// let returns = [0.5, -0.5, 1.0];
+    /// let cumulative: Vec<_> = returns.into_iter().cumulative_returns().collect();
+    ///
+    /// assert_eq!(cumulative, [0.5, -0.25, 0.5]);
+    /// ```
+    #[cfg(feature = "finance")]
+    #[inline]
+    fn cumulative_returns(self) -> CumulativeReturns<Self, Self::Item>
+    where
+        Self: Sized,
+        Self::Item: Float,
+    {
+        AccumulateOutput::new(self, Float::ONE, finance::compound)
+    }
+
+    /// Creates an iterator adaptor that yields the running maximum drawdown of the values from
+    /// the base iterator, like prices or portfolio values.
+    ///
+    /// The maximum drawdown is the largest relative decline from a peak to a later trough so far,
+    /// between zero and one. The current [`Drawdown`], including the current peak and the peak and
+    /// trough of the maximum drawdown, is available using [`acc()`](AccumulateOutput::acc).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iter_accumulate::IterAccumulate;
+    ///
+    /// let prices = [100.0, 120.0, 90.0, 110.0, 60.0, 130.0];
+    /// let mut drawdowns = prices.into_iter().running_max_drawdown();
+    /// let max: Vec<_> = drawdowns.by_ref().collect();
+    ///
+    /// assert_eq!(max, [0.0, 0.0, 0.25, 0.25, 0.5, 0.5]);
+    /// assert_eq!(drawdowns.acc().peak_trough(), Some((120.0, 60.0)));
+    /// assert_eq!(drawdowns.acc().drawdown(), 0.0);
+    /// ```
+    #[cfg(feature = "finance")]
+    #[inline]
+    fn running_max_drawdown(self) -> RunningMaxDrawdown<Self, Self::Item>
+    where
+        Self: Sized,
+        Self::Item: Float,
+    {
+        AccumulateOutput::new(self, Drawdown::new(), |mut drawdown, x| {
+            drawdown.push(x);
+            (drawdown, drawdown.max_drawdown())
+        })
+    }
+
+    /// Creates an iterator adaptor that yields the volatility, i.e. the sample standard deviation,
+    /// of the last `window` per-period returns from the base iterator.
+    ///
+    /// This is the same as `rolling_stddev(window, warm_up, VarianceKind::Sample)`. The
+    /// volatility is per period, so it has to be multiplied by the square root of the number of
+    /// periods per year to annualize it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `window` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iter_accumulate::{IterAccumulate, WarmUp};
+    ///
+    /// let returns = [0.01, 0.03, -0.01, 0.01];
+    /// let volatility: Vec<_> = returns
+    ///     .into_iter()
+    ///     .rolling_volatility(3, WarmUp::Skip)
+    ///     .collect();
+    ///
+    /// assert!((volatility[0] - 0.02f64).abs() < 1e-12);
+    /// assert!((volatility[1] - 0.02f64).abs() < 1e-12);
+    ///
+    /// let annualized = volatility[0] * 252f64.sqrt();
+    /// assert!((annualized - 0.3175f64).abs() < 1e-4);
+    /// ```
+    #[cfg(feature = "finance")]
+    #[inline]
+    fn rolling_volatility(self, window: usize, warm_up: WarmUp) -> RollingStddev<Self, Self::Item>
+    where
+        Self: Sized,
+        Self::Item: Float,
+    {
+        self.rolling_stddev(window, warm_up, VarianceKind::Sample)
+    }
+
+    /// Creates an iterator adaptor that yields the values of a hash chain over the elements from
+    /// the base iterator, starting from `seed`.
+    ///
+    /// Every chain value is `hash(&previous, &item)`, i.e. `h_n = H(h_{n-1} || item_n)`, where the
+    /// previous value of the first element is `seed`. As every value depends on all elements
+    /// before it, changing, removing or reordering any element changes all later values, which
+    /// makes the chain tamper-evident when the closure uses a cryptographic hash function. The
+    /// last value is available through [`HashChain::head()`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iter_accumulate::IterAccumulate;
+    /// use std::hash::{DefaultHasher, Hash, Hasher};
+    ///
+    /// // Not a cryptographic hash function, only to keep the example short.
+    /// let hash = |prev: &u64, entry: &&str| {
+    ///     let mut hasher = DefaultHasher::new();
+    ///     prev.hash(&mut hasher);
+    ///     entry.hash(&mut hasher);
+    ///     hasher.finish()
+    /// };
+    ///
+    /// let log = ["login alice", "delete file", "logout alice"];
+    /// let chain: Vec<_> = log.into_iter().hash_chain(0, hash).collect();
+    ///
+    /// let tampered = ["login alice", "read file", "logout alice"];
+    /// let tampered_chain: Vec<_> = tampered.into_iter().hash_chain(0, hash).collect();
+    ///
+    /// assert_eq!(chain[0], tampered_chain[0]);
+    /// assert_ne!(chain[1], tampered_chain[1]);
+    /// assert_ne!(chain[2], tampered_chain[2]);
+    /// ```
+    #[inline]
+    fn hash_chain<H, F>(self, seed: H, hash: F) -> HashChain<Self, H, F>
+    where
+        Self: Sized,
+        H: Clone,
+        F: FnMut(&H, &Self::Item) -> H,
+    {
+        HashChain::new(self, seed, hash)
+    }
+
+    /// Creates an iterator adaptor that yields the values of a hash chain over the elements from
+    /// the base iterator, hashing them using `build_hasher`.
+    ///
+    /// This is like [`hash_chain()`](Self::hash_chain) with a seed of zero, where every chain
+    /// value is computed by writing the previous value and then hashing the element into a new
+    /// [`Hasher`](core::hash::Hasher), and finishing it. The hashers of the standard library are
+    /// not cryptographic, so this only detects accidental changes, not deliberate tampering.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iter_accumulate::IterAccumulate;
+    /// use std::hash::{BuildHasherDefault, DefaultHasher};
+    ///
+    /// let build_hasher = BuildHasherDefault::<DefaultHasher>::default();
+    /// let blocks = [b"genesis".as_slice(), b"block 1", b"block 2"];
+    /// let chain: Vec<_> = blocks.iter().hash_chain_with(build_hasher.clone()).collect();
+    ///
+    /// let mut replay = blocks.iter().hash_chain_with(build_hasher);
+    /// assert_eq!(replay.by_ref().last(), Some(chain[2]));
+    /// assert_eq!(replay.head(), chain[2]);
+    /// ```
+    #[inline]
+    fn hash_chain_with<S>(self, build_hasher: S) -> HasherChain<Self, S>
+    where
+        Self: Sized,
+        Self::Item: core::hash::Hash,
+        S: core::hash::BuildHasher,
+    {
+        HasherChain::new(self, build_hasher)
+    }
+
+    /// Creates an iterator adaptor that accumulates the elements from the base iterator using the
+    /// provided closure, using the first element as the initial value.
+    ///
+    /// The first element of the base iterator is yielded as is. Every following element is
+    /// accumulated into the previously yielded one, like with [`accumulate()`](Self::accumulate).
+    /// This is useful for operations that have no obvious initial value, like taking the maximum.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iter_accumulate::IterAccumulate;
+    ///
+    /// let input = [3, 1, 4, 1, 5];
+    /// let mut iter = input.into_iter().accumulate_first(|acc, i| acc.max(i));
+    ///
+    /// assert_eq!(iter.next(), Some(3));
+    /// assert_eq!(iter.next(), Some(3));
+    /// assert_eq!(iter.next(), Some(4));
+    /// assert_eq!(iter.next(), Some(4));
+    /// assert_eq!(iter.next(), Some(5));
+    /// assert_eq!(iter.next(), None);
+    ///
+    /// let prefix_sums: i32 = input.into_iter().accumulate_first(|acc, i| acc + i).sum();
+    /// assert_eq!(prefix_sums, 3 + 4 + 8 + 9 + 14);
+    /// ```
+    #[inline]
+    fn accumulate_first<F>(self, f: F) -> AccumulateFirst<Self, F>
+    where
+        Self: Sized,
+        Self::Item: Clone,
+        F: FnMut(Self::Item, Self::Item) -> Self::Item,
+    {
+        AccumulateFirst::new(self, f)
+    }
+
+    /// Creates an iterator adaptor that accumulates the elements from the base iterator by
+    /// updating the accumulated value in place, and yields clones of the accumulated value.
+    ///
+    /// Unlike with [`accumulate()`](Self::accumulate), the closure gets a mutable reference to
+    /// the accumulated value instead of taking and returning it by value. This is convenient for
+    /// large accumulated values like buffers, which can then be updated in place.
+    /// To yield something other than a clone of the whole accumulated value, use
+    /// [`accumulate_mut_map()`](Self::accumulate_mut_map).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iter_accumulate::IterAccumulate;
+    ///
+    /// let input = [3, 1, 2];
+    /// let mut iter = input.iter().accumulate_mut(Vec::new(), |acc, &i| acc.push(i));
+    ///
+    /// assert_eq!(iter.next(), Some(vec![3]));
+    /// assert_eq!(iter.next(), Some(vec![3, 1]));
+    /// assert_eq!(iter.next(), Some(vec![3, 1, 2]));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    #[inline]
+    fn accumulate_mut<B, F>(self, init: B, f: F) -> AccumulateMut<Self, B, F, fn(&B) -> B>
+    where
+        Self: Sized,
+        B: Clone,
+        F: FnMut(&mut B, Self::Item),
+    {
+        AccumulateMut::new(self, init, f, B::clone)
+    }
+
+    /// Creates an iterator adaptor that accumulates the elements from the base iterator by
+    /// updating the accumulated value in place, and yields the result of the `project` closure
+    /// applied to the accumulated value.
+    ///
+    /// This is like [`accumulate_mut()`](Self::accumulate_mut), but the accumulated value does
+    /// not have to be cloned for every element.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iter_accumulate::IterAccumulate;
+    ///
+    /// let input = [1, 3, 2];
+    /// let mut iter = input.iter().accumulate_mut_map(
+    ///     Vec::new(),
+    ///     |acc, &i| acc.push(i),
+    ///     |acc| acc.iter().max().copied(),
+    /// );
+    ///
+    /// assert_eq!(iter.next(), Some(Some(1)));
+    /// assert_eq!(iter.next(), Some(Some(3)));
+    /// assert_eq!(iter.next(), Some(Some(3)));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    #[inline]
+    fn accumulate_mut_map<B, F, P, O>(
+        self,
+        init: B,
+        f: F,
+        project: P,
+    ) -> AccumulateMut<Self, B, F, P>
+    where
+        Self: Sized,
+        F: FnMut(&mut B, Self::Item),
+        P: FnMut(&B) -> O,
+    {
+        AccumulateMut::new(self, init, f, project)
+    }
+
+    /// Creates a lending iterator adaptor that accumulates the elements from the base iterator by
+    /// updating the accumulated value in place, and yields references to the accumulated value.
+    ///
+    /// This is like [`accumulate_mut()`](Self::accumulate_mut), but the accumulated value is
+    /// never cloned. Because the yielded references borrow from the adaptor, it does not
+    /// implement [`Iterator`] but this crate's [`LendingIterator`] trait, so every reference has
+    /// to be dropped before the next element is requested.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iter_accumulate::{IterAccumulate, LendingIterator};
+    ///
+    /// let input = ["a", "b", "c"];
+    /// let mut iter = input.iter().accumulate_ref(String::new(), |acc, s| acc.push_str(s));
+    ///
+    /// assert_eq!(iter.next().map(String::as_str), Some("a"));
+    /// assert_eq!(iter.next().map(String::as_str), Some("ab"));
+    ///
+    /// let mut lengths = Vec::new();
+    /// while let Some(acc) = iter.next() {
+    ///     lengths.push(acc.len());
+    /// }
+    /// assert_eq!(lengths, [3]);
+    /// assert_eq!(iter.into_acc(), "abc");
+    /// ```
+    #[inline]
+    fn accumulate_ref<B, F>(self, init: B, f: F) -> AccumulateRef<Self, B, F>
+    where
+        Self: Sized,
+        F: FnMut(&mut B, Self::Item),
+    {
+        AccumulateRef::new(self, init, f)
+    }
+
+    /// Creates an iterator adaptor that accumulates the elements from the base iterator using the
+    /// provided fallible closure.
+    ///
+    /// As long as the closure returns [`Ok`], this behaves like
+    /// [`accumulate()`](Self::accumulate), wrapping every accumulated value in [`Ok`]. The first
+    /// time the closure returns an [`Err`], that error is yielded and the returned iterator is
+    /// finished: it neither polls the base iterator nor calls the closure again.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iter_accumulate::IterAccumulate;
+    ///
+    /// let input = [100u8, 100, 100];
+    /// let mut iter = input
+    ///     .iter()
+    ///     .try_accumulate(0u8, |acc, &i| acc.checked_add(i).ok_or("overflow"));
+    ///
+    /// assert_eq!(iter.next(), Some(Ok(100)));
+    /// assert_eq!(iter.next(), Some(Ok(200)));
+    /// assert_eq!(iter.next(), Some(Err("overflow")));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    ///
+    /// Collecting into a [`Result`] gives either all accumulated values or the first error:
+    ///
+    /// ```
+    /// use iter_accumulate::IterAccumulate;
+    ///
+    /// let input = ["1", "2", "3"];
+    /// let sums: Result<Vec<i32>, _> = input
+    ///     .iter()
+    ///     .try_accumulate(0, |acc, s| s.parse::<i32>().map(|i| acc + i))
+    ///     .collect();
+    ///
+    /// assert_eq!(sums, Ok(vec![1, 3, 6]));
+    /// ```
+    #[inline]
+    fn try_accumulate<B, E, F>(self, init: B, f: F) -> TryAccumulate<Self, B, F>
+    where
+        Self: Sized,
+        B: Clone,
+        F: FnMut(B, Self::Item) -> Result<B, E>,
+    {
+        TryAccumulate::new(self, init, f)
+    }
+
+    /// Creates an iterator adaptor that accumulates the [`Ok`] values from a base iterator of
+    /// [`Result`]s using the provided closure, and stops at the first [`Err`].
+    ///
+    /// Every accumulated value is yielded wrapped in [`Ok`]. The first error of the base iterator
+    /// is yielded as is, and afterwards, the returned iterator is finished: it neither polls the
+    /// base iterator nor calls the closure again.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iter_accumulate::IterAccumulate;
+    ///
+    /// let input = ["1", "2", "x", "4"];
+    /// let mut iter = input
+    ///     .iter()
+    ///     .map(|s| s.parse::<i32>())
+    ///     .accumulate_ok(0, |acc, i| acc + i);
+    ///
+    /// assert_eq!(iter.next(), Some(Ok(1)));
+    /// assert_eq!(iter.next(), Some(Ok(3)));
+    /// assert!(matches!(iter.next(), Some(Err(_))));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    #[inline]
+    fn accumulate_ok<T, E, B, F>(self, init: B, f: F) -> AccumulateOk<Self, B, F>
+    where
+        Self: Sized + Iterator<Item = Result<T, E>>,
+        B: Clone,
+        F: FnMut(B, T) -> B,
+    {
+        AccumulateOk::new(self, init, f)
+    }
+
+    /// Creates an iterator adaptor that accumulates the [`Some`] values from a base iterator of
+    /// [`Option`]s using the provided closure, and stops at the first [`None`].
+    ///
+    /// The accumulated values are yielded without being wrapped in an [`Option`]. Once the base
+    /// iterator yields [`None`], the returned iterator is finished: it neither polls the base
+    /// iterator nor calls the closure again. To skip over [`None`] values instead, use
+    /// [`accumulate_some_or_none()`](Self::accumulate_some_or_none).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iter_accumulate::IterAccumulate;
+    ///
+    /// // `None` marks the end of the data
+    /// let readings = [Some(2), Some(3), None, Some(4)];
+    /// let mut iter = readings.into_iter().accumulate_some(0, |acc, i| acc + i);
+    ///
+    /// assert_eq!(iter.next(), Some(2));
+    /// assert_eq!(iter.next(), Some(5));
+    /// assert_eq!(iter.next(), None);
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    #[inline]
+    fn accumulate_some<T, B, F>(self, init: B, f: F) -> AccumulateSome<Self, B, F>
+    where
+        Self: Sized + Iterator<Item = Option<T>>,
+        B: Clone,
+        F: FnMut(B, T) -> B,
+    {
+        AccumulateSome::new(self, init, f)
+    }
+
+    /// Creates an iterator adaptor that accumulates the [`Some`] values from a base iterator of
+    /// [`Option`]s using the provided closure, and passes [`None`] values through.
+    ///
+    /// For every [`Some`] value, the new accumulated value is yielded wrapped in [`Some`]. For
+    /// every [`None`] value, [`None`] is yielded and the accumulated value stays the same.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iter_accumulate::IterAccumulate;
+    ///
+    /// let readings = [Some(2), Some(3), None, Some(4)];
+    /// let mut iter = readings.into_iter().accumulate_some_or_none(0, |acc, i| acc + i);
+    ///
+    /// assert_eq!(iter.next(), Some(Some(2)));
+    /// assert_eq!(iter.next(), Some(Some(5)));
+    /// assert_eq!(iter.next(), Some(None));
+    /// assert_eq!(iter.next(), Some(Some(9)));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    #[inline]
+    fn accumulate_some_or_none<T, B, F>(self, init: B, f: F) -> AccumulateSomeOrNone<Self, B, F>
+    where
+        Self: Sized + Iterator<Item = Option<T>>,
+        B: Clone,
+        F: FnMut(B, T) -> B,
+    {
+        AccumulateSomeOrNone::new(self, init, f)
+    }
+
+    /// Creates an iterator adaptor that accumulates the elements from the base iterator using the
+    /// provided closure, until the closure decides to stop.
+    ///
+    /// The closure returns [`ControlFlow::Continue`] with the new accumulated value to go on, or
+    /// [`ControlFlow::Break`] with a final value to stop. That final value is still yielded, but
+    /// afterwards, the returned iterator is finished: it neither polls the base iterator nor calls
+    /// the closure again.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::ops::ControlFlow;
+    /// use iter_accumulate::IterAccumulate;
+    ///
+    /// // Sum up the elements, but stop at the first negative one
+    /// let input = [1, 2, -3, 4];
+    /// let mut iter = input.iter().accumulate_while(0, |acc, &i| {
+    ///     if i < 0 {
+    ///         ControlFlow::Break(acc)
+    ///     } else {
+    ///         ControlFlow::Continue(acc + i)
+    ///     }
+    /// });
+    ///
+    /// assert_eq!(iter.next(), Some(1));
+    /// assert_eq!(iter.next(), Some(3));
+    /// assert_eq!(iter.next(), Some(3));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    #[inline]
+    fn accumulate_while<B, F>(self, init: B, f: F) -> AccumulateWhile<Self, B, F>
+    where
+        Self: Sized,
+        B: Clone,
+        F: FnMut(B, Self::Item) -> ControlFlow<B, B>,
+    {
+        AccumulateWhile::new(self, init, f)
+    }
+
+    /// Creates an iterator adaptor that accumulates the elements from the base iterator using the
+    /// provided closure `f`, until the accumulated value satisfies the predicate `pred`.
+    ///
+    /// With [`Boundary::Inclusive`], the first accumulated value that satisfies `pred` is yielded
+    /// as the last element, with [`Boundary::Exclusive`], it is not yielded. Either way, the
+    /// returned iterator is finished afterwards: it neither polls the base iterator nor calls the
+    /// closures again.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iter_accumulate::{Boundary, IterAccumulate};
+    ///
+    /// let input = [3, 4, 5, 6];
+    ///
+    /// let inclusive: Vec<_> = input
+    ///     .iter()
+    ///     .accumulate_until(0, |acc, i| acc + i, |&acc| acc >= 10, Boundary::Inclusive)
+    ///     .collect();
+    /// assert_eq!(inclusive, [3, 7, 12]);
+    ///
+    /// let exclusive: Vec<_> = input
+    ///     .iter()
+    ///     .accumulate_until(0, |acc, i| acc + i, |&acc| acc >= 10, Boundary::Exclusive)
+    ///     .collect();
+    /// assert_eq!(exclusive, [3, 7]);
+    /// ```
+    #[inline]
+    fn accumulate_until<B, F, P>(
+        self,
+        init: B,
+        f: F,
+        pred: P,
+        boundary: Boundary,
+    ) -> AccumulateUntil<Self, B, F, P>
+    where
+        Self: Sized,
+        B: Clone,
+        F: FnMut(B, Self::Item) -> B,
+        P: FnMut(&B) -> bool,
+    {
+        AccumulateUntil::new(self, init, f, pred, true, boundary)
+    }
+
+    /// Creates an iterator adaptor that accumulates the elements from the base iterator using the
+    /// provided closure `f`, as long as the accumulated value satisfies the predicate `pred`.
+    ///
+    /// This is the counterpart to [`accumulate_until()`](Self::accumulate_until): with
+    /// [`Boundary::Inclusive`], the first accumulated value that does *not* satisfy `pred` is
+    /// yielded as the last element, with [`Boundary::Exclusive`], it is not yielded.
+    ///
+    /// # Examples
+    ///
+    /// Filling bins of capacity 10, stopping before a bin would overflow:
+    ///
+    /// ```
+    /// use iter_accumulate::{Boundary, IterAccumulate};
+    ///
+    /// let sizes = [4, 3, 2, 5, 1];
+    /// let fill: Vec<_> = sizes
+    ///     .iter()
+    ///     .accumulate_while_acc(0, |acc, size| acc + size, |&acc| acc <= 10, Boundary::Exclusive)
+    ///     .collect();
+    ///
+    /// assert_eq!(fill, [4, 7, 9]);
+    /// ```
+    #[inline]
+    fn accumulate_while_acc<B, F, P>(
+        self,
+        init: B,
+        f: F,
+        pred: P,
+        boundary: Boundary,
+    ) -> AccumulateUntil<Self, B, F, P>
+    where
+        Self: Sized,
+        B: Clone,
+        F: FnMut(B, Self::Item) -> B,
+        P: FnMut(&B) -> bool,
+    {
+        AccumulateUntil::new(self, init, f, pred, false, boundary)
+    }
+
+    /// Creates an iterator adaptor that accumulates the elements from the base iterator using the
+    /// provided closure `f`, and that can also be iterated from the back by undoing `f` with the
+    /// `inverse` closure.
+    ///
+    /// Iterating from the front behaves exactly like [`accumulate()`](Self::accumulate).
+    /// When the base iterator is a [`DoubleEndedIterator`] that can be cloned, the returned
+    /// iterator is double-ended as well: the first element yielded from the back is the value
+    /// that [`fold()`](Iterator::fold) would have returned, and every following element
+    /// is obtained by applying `inverse` to the previous one and the element from the back of
+    /// the base iterator.
+    /// To get that first value, a clone of the base iterator is folded once, the first time an
+    /// element is requested from the back.
+    ///
+    /// For the results to be consistent, `inverse(f(acc, item), item)` has to be equal to `acc`
+    /// for all `acc` and `item`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iter_accumulate::IterAccumulate;
+    ///
+    /// let input = [1, 2, 3, 4, 5];
+    /// let mut iter = input
+    ///     .iter()
+    ///     .accumulate_invertible(0, |acc, i| acc + i, |acc, i| acc - i);
+    ///
+    /// assert_eq!(iter.next(), Some(1));
+    /// assert_eq!(iter.next_back(), Some(15));
+    /// assert_eq!(iter.next_back(), Some(10));
+    /// assert_eq!(iter.next(), Some(3));
+    /// assert_eq!(iter.next_back(), Some(6));
+    /// assert_eq!(iter.next(), None);
+    /// assert_eq!(iter.next_back(), None);
+    ///
+    /// let reversed: Vec<_> = input
+    ///     .iter()
+    ///     .accumulate_invertible(0, |acc, i| acc + i, |acc, i| acc - i)
+    ///     .rev()
+    ///     .collect();
+    /// assert_eq!(reversed, [15, 10, 6, 3, 1]);
+    /// ```
+    ///
+    /// The accumulated value does not have to be [`Copy`], e.g. the path to every node along a
+    /// route can be built by pushing and undone by popping:
+    ///
+    /// ```
+    /// use iter_accumulate::IterAccumulate;
+    ///
+    /// let route = ["usr", "share", "doc"];
+    /// let push = |mut path: Vec<&'static str>, dir| {
+    ///     path.push(dir);
+    ///     path
+    /// };
+    /// let pop = |mut path: Vec<&'static str>, _| {
+    ///     path.pop();
+    ///     path
+    /// };
+    /// let mut paths = route.into_iter().accumulate_invertible(Vec::new(), push, pop);
+    ///
+    /// assert_eq!(paths.next_back(), Some(vec!["usr", "share", "doc"]));
+    /// assert_eq!(paths.next_back(), Some(vec!["usr", "share"]));
+    /// assert_eq!(paths.next(), Some(vec!["usr"]));
+    /// assert_eq!(paths.next(), None);
+    /// ```
+    #[inline]
+    fn accumulate_invertible<B, F, G>(
+        self,
+        init: B,
+        f: F,
+        inverse: G,
+    ) -> AccumulateInvertible<Self, B, F, G>
+    where
+        Self: Sized,
+        B: Clone,
+        F: FnMut(B, Self::Item) -> B,
+        G: FnMut(B, Self::Item) -> B,
+    {
+        AccumulateInvertible::new(self, init, f, inverse)
+    }
+
+    /// Creates an iterator adaptor that accumulates the elements from the back of the base
+    /// iterator using the provided closure.
+    ///
+    /// The suffix accumulations are yielded in reverse order, i.e. starting with the accumulation
+    /// of only the last element. This is the same as calling [`rev()`](Iterator::rev) before
+    /// [`accumulate()`](Self::accumulate).
+    /// To get the suffix accumulations in the order of the base iterator instead, use
+    /// `suffix_accumulate_aligned()`, which needs the `alloc` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iter_accumulate::IterAccumulate;
+    ///
+    /// let input = [3, 1, 4, 1, 5];
+    /// let mut iter = input.iter().suffix_accumulate(0, |acc, &i| acc.max(i));
+    ///
+    /// assert_eq!(iter.next(), Some(5));
+    /// assert_eq!(iter.next(), Some(5));
+    /// assert_eq!(iter.next(), Some(5));
+    /// assert_eq!(iter.next(), Some(5));
+    /// assert_eq!(iter.next(), Some(5));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    #[inline]
+    fn suffix_accumulate<B, F>(self, init: B, f: F) -> Accumulate<iter::Rev<Self>, B, F>
+    where
+        Self: Sized + DoubleEndedIterator,
+        B: Clone,
+        F: FnMut(B, Self::Item) -> B,
+    {
+        Accumulate::new(self.rev(), init, f)
+    }
+
+    /// Creates an iterator adaptor that accumulates the elements from the back of the base
+    /// iterator using the provided closure, and yields the suffix accumulations in the order of
+    /// the base iterator.
+    ///
+    /// The `n`-th element yielded is the accumulation of the `n`-th and all following elements of
+    /// the base iterator. Because the first yielded element already depends on all elements of
+    /// the base iterator, the whole base iterator is accumulated into a buffer when the first
+    /// element is requested.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iter_accumulate::IterAccumulate;
+    ///
+    /// let input = [3, 1, 4, 1, 5];
+    /// let mut iter = input.iter().suffix_accumulate_aligned(0, |acc, i| acc + i);
+    ///
+    /// assert_eq!(iter.next(), Some(14));
+    /// assert_eq!(iter.next(), Some(11));
+    /// assert_eq!(iter.next(), Some(10));
+    /// assert_eq!(iter.next(), Some(6));
+    /// assert_eq!(iter.next(), Some(5));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn suffix_accumulate_aligned<B, F>(self, init: B, f: F) -> SuffixAccumulateAligned<Self, B, F>
+    where
+        Self: Sized + DoubleEndedIterator,
+        B: Clone,
+        F: FnMut(B, Self::Item) -> B,
+    {
+        SuffixAccumulateAligned::new(self, init, f)
+    }
+
+    /// Creates an iterator adaptor that yields both the prefix and the suffix accumulation of
+    /// each element of the base iterator as a `(prefix, suffix)` pair.
+    ///
+    /// The prefix accumulation of an element includes the element itself and all preceding
+    /// elements, like the values yielded by [`accumulate()`](Self::accumulate). The suffix
+    /// accumulation includes the element itself and all following elements, like the values
+    /// yielded by [`suffix_accumulate_aligned()`](Self::suffix_accumulate_aligned). Both start
+    /// from `init`.
+    ///
+    /// The suffix accumulations are computed from a clone of the base iterator and buffered when
+    /// the first element is requested, which is why the base iterator also has to implement
+    /// [`Clone`].
+    ///
+    /// # Examples
+    ///
+    /// Computing how much rain water is trapped between bars of the given heights:
+    ///
+    /// ```
+    /// use iter_accumulate::IterAccumulate;
+    ///
+    /// let heights = [0, 1, 0, 2, 1, 0, 1, 3, 2, 1, 2, 1];
+    /// let water: u32 = heights
+    ///     .iter()
+    ///     .bidirectional_accumulate(0, |acc, &h| acc.max(h))
+    ///     .zip(&heights)
+    ///     .map(|((left, right), h)| left.min(right) - h)
+    ///     .sum();
+    ///
+    /// assert_eq!(water, 6);
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn bidirectional_accumulate<B, F>(self, init: B, f: F) -> BidirectionalAccumulate<Self, B, F>
+    where
+        Self: Sized + DoubleEndedIterator + ExactSizeIterator + Clone,
+        B: Clone,
+        F: FnMut(B, Self::Item) -> B,
+    {
+        BidirectionalAccumulate::new(self, init, f)
     }
 }
 
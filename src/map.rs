@@ -0,0 +1,129 @@
+use crate::Accumulate;
+use core::{
+    fmt,
+    iter::{FusedIterator, Map},
+};
+
+/// An iterator adaptor that maps the elements from the base iterator and accumulates the results
+/// using the provided closures.
+///
+/// This `struct` is created by [`map_accumulate()`]. See its documentation for more information.
+///
+/// [`map_accumulate()`]: crate::IterAccumulate::map_accumulate
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone)]
+pub struct MapAccumulate<I, B, M, F> {
+    inner: Accumulate<Map<I, M>, B, F>,
+}
+
+impl<I, B, M, F> MapAccumulate<I, B, M, F>
+where
+    B: Clone,
+{
+    pub(crate) fn new(iter: Map<I, M>, init: B, f: F) -> Self {
+        Self {
+            inner: Accumulate::new(iter, init, f),
+        }
+    }
+}
+
+impl<I, B, M, F> MapAccumulate<I, B, M, F> {
+    /// Returns a reference to the current accumulated value.
+    #[inline]
+    pub fn acc(&self) -> &B {
+        self.inner.acc()
+    }
+
+    /// Consumes the iterator adaptor and returns the current accumulated value.
+    #[inline]
+    pub fn into_acc(self) -> B {
+        self.inner.into_acc()
+    }
+}
+
+impl<I, B, M, F> fmt::Debug for MapAccumulate<I, B, M, F>
+where
+    I: fmt::Debug,
+    B: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("MapAccumulate")
+            .field("inner", &self.inner)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<I, B, M, F, T> Iterator for MapAccumulate<I, B, M, F>
+where
+    I: Iterator,
+    B: Clone,
+    M: FnMut(I::Item) -> T,
+    F: FnMut(B, T) -> B,
+{
+    type Item = B;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next()
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.inner.nth(n)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        self.inner.count()
+    }
+
+    #[inline]
+    fn last(self) -> Option<Self::Item> {
+        self.inner.last()
+    }
+
+    #[cfg(feature = "try_trait")]
+    #[inline]
+    fn try_fold<Acc, G, R>(&mut self, init: Acc, g: G) -> R
+    where
+        G: FnMut(Acc, Self::Item) -> R,
+        R: core::ops::Try<Output = Acc>,
+    {
+        self.inner.try_fold(init, g)
+    }
+
+    #[inline]
+    fn fold<Acc, G>(self, init: Acc, g: G) -> Acc
+    where
+        G: FnMut(Acc, Self::Item) -> Acc,
+    {
+        self.inner.fold(init, g)
+    }
+}
+
+impl<I, B, M, F, T> ExactSizeIterator for MapAccumulate<I, B, M, F>
+where
+    I: ExactSizeIterator,
+    B: Clone,
+    M: FnMut(I::Item) -> T,
+    F: FnMut(B, T) -> B,
+{
+    #[inline]
+    fn len(&self) -> usize {
+        self.inner.len()
+    }
+}
+
+impl<I, B, M, F, T> FusedIterator for MapAccumulate<I, B, M, F>
+where
+    I: Iterator,
+    B: Clone,
+    M: FnMut(I::Item) -> T,
+    F: FnMut(B, T) -> B,
+{
+}
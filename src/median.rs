@@ -0,0 +1,118 @@
+use alloc::collections::BinaryHeap;
+use core::cmp::{Ordering, Reverse};
+
+use crate::{AccumulateOutput, Float};
+
+/// The state of a running median.
+///
+/// The values are kept in two heaps: a max-heap with the lower half of the values and a min-heap
+/// with the upper half, so the middle values are always at the top of one of the heaps. Adding a
+/// value takes `O(log n)` time.
+///
+/// This is the accumulated state of [`RunningMedian`], see
+/// [`running_median()`](crate::IterAccumulate::running_median).
+#[derive(Clone, Debug)]
+pub struct Median<T> {
+    /// The lower half of the values, which has one more value than `high` if the count is odd.
+    low: BinaryHeap<TotalOrder<T>>,
+    high: BinaryHeap<Reverse<TotalOrder<T>>>,
+}
+
+impl<T: Float> Median<T> {
+    /// Creates the state of a median of no values.
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            low: BinaryHeap::new(),
+            high: BinaryHeap::new(),
+        }
+    }
+
+    /// Adds `x` to the median.
+    ///
+    /// Values are ordered by [`total_cmp()`](f64::total_cmp), so NaNs are sorted to either end
+    /// depending on their sign instead of being ignored.
+    pub fn push(&mut self, x: T) {
+        match self.low.peek() {
+            Some(low) if TotalOrder(x) > *low => self.high.push(Reverse(TotalOrder(x))),
+            _ => self.low.push(TotalOrder(x)),
+        }
+        if self.low.len() > self.high.len() + 1 {
+            let TotalOrder(x) = self.low.pop().unwrap();
+            self.high.push(Reverse(TotalOrder(x)));
+        } else if self.high.len() > self.low.len() {
+            let Reverse(x) = self.high.pop().unwrap();
+            self.low.push(x);
+        }
+    }
+
+    /// Returns the number of values that have been added.
+    #[inline]
+    pub fn count(&self) -> usize {
+        self.low.len() + self.high.len()
+    }
+
+    /// Returns the median of the values that have been added, or NaN if there are none.
+    ///
+    /// If the number of values is even, this is the mean of the two middle values.
+    pub fn median(&self) -> T {
+        match (self.low.peek(), self.high.peek()) {
+            (Some(&TotalOrder(low)), Some(&Reverse(TotalOrder(high))))
+                if self.low.len() == self.high.len() =>
+            {
+                (low + high) / T::from_usize(2)
+            }
+            (Some(&TotalOrder(low)), _) => low,
+            (None, _) => T::NAN,
+        }
+    }
+}
+
+impl<T: Float> Default for Median<T> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A wrapper that orders floating point numbers by [`Float::total_cmp()`].
+#[derive(Clone, Copy, Debug)]
+struct TotalOrder<T>(T);
+
+impl<T: Float> PartialEq for TotalOrder<T> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<T: Float> Eq for TotalOrder<T> {}
+
+impl<T: Float> PartialOrd for TotalOrder<T> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: Float> Ord for TotalOrder<T> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.total_cmp(&other.0)
+    }
+}
+
+/// An iterator adaptor that yields the running median of the floating point numbers from the base
+/// iterator.
+///
+/// This is created by [`running_median()`]. See its documentation for more information.
+///
+/// [`running_median()`]: crate::IterAccumulate::running_median
+pub type RunningMedian<I, T> = AccumulateOutput<I, Median<T>, fn(Median<T>, T) -> (Median<T>, T)>;
+
+/// Adds `x` to `median` and returns it along with the new median.
+pub(crate) fn step<T: Float>(mut median: Median<T>, x: T) -> (Median<T>, T) {
+    median.push(x);
+    let value = median.median();
+    (median, value)
+}
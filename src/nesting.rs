@@ -0,0 +1,120 @@
+use core::{fmt, iter::FusedIterator};
+
+/// Whether an element opens or closes a level of nesting, as decided by the closure passed to
+/// [`nesting_depth()`](crate::IterAccumulate::nesting_depth).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Delimiter {
+    /// The element opens a level of nesting, like `(`.
+    Open,
+    /// The element closes a level of nesting, like `)`.
+    Close,
+}
+
+/// An iterator adaptor that yields the elements from the base iterator, together with the
+/// nesting depth they are at.
+///
+/// This `struct` is created by [`nesting_depth()`]. See its documentation for more information.
+///
+/// [`nesting_depth()`]: crate::IterAccumulate::nesting_depth
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone)]
+pub struct NestingDepth<I, F> {
+    iter: I,
+    depth: isize,
+    /// Whether the depth has ever been negative, i.e. an element closed a level that was never
+    /// opened.
+    underflowed: bool,
+    f: F,
+}
+
+impl<I, F> NestingDepth<I, F> {
+    pub(crate) fn new(iter: I, f: F) -> Self {
+        Self {
+            iter,
+            depth: 0,
+            underflowed: false,
+            f,
+        }
+    }
+
+    /// Returns the current nesting depth, i.e. the number of levels opened but not yet closed.
+    ///
+    /// Once the iterator has finished, this is zero if and only if every opened level has been
+    /// closed.
+    #[inline]
+    pub fn depth(&self) -> isize {
+        self.depth
+    }
+
+    /// Returns whether the nesting depth has been negative so far, i.e. whether an element closed
+    /// a level that was never opened.
+    #[inline]
+    pub fn underflowed(&self) -> bool {
+        self.underflowed
+    }
+
+    /// Returns whether the elements so far are balanced, i.e. whether every level has been closed
+    /// after it has been opened, and never before.
+    #[inline]
+    pub fn is_balanced(&self) -> bool {
+        self.depth == 0 && !self.underflowed
+    }
+}
+
+impl<I, F> fmt::Debug for NestingDepth<I, F>
+where
+    I: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("NestingDepth")
+            .field("iter", &self.iter)
+            .field("depth", &self.depth)
+            .field("underflowed", &self.underflowed)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<I, F> Iterator for NestingDepth<I, F>
+where
+    I: Iterator,
+    F: FnMut(&I::Item) -> Option<Delimiter>,
+{
+    type Item = (I::Item, isize);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.iter.next()?;
+        let depth = match (self.f)(&item) {
+            Some(Delimiter::Open) => {
+                self.depth += 1;
+                self.depth - 1
+            }
+            Some(Delimiter::Close) => {
+                self.depth -= 1;
+                self.underflowed |= self.depth < 0;
+                self.depth
+            }
+            None => self.depth,
+        };
+        Some((item, depth))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I, F> ExactSizeIterator for NestingDepth<I, F>
+where
+    I: ExactSizeIterator,
+    F: FnMut(&I::Item) -> Option<Delimiter>,
+{
+}
+
+impl<I, F> FusedIterator for NestingDepth<I, F>
+where
+    I: FusedIterator,
+    F: FnMut(&I::Item) -> Option<Delimiter>,
+{
+}
@@ -0,0 +1,168 @@
+use crate::POISONED;
+use core::{fmt, iter::FusedIterator};
+
+/// An iterator adaptor that threads an accumulated state through the elements from the base
+/// iterator, and yields a separate output value for each element.
+///
+/// This `struct` is created by [`accumulate_output()`]. See its documentation for more
+/// information.
+///
+/// [`accumulate_output()`]: crate::IterAccumulate::accumulate_output
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone)]
+pub struct AccumulateOutput<I, B, F> {
+    iter: I,
+    /// The accumulated state. This is only [`None`] while it is moved into the closure, or if
+    /// the closure panicked.
+    acc: Option<B>,
+    f: F,
+    /// Whether the base iterator has returned [`None`] yet.
+    done: bool,
+}
+
+impl<I, B, F> AccumulateOutput<I, B, F> {
+    pub(crate) fn new(iter: I, acc: B, f: F) -> Self {
+        Self {
+            iter,
+            acc: Some(acc),
+            f,
+            done: false,
+        }
+    }
+
+    /// Returns a reference to the current accumulated state.
+    #[inline]
+    pub fn acc(&self) -> &B {
+        self.acc.as_ref().expect(POISONED)
+    }
+
+    /// Consumes the iterator adaptor and returns the current accumulated state.
+    #[inline]
+    pub fn into_acc(self) -> B {
+        self.acc.expect(POISONED)
+    }
+}
+
+impl<I, B, F> fmt::Debug for AccumulateOutput<I, B, F>
+where
+    I: fmt::Debug,
+    B: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("AccumulateOutput")
+            .field("iter", &self.iter)
+            .field("acc", &self.acc)
+            .field("done", &self.done)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<I, B, F, O> Iterator for AccumulateOutput<I, B, F>
+where
+    I: Iterator,
+    F: FnMut(B, I::Item) -> (B, O),
+{
+    type Item = O;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        match self.iter.next() {
+            Some(item) => {
+                let (acc, output) = (self.f)(self.acc.take().expect(POISONED), item);
+                self.acc = Some(acc);
+                Some(output)
+            }
+            None => {
+                self.done = true;
+                None
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            (0, Some(0))
+        } else {
+            self.iter.size_hint()
+        }
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        if self.done {
+            0
+        } else {
+            self.iter.count()
+        }
+    }
+
+    #[cfg(feature = "try_trait")]
+    #[inline]
+    fn try_fold<Acc, G, R>(&mut self, init: Acc, mut g: G) -> R
+    where
+        G: FnMut(Acc, Self::Item) -> R,
+        R: core::ops::Try<Output = Acc>,
+    {
+        if self.done {
+            return R::from_output(init);
+        }
+        let f = &mut self.f;
+        let state = &mut self.acc;
+        let result = self.iter.try_fold(init, |acc, item| {
+            let (next, output) = f(state.take().expect(POISONED), item);
+            *state = Some(next);
+            g(acc, output)
+        });
+        match result.branch() {
+            core::ops::ControlFlow::Continue(acc) => {
+                self.done = true;
+                R::from_output(acc)
+            }
+            core::ops::ControlFlow::Break(residual) => R::from_residual(residual),
+        }
+    }
+
+    #[inline]
+    fn fold<Acc, G>(self, init: Acc, mut g: G) -> Acc
+    where
+        G: FnMut(Acc, Self::Item) -> Acc,
+    {
+        if self.done {
+            return init;
+        }
+        let mut f = self.f;
+        let state = self.acc.expect(POISONED);
+        self.iter
+            .fold((init, state), move |(acc, state), item| {
+                let (state, output) = f(state, item);
+                (g(acc, output), state)
+            })
+            .0
+    }
+}
+
+impl<I, B, F, O> ExactSizeIterator for AccumulateOutput<I, B, F>
+where
+    I: ExactSizeIterator,
+    F: FnMut(B, I::Item) -> (B, O),
+{
+    #[inline]
+    fn len(&self) -> usize {
+        if self.done {
+            0
+        } else {
+            self.iter.len()
+        }
+    }
+}
+
+impl<I, B, F, O> FusedIterator for AccumulateOutput<I, B, F>
+where
+    I: Iterator,
+    F: FnMut(B, I::Item) -> (B, O),
+{
+}
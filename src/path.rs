@@ -0,0 +1,96 @@
+use crate::Float;
+use core::{fmt, iter::FusedIterator};
+
+/// An iterator adaptor that yields the running length of the path through the points from the
+/// base iterator.
+///
+/// This `struct` is created by [`arc_length()`]. See its documentation for more information.
+///
+/// [`arc_length()`]: crate::IterAccumulate::arc_length
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone)]
+pub struct ArcLength<I: Iterator, D, F> {
+    iter: I,
+    /// The last point, or [`None`] before the first point.
+    last: Option<I::Item>,
+    length: D,
+    distance: F,
+}
+
+impl<I: Iterator, D: Float, F> ArcLength<I, D, F> {
+    pub(crate) fn new(iter: I, distance: F) -> Self {
+        Self {
+            iter,
+            last: None,
+            length: D::ZERO,
+            distance,
+        }
+    }
+
+    /// Returns the length of the path through the points so far.
+    #[inline]
+    pub fn length(&self) -> D {
+        self.length
+    }
+
+    /// Returns a reference to the last point, or [`None`] before the first point.
+    #[inline]
+    pub fn last_point(&self) -> Option<&I::Item> {
+        self.last.as_ref()
+    }
+}
+
+impl<I, D, F> fmt::Debug for ArcLength<I, D, F>
+where
+    I: Iterator + fmt::Debug,
+    I::Item: fmt::Debug,
+    D: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("ArcLength")
+            .field("iter", &self.iter)
+            .field("last", &self.last)
+            .field("length", &self.length)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<I, D, F> Iterator for ArcLength<I, D, F>
+where
+    I: Iterator,
+    D: Float,
+    F: FnMut(&I::Item, &I::Item) -> D,
+{
+    type Item = D;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let point = self.iter.next()?;
+        if let Some(last) = &self.last {
+            self.length = self.length + (self.distance)(last, &point);
+        }
+        self.last = Some(point);
+        Some(self.length)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I, D, F> ExactSizeIterator for ArcLength<I, D, F>
+where
+    I: ExactSizeIterator,
+    D: Float,
+    F: FnMut(&I::Item, &I::Item) -> D,
+{
+}
+
+impl<I, D, F> FusedIterator for ArcLength<I, D, F>
+where
+    I: FusedIterator,
+    D: Float,
+    F: FnMut(&I::Item, &I::Item) -> D,
+{
+}
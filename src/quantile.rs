@@ -0,0 +1,147 @@
+use crate::{AccumulateOutput, Float};
+
+/// The state of a running quantile estimate using the P² algorithm by Jain and Chlamtac.
+///
+/// Instead of storing the values, the algorithm keeps five markers: the minimum, the maximum, the
+/// estimated `p`-quantile and two estimated quantiles halfway between it and the extremes. For
+/// every value, the markers are moved towards their desired positions using piecewise-parabolic
+/// interpolation. This takes constant time and memory, but is only an approximation once more
+/// than five values have been added.
+///
+/// This is the accumulated state of [`RunningQuantile`], see
+/// [`running_quantile()`](crate::IterAccumulate::running_quantile).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Quantile<T> {
+    p: T,
+    count: usize,
+    /// The heights of the markers, or the sorted values themselves while there are at most five.
+    heights: [T; 5],
+    /// The actual positions of the markers, counting from zero.
+    positions: [T; 5],
+    /// The desired positions of the markers.
+    desired: [T; 5],
+    /// How much the desired positions of the markers increase with every value.
+    increments: [T; 5],
+}
+
+impl<T: Float> Quantile<T> {
+    /// Creates the state of an estimate of the `p`-quantile of no values.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `p` is not in the range `[0, 1]`.
+    pub fn new(p: T) -> Self {
+        assert!(
+            p >= T::ZERO && p <= T::ONE,
+            "`p` must be in the range [0, 1]"
+        );
+        let two = T::from_usize(2);
+        let four = T::from_usize(4);
+        Self {
+            p,
+            count: 0,
+            heights: [T::ZERO; 5],
+            positions: [0, 1, 2, 3, 4].map(T::from_usize),
+            desired: [T::ZERO, two * p, four * p, two + two * p, four],
+            increments: [T::ZERO, p / two, p, (T::ONE + p) / two, T::ONE],
+        }
+    }
+
+    /// Adds `x` to the estimate.
+    pub fn push(&mut self, x: T) {
+        if self.count < 5 {
+            // Insertion sort into the first values.
+            let mut i = self.count;
+            while i > 0 && self.heights[i - 1] > x {
+                self.heights[i] = self.heights[i - 1];
+                i -= 1;
+            }
+            self.heights[i] = x;
+            self.count += 1;
+            return;
+        }
+        self.count += 1;
+
+        let q = &mut self.heights;
+        let k = if x < q[0] {
+            q[0] = x;
+            0
+        } else if x >= q[4] {
+            q[4] = x;
+            3
+        } else {
+            (0..4).rfind(|&i| q[i] <= x).unwrap_or(0)
+        };
+        for position in &mut self.positions[k + 1..] {
+            *position = *position + T::ONE;
+        }
+        for (desired, &increment) in self.desired.iter_mut().zip(&self.increments) {
+            *desired = *desired + increment;
+        }
+
+        for i in 1..4 {
+            let n = &mut self.positions;
+            let d = self.desired[i] - n[i];
+            let step = if d >= T::ONE && n[i + 1] - n[i] > T::ONE {
+                T::ONE
+            } else if d <= -T::ONE && n[i - 1] - n[i] < -T::ONE {
+                -T::ONE
+            } else {
+                continue;
+            };
+            let parabolic = q[i]
+                + step / (n[i + 1] - n[i - 1])
+                    * ((n[i] - n[i - 1] + step) * (q[i + 1] - q[i]) / (n[i + 1] - n[i])
+                        + (n[i + 1] - n[i] - step) * (q[i] - q[i - 1]) / (n[i] - n[i - 1]));
+            q[i] = if q[i - 1] < parabolic && parabolic < q[i + 1] {
+                parabolic
+            } else {
+                // Fall back to linear interpolation towards the neighbor in the direction of
+                // the step if the parabola would move the marker past one of its neighbors.
+                let j = if step > T::ZERO { i + 1 } else { i - 1 };
+                q[i] + step * (q[j] - q[i]) / (n[j] - n[i])
+            };
+            n[i] = n[i] + step;
+        }
+    }
+
+    /// Returns the number of values that have been added.
+    #[inline]
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Returns the estimated `p`-quantile of the values that have been added, or NaN if there
+    /// are none.
+    ///
+    /// While there are at most five values, this is exact: the smallest value that is greater
+    /// than or equal to a `p` fraction of the other values.
+    pub fn quantile(&self) -> T {
+        match self.count {
+            0 => T::NAN,
+            count @ 1..=5 => {
+                let rank = self.p * T::from_usize(count - 1);
+                let i = (0..count)
+                    .find(|&i| T::from_usize(i) >= rank)
+                    .unwrap_or(count - 1);
+                self.heights[i]
+            }
+            _ => self.heights[2],
+        }
+    }
+}
+
+/// An iterator adaptor that yields a running estimate of a quantile of the floating point numbers
+/// from the base iterator.
+///
+/// This is created by [`running_quantile()`]. See its documentation for more information.
+///
+/// [`running_quantile()`]: crate::IterAccumulate::running_quantile
+pub type RunningQuantile<I, T> =
+    AccumulateOutput<I, Quantile<T>, fn(Quantile<T>, T) -> (Quantile<T>, T)>;
+
+/// Updates a quantile estimate and yields its value.
+pub(crate) fn step<T: Float>(mut quantile: Quantile<T>, x: T) -> (Quantile<T>, T) {
+    quantile.push(x);
+    (quantile, quantile.quantile())
+}
@@ -0,0 +1,129 @@
+use core::{fmt, iter::FusedIterator};
+
+/// An iterator adaptor that accumulates the elements from the base iterator using the provided
+/// closure, which is passed the last `N` accumulated values.
+///
+/// This `struct` is created by [`accumulate_recurrence()`]. See its documentation for more
+/// information.
+///
+/// [`accumulate_recurrence()`]: crate::IterAccumulate::accumulate_recurrence
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone)]
+pub struct AccumulateRecurrence<I, B, F, const N: usize> {
+    iter: I,
+    /// The last `N` accumulated values, from oldest to newest.
+    states: [B; N],
+    f: F,
+    /// Whether the base iterator has returned [`None`] yet.
+    done: bool,
+}
+
+impl<I, B, F, const N: usize> AccumulateRecurrence<I, B, F, N> {
+    pub(crate) fn new(iter: I, init: [B; N], f: F) -> Self {
+        assert!(N != 0, "`N` must not be zero");
+        Self {
+            iter,
+            states: init,
+            f,
+            done: false,
+        }
+    }
+
+    /// Returns a reference to the last `N` accumulated values, from oldest to newest.
+    #[inline]
+    pub fn states(&self) -> &[B; N] {
+        &self.states
+    }
+
+    /// Consumes the iterator adaptor and returns the last `N` accumulated values, from oldest to
+    /// newest.
+    #[inline]
+    pub fn into_states(self) -> [B; N] {
+        self.states
+    }
+}
+
+impl<I, B, F, const N: usize> fmt::Debug for AccumulateRecurrence<I, B, F, N>
+where
+    I: fmt::Debug,
+    B: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("AccumulateRecurrence")
+            .field("iter", &self.iter)
+            .field("states", &self.states)
+            .field("done", &self.done)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<I, B, F, const N: usize> Iterator for AccumulateRecurrence<I, B, F, N>
+where
+    I: Iterator,
+    B: Clone,
+    F: FnMut(&[B; N], I::Item) -> B,
+{
+    type Item = B;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        match self.iter.next() {
+            Some(item) => {
+                let next = (self.f)(&self.states, item);
+                // Drop the oldest value and append the new one
+                self.states.rotate_left(1);
+                self.states[N - 1] = next.clone();
+                Some(next)
+            }
+            None => {
+                self.done = true;
+                None
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            (0, Some(0))
+        } else {
+            self.iter.size_hint()
+        }
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        if self.done {
+            0
+        } else {
+            self.iter.count()
+        }
+    }
+}
+
+impl<I, B, F, const N: usize> ExactSizeIterator for AccumulateRecurrence<I, B, F, N>
+where
+    I: ExactSizeIterator,
+    B: Clone,
+    F: FnMut(&[B; N], I::Item) -> B,
+{
+    #[inline]
+    fn len(&self) -> usize {
+        if self.done {
+            0
+        } else {
+            self.iter.len()
+        }
+    }
+}
+
+impl<I, B, F, const N: usize> FusedIterator for AccumulateRecurrence<I, B, F, N>
+where
+    I: Iterator,
+    B: Clone,
+    F: FnMut(&[B; N], I::Item) -> B,
+{
+}
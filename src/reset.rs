@@ -0,0 +1,338 @@
+use crate::POISONED;
+use core::{fmt, iter::FusedIterator};
+
+/// An iterator adaptor that accumulates the elements from the base iterator using the provided
+/// closure, and resets the accumulated value to the initial value after every `n` elements.
+///
+/// This `struct` is created by [`accumulate_tumbling()`]. See its documentation for more
+/// information.
+///
+/// [`accumulate_tumbling()`]: crate::IterAccumulate::accumulate_tumbling
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone)]
+pub struct AccumulateTumbling<I, B, F> {
+    iter: I,
+    init: B,
+    /// The accumulated value of the current block. This is only [`None`] before the first
+    /// element, while it is moved into the closure, or if the closure panicked.
+    acc: Option<B>,
+    f: F,
+    n: usize,
+    /// The number of elements in the current block so far.
+    pos: usize,
+}
+
+impl<I, B, F> AccumulateTumbling<I, B, F> {
+    pub(crate) fn new(iter: I, n: usize, init: B, f: F) -> Self {
+        assert!(n != 0, "`n` must not be zero");
+        Self {
+            iter,
+            init,
+            acc: None,
+            f,
+            n,
+            pos: 0,
+        }
+    }
+}
+
+impl<I, B, F> fmt::Debug for AccumulateTumbling<I, B, F>
+where
+    I: fmt::Debug,
+    B: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("AccumulateTumbling")
+            .field("iter", &self.iter)
+            .field("init", &self.init)
+            .field("acc", &self.acc)
+            .field("n", &self.n)
+            .field("pos", &self.pos)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<I, B, F> Iterator for AccumulateTumbling<I, B, F>
+where
+    I: Iterator,
+    B: Clone,
+    F: FnMut(B, I::Item) -> B,
+{
+    type Item = B;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.iter.next()?;
+        let acc = if self.pos == 0 {
+            self.init.clone()
+        } else {
+            self.acc.take().expect(POISONED)
+        };
+        self.pos = (self.pos + 1) % self.n;
+        Some(self.acc.insert((self.f)(acc, item)).clone())
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        self.iter.count()
+    }
+}
+
+impl<I, B, F> ExactSizeIterator for AccumulateTumbling<I, B, F>
+where
+    I: ExactSizeIterator,
+    B: Clone,
+    F: FnMut(B, I::Item) -> B,
+{
+    #[inline]
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+
+impl<I, B, F> FusedIterator for AccumulateTumbling<I, B, F>
+where
+    I: FusedIterator,
+    B: Clone,
+    F: FnMut(B, I::Item) -> B,
+{
+}
+
+/// Whether [`accumulate_reset_when()`](crate::IterAccumulate::accumulate_reset_when) resets the
+/// accumulated value before or after the element for which the predicate returned `true`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ResetPosition {
+    /// Reset before accumulating the element, so that it starts a new run. The predicate is called
+    /// with the value accumulated before the element.
+    Before,
+    /// Reset after accumulating and yielding the element, so that it ends the current run. The
+    /// predicate is called with the value accumulated including the element.
+    After,
+}
+
+/// An iterator adaptor that accumulates the elements from the base iterator using the provided
+/// closure, and resets the accumulated value to the initial value whenever a predicate is
+/// satisfied.
+///
+/// This `struct` is created by [`accumulate_reset_when()`]. See its documentation for more
+/// information.
+///
+/// [`accumulate_reset_when()`]: crate::IterAccumulate::accumulate_reset_when
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone)]
+pub struct AccumulateResetWhen<I, B, F, P> {
+    iter: I,
+    init: B,
+    /// The accumulated value. This is only [`None`] if the next element starts a new run, while
+    /// it is moved into the closure, or if the closure panicked.
+    acc: Option<B>,
+    f: F,
+    pred: P,
+    position: ResetPosition,
+}
+
+impl<I, B, F, P> AccumulateResetWhen<I, B, F, P> {
+    pub(crate) fn new(iter: I, init: B, f: F, pred: P, position: ResetPosition) -> Self {
+        Self {
+            iter,
+            init,
+            acc: None,
+            f,
+            pred,
+            position,
+        }
+    }
+}
+
+impl<I, B, F, P> fmt::Debug for AccumulateResetWhen<I, B, F, P>
+where
+    I: fmt::Debug,
+    B: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("AccumulateResetWhen")
+            .field("iter", &self.iter)
+            .field("init", &self.init)
+            .field("acc", &self.acc)
+            .field("position", &self.position)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<I, B, F, P> Iterator for AccumulateResetWhen<I, B, F, P>
+where
+    I: Iterator,
+    I::Item: Clone,
+    B: Clone,
+    F: FnMut(B, I::Item) -> B,
+    P: FnMut(&I::Item, &B) -> bool,
+{
+    type Item = B;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.iter.next()?;
+        let acc = self.acc.take().unwrap_or_else(|| self.init.clone());
+        match self.position {
+            ResetPosition::Before => {
+                let acc = if (self.pred)(&item, &acc) {
+                    self.init.clone()
+                } else {
+                    acc
+                };
+                let acc = (self.f)(acc, item);
+                Some(self.acc.insert(acc).clone())
+            }
+            ResetPosition::After => {
+                let acc = (self.f)(acc, item.clone());
+                if !(self.pred)(&item, &acc) {
+                    self.acc = Some(acc.clone());
+                }
+                Some(acc)
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        self.iter.count()
+    }
+}
+
+impl<I, B, F, P> ExactSizeIterator for AccumulateResetWhen<I, B, F, P>
+where
+    I: ExactSizeIterator,
+    I::Item: Clone,
+    B: Clone,
+    F: FnMut(B, I::Item) -> B,
+    P: FnMut(&I::Item, &B) -> bool,
+{
+    #[inline]
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+
+impl<I, B, F, P> FusedIterator for AccumulateResetWhen<I, B, F, P>
+where
+    I: FusedIterator,
+    I::Item: Clone,
+    B: Clone,
+    F: FnMut(B, I::Item) -> B,
+    P: FnMut(&I::Item, &B) -> bool,
+{
+}
+
+/// An iterator adaptor that accumulates runs of consecutive elements from the base iterator with
+/// the same key, and yields every key along with the accumulated value of its run so far.
+///
+/// This `struct` is created by [`accumulate_runs_by_key()`]. See its documentation for more
+/// information.
+///
+/// [`accumulate_runs_by_key()`]: crate::IterAccumulate::accumulate_runs_by_key
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone)]
+pub struct AccumulateRunsByKey<I, K, B, G, F> {
+    iter: I,
+    key: G,
+    init: B,
+    /// The key of the current run and its accumulated value. This is only [`None`] before the
+    /// first element, while it is moved into the closure, or if the closure panicked.
+    run: Option<(K, B)>,
+    f: F,
+}
+
+impl<I, K, B, G, F> AccumulateRunsByKey<I, K, B, G, F> {
+    pub(crate) fn new(iter: I, key: G, init: B, f: F) -> Self {
+        Self {
+            iter,
+            key,
+            init,
+            run: None,
+            f,
+        }
+    }
+}
+
+impl<I, K, B, G, F> fmt::Debug for AccumulateRunsByKey<I, K, B, G, F>
+where
+    I: fmt::Debug,
+    K: fmt::Debug,
+    B: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("AccumulateRunsByKey")
+            .field("iter", &self.iter)
+            .field("init", &self.init)
+            .field("run", &self.run)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<I, K, B, G, F> Iterator for AccumulateRunsByKey<I, K, B, G, F>
+where
+    I: Iterator,
+    K: PartialEq + Clone,
+    B: Clone,
+    G: FnMut(&I::Item) -> K,
+    F: FnMut(B, I::Item) -> B,
+{
+    type Item = (K, B);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.iter.next()?;
+        let key = (self.key)(&item);
+        let acc = match self.run.take() {
+            Some((run_key, acc)) if run_key == key => acc,
+            _ => self.init.clone(),
+        };
+        let acc = (self.f)(acc, item);
+        self.run = Some((key.clone(), acc.clone()));
+        Some((key, acc))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        self.iter.count()
+    }
+}
+
+impl<I, K, B, G, F> ExactSizeIterator for AccumulateRunsByKey<I, K, B, G, F>
+where
+    I: ExactSizeIterator,
+    K: PartialEq + Clone,
+    B: Clone,
+    G: FnMut(&I::Item) -> K,
+    F: FnMut(B, I::Item) -> B,
+{
+    #[inline]
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+
+impl<I, K, B, G, F> FusedIterator for AccumulateRunsByKey<I, K, B, G, F>
+where
+    I: FusedIterator,
+    K: PartialEq + Clone,
+    B: Clone,
+    G: FnMut(&I::Item) -> K,
+    F: FnMut(B, I::Item) -> B,
+{
+}
@@ -0,0 +1,506 @@
+use crate::POISONED;
+#[cfg(feature = "alloc")]
+use crate::{Float, Mean};
+#[cfg(feature = "std")]
+use crate::{Variance, VarianceKind};
+#[cfg(feature = "alloc")]
+use alloc::collections::VecDeque;
+#[cfg(feature = "alloc")]
+use core::iter;
+use core::{array, fmt, iter::FusedIterator};
+
+/// What a rolling adaptor yields for the first elements, before its window is full.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum WarmUp {
+    /// Yield a value for every element, accumulated from the elements so far while there are
+    /// fewer than the window size, like a prefix accumulation.
+    Partial,
+    /// Only yield a value for full windows, like [`slice::windows()`]. If there are fewer
+    /// elements than the window size, nothing is yielded.
+    Skip,
+}
+
+impl WarmUp {
+    /// Returns how many more elements are read before a value is yielded, given the number of
+    /// elements in the window so far.
+    #[inline]
+    fn pending(self, len: usize, size: usize) -> usize {
+        match self {
+            WarmUp::Partial => 0,
+            WarmUp::Skip => (size - len).saturating_sub(1),
+        }
+    }
+}
+
+/// The size `N` of the window of [`rolling()`], which is part of the type of its adaptor.
+///
+/// This is passed as `Window::<N>`, so that the size is the only parameter that has to be
+/// spelled out.
+///
+/// [`rolling()`]: crate::IterAccumulate::rolling
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Window<const N: usize>;
+
+/// An iterator adaptor that accumulates the elements in a window over the last elements of the
+/// base iterator, by adding elements as they enter the window and removing them as they leave
+/// it.
+///
+/// This `struct` is created by [`rolling_fold()`] and [`rolling_sum()`]. See their documentation
+/// for more information.
+///
+/// [`rolling_fold()`]: crate::IterAccumulate::rolling_fold
+/// [`rolling_sum()`]: crate::IterAccumulate::rolling_sum
+#[cfg(feature = "alloc")]
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone)]
+pub struct RollingFold<I: Iterator, B, F, G> {
+    iter: I,
+    /// The elements in the window, from oldest to newest.
+    window: VecDeque<I::Item>,
+    size: usize,
+    /// The accumulated value of the elements in the window. This is only [`None`] while it is
+    /// moved into a closure, or if a closure panicked.
+    acc: Option<B>,
+    add: F,
+    remove: G,
+    warm_up: WarmUp,
+}
+
+#[cfg(feature = "alloc")]
+impl<I: Iterator, B, F, G> RollingFold<I, B, F, G> {
+    pub(crate) fn new(iter: I, size: usize, warm_up: WarmUp, init: B, add: F, remove: G) -> Self {
+        assert!(size != 0, "window size must not be zero");
+        Self {
+            iter,
+            window: VecDeque::with_capacity(size),
+            size,
+            acc: Some(init),
+            add,
+            remove,
+            warm_up,
+        }
+    }
+
+    /// Returns a reference to the accumulated value of the elements in the window.
+    #[inline]
+    pub fn acc(&self) -> &B {
+        self.acc.as_ref().expect(POISONED)
+    }
+
+    /// Returns an iterator over the elements in the window, from oldest to newest.
+    #[inline]
+    pub fn window(&self) -> impl Iterator<Item = &I::Item> {
+        self.window.iter()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<I, B, F, G> fmt::Debug for RollingFold<I, B, F, G>
+where
+    I: Iterator + fmt::Debug,
+    I::Item: fmt::Debug,
+    B: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("RollingFold")
+            .field("iter", &self.iter)
+            .field("window", &self.window)
+            .field("size", &self.size)
+            .field("acc", &self.acc)
+            .field("warm_up", &self.warm_up)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<I, B, F, G> Iterator for RollingFold<I, B, F, G>
+where
+    I: Iterator,
+    I::Item: Clone,
+    B: Clone,
+    F: FnMut(B, I::Item) -> B,
+    G: FnMut(B, I::Item) -> B,
+{
+    type Item = B;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let item = self.iter.next()?;
+            let mut acc = self.acc.take().expect(POISONED);
+            if self.window.len() == self.size {
+                let oldest = self.window.pop_front().unwrap();
+                acc = (self.remove)(acc, oldest);
+            }
+            self.window.push_back(item.clone());
+            let acc = self.acc.insert((self.add)(acc, item));
+            if self.warm_up == WarmUp::Partial || self.window.len() == self.size {
+                return Some(acc.clone());
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let pending = self.warm_up.pending(self.window.len(), self.size);
+        let (lower, upper) = self.iter.size_hint();
+        (
+            lower.saturating_sub(pending),
+            upper.map(|upper| upper.saturating_sub(pending)),
+        )
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<I, B, F, G> ExactSizeIterator for RollingFold<I, B, F, G>
+where
+    I: ExactSizeIterator,
+    I::Item: Clone,
+    B: Clone,
+    F: FnMut(B, I::Item) -> B,
+    G: FnMut(B, I::Item) -> B,
+{
+}
+
+#[cfg(feature = "alloc")]
+impl<I, B, F, G> FusedIterator for RollingFold<I, B, F, G>
+where
+    I: FusedIterator,
+    I::Item: Clone,
+    B: Clone,
+    F: FnMut(B, I::Item) -> B,
+    G: FnMut(B, I::Item) -> B,
+{
+}
+
+/// An iterator adaptor that yields the sums of a window over the last elements of the base
+/// iterator.
+///
+/// This is created by [`rolling_sum()`]. See its documentation for more information.
+///
+/// [`rolling_sum()`]: crate::IterAccumulate::rolling_sum
+#[cfg(feature = "alloc")]
+pub type RollingSum<I, B> = RollingFold<I, B, RollingStep<I, B>, RollingStep<I, B>>;
+
+/// The type of the closures of [`RollingSum`].
+#[cfg(feature = "alloc")]
+type RollingStep<I, B> = fn(B, <I as Iterator>::Item) -> B;
+
+/// An iterator adaptor that yields the maximum or minimum of a window over the last elements of
+/// the base iterator.
+///
+/// This `struct` is created by [`rolling_max()`] and [`rolling_min()`]. See their documentation
+/// for more information.
+///
+/// [`rolling_max()`]: crate::IterAccumulate::rolling_max
+/// [`rolling_min()`]: crate::IterAccumulate::rolling_min
+#[cfg(feature = "alloc")]
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone)]
+pub struct RollingExtremum<I: Iterator> {
+    iter: I,
+    /// The elements in the window that are not dominated by a later element, with their indices.
+    /// Their values are monotonic, so the extremum is always at the front.
+    candidates: VecDeque<(usize, I::Item)>,
+    /// The index of the next element.
+    index: usize,
+    size: usize,
+    max: bool,
+    warm_up: WarmUp,
+}
+
+#[cfg(feature = "alloc")]
+impl<I: Iterator> RollingExtremum<I> {
+    pub(crate) fn new(iter: I, size: usize, warm_up: WarmUp, max: bool) -> Self {
+        assert!(size != 0, "window size must not be zero");
+        Self {
+            iter,
+            candidates: VecDeque::new(),
+            index: 0,
+            size,
+            max,
+            warm_up,
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<I> fmt::Debug for RollingExtremum<I>
+where
+    I: Iterator + fmt::Debug,
+    I::Item: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("RollingExtremum")
+            .field("iter", &self.iter)
+            .field("candidates", &self.candidates)
+            .field("index", &self.index)
+            .field("size", &self.size)
+            .field("max", &self.max)
+            .field("warm_up", &self.warm_up)
+            .finish()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<I> Iterator for RollingExtremum<I>
+where
+    I: Iterator,
+    I::Item: Ord + Clone,
+{
+    type Item = I::Item;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let item = self.iter.next()?;
+            // Every element is pushed and popped at most once, so this takes amortized constant
+            // time per element.
+            while let Some((_, last)) = self.candidates.back() {
+                let dominated = if self.max {
+                    *last <= item
+                } else {
+                    *last >= item
+                };
+                if !dominated {
+                    break;
+                }
+                self.candidates.pop_back();
+            }
+            self.candidates.push_back((self.index, item));
+            self.index += 1;
+            if let Some(&(first, _)) = self.candidates.front() {
+                if first + self.size < self.index {
+                    self.candidates.pop_front();
+                }
+            }
+            if self.warm_up == WarmUp::Partial || self.index >= self.size {
+                return self.candidates.front().map(|(_, x)| x.clone());
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let pending = self.warm_up.pending(self.index.min(self.size), self.size);
+        let (lower, upper) = self.iter.size_hint();
+        (
+            lower.saturating_sub(pending),
+            upper.map(|upper| upper.saturating_sub(pending)),
+        )
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<I> ExactSizeIterator for RollingExtremum<I>
+where
+    I: ExactSizeIterator,
+    I::Item: Ord + Clone,
+{
+}
+
+#[cfg(feature = "alloc")]
+impl<I> FusedIterator for RollingExtremum<I>
+where
+    I: FusedIterator,
+    I::Item: Ord + Clone,
+{
+}
+
+/// An iterator adaptor that yields the arithmetic means of a window over the last floating point
+/// numbers from the base iterator.
+///
+/// This is created by [`rolling_mean()`]. See its documentation for more information.
+///
+/// [`rolling_mean()`]: crate::IterAccumulate::rolling_mean
+#[cfg(feature = "alloc")]
+pub type RollingMean<I, T> =
+    iter::Map<RollingFold<I, Mean<T>, MeanStep<T>, MeanStep<T>>, fn(Mean<T>) -> T>;
+
+/// An iterator adaptor that yields the standard deviations of a window over the last floating
+/// point numbers from the base iterator.
+///
+/// This is created by [`rolling_stddev()`]. See its documentation for more information.
+///
+/// [`rolling_stddev()`]: crate::IterAccumulate::rolling_stddev
+#[cfg(feature = "std")]
+pub type RollingStddev<I, T> =
+    iter::Map<RollingFold<I, Variance<T>, VarianceStep<T>, VarianceStep<T>>, fn(Variance<T>) -> T>;
+
+#[cfg(feature = "alloc")]
+type MeanStep<T> = fn(Mean<T>, T) -> Mean<T>;
+
+#[cfg(feature = "std")]
+type VarianceStep<T> = fn(Variance<T>, T) -> Variance<T>;
+
+/// Returns the means of the elements in a window, updated as elements enter and leave it.
+#[cfg(feature = "alloc")]
+pub(crate) fn rolling_mean<I>(iter: I, size: usize, warm_up: WarmUp) -> RollingMean<I, I::Item>
+where
+    I: Iterator,
+    I::Item: Float,
+{
+    let add: MeanStep<I::Item> = |mut mean, x| {
+        mean.push(x);
+        mean
+    };
+    let remove: MeanStep<I::Item> = |mut mean, x| {
+        mean.remove(x);
+        mean
+    };
+    RollingFold::new(iter, size, warm_up, Mean::new(), add, remove).map(|mean| mean.mean())
+}
+
+/// Returns the standard deviations of the elements in a window, updated as elements enter and
+/// leave it.
+#[cfg(feature = "std")]
+pub(crate) fn rolling_stddev<I>(
+    iter: I,
+    size: usize,
+    warm_up: WarmUp,
+    kind: VarianceKind,
+) -> RollingStddev<I, I::Item>
+where
+    I: Iterator,
+    I::Item: Float,
+{
+    let add: VarianceStep<I::Item> = |mut variance, x| {
+        variance.push(x);
+        variance
+    };
+    let remove: VarianceStep<I::Item> = |mut variance, x| {
+        variance.remove(x);
+        variance
+    };
+    RollingFold::new(iter, size, warm_up, Variance::new(), add, remove).map(kind.stddev_of())
+}
+
+/// An iterator adaptor that accumulates the elements in a window of `N` elements over the last
+/// elements of the base iterator, by adding elements as they enter the window and removing them
+/// as they leave it, without allocating.
+///
+/// This `struct` is created by [`rolling()`]. See its documentation for more information.
+///
+/// [`rolling()`]: crate::IterAccumulate::rolling
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone)]
+pub struct RollingArray<I: Iterator, B, F, G, const N: usize> {
+    iter: I,
+    /// A ring buffer of the elements in the window.
+    window: [Option<I::Item>; N],
+    /// The index in `window` of the oldest element if the window is full, or of the next free
+    /// slot otherwise.
+    head: usize,
+    len: usize,
+    /// The accumulated value of the elements in the window. This is only [`None`] while it is
+    /// moved into a closure, or if a closure panicked.
+    acc: Option<B>,
+    add: F,
+    remove: G,
+    warm_up: WarmUp,
+}
+
+impl<I: Iterator, B, F, G, const N: usize> RollingArray<I, B, F, G, N> {
+    pub(crate) fn new(iter: I, warm_up: WarmUp, init: B, add: F, remove: G) -> Self {
+        assert!(N != 0, "`N` must not be zero");
+        Self {
+            iter,
+            window: array::from_fn(|_| None),
+            head: 0,
+            len: 0,
+            acc: Some(init),
+            add,
+            remove,
+            warm_up,
+        }
+    }
+
+    /// Returns a reference to the accumulated value of the elements in the window.
+    #[inline]
+    pub fn acc(&self) -> &B {
+        self.acc.as_ref().expect(POISONED)
+    }
+
+    /// Returns an iterator over the elements in the window, from oldest to newest.
+    #[inline]
+    pub fn window(&self) -> impl Iterator<Item = &I::Item> {
+        let (newer, older) = self.window.split_at(self.head);
+        older.iter().chain(newer).flatten()
+    }
+}
+
+impl<I, B, F, G, const N: usize> fmt::Debug for RollingArray<I, B, F, G, N>
+where
+    I: Iterator + fmt::Debug,
+    I::Item: fmt::Debug,
+    B: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("RollingArray")
+            .field("iter", &self.iter)
+            .field("window", &self.window)
+            .field("head", &self.head)
+            .field("acc", &self.acc)
+            .field("warm_up", &self.warm_up)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<I, B, F, G, const N: usize> Iterator for RollingArray<I, B, F, G, N>
+where
+    I: Iterator,
+    I::Item: Clone,
+    B: Clone,
+    F: FnMut(B, I::Item) -> B,
+    G: FnMut(B, I::Item) -> B,
+{
+    type Item = B;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let item = self.iter.next()?;
+            let mut acc = self.acc.take().expect(POISONED);
+            if let Some(oldest) = self.window[self.head].replace(item.clone()) {
+                acc = (self.remove)(acc, oldest);
+            } else {
+                self.len += 1;
+            }
+            self.head = (self.head + 1) % N;
+            let acc = self.acc.insert((self.add)(acc, item));
+            if self.warm_up == WarmUp::Partial || self.len == N {
+                return Some(acc.clone());
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let pending = self.warm_up.pending(self.len, N);
+        let (lower, upper) = self.iter.size_hint();
+        (
+            lower.saturating_sub(pending),
+            upper.map(|upper| upper.saturating_sub(pending)),
+        )
+    }
+}
+
+impl<I, B, F, G, const N: usize> ExactSizeIterator for RollingArray<I, B, F, G, N>
+where
+    I: ExactSizeIterator,
+    I::Item: Clone,
+    B: Clone,
+    F: FnMut(B, I::Item) -> B,
+    G: FnMut(B, I::Item) -> B,
+{
+}
+
+impl<I, B, F, G, const N: usize> FusedIterator for RollingArray<I, B, F, G, N>
+where
+    I: FusedIterator,
+    I::Item: Clone,
+    B: Clone,
+    F: FnMut(B, I::Item) -> B,
+    G: FnMut(B, I::Item) -> B,
+{
+}
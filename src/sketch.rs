@@ -0,0 +1,275 @@
+use alloc::{vec, vec::Vec};
+use core::hash::{BuildHasher, Hash};
+
+use crate::AccumulateOutput;
+
+/// A HyperLogLog sketch, which estimates the number of distinct values added to it.
+///
+/// Every value is hashed, the first `precision` bits of the hash select one of
+/// `2^precision` registers, and that register remembers the longest run of leading zeros seen in
+/// the remaining bits. The sketch uses one byte per register regardless of the number of values,
+/// and the standard error of the estimate is about `1.04 / sqrt(2^precision)`, e.g. 1.6% for a
+/// precision of 12.
+///
+/// This is the accumulated state of [`RunningDistinctCount`], see
+/// [`running_distinct_count()`](crate::IterAccumulate::running_distinct_count).
+#[derive(Clone, Debug)]
+pub struct HyperLogLog<S> {
+    precision: u32,
+    registers: Vec<u8>,
+    /// The sum of `2^-register` over all registers, updated whenever a register changes.
+    sum: f64,
+    /// The number of registers that are still zero.
+    zeros: usize,
+    build_hasher: S,
+}
+
+impl<S: BuildHasher> HyperLogLog<S> {
+    /// Creates an empty sketch with `2^precision` registers, which hashes values using
+    /// `build_hasher`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `precision` is not in the range `4..=18`.
+    pub fn with_hasher(precision: u32, build_hasher: S) -> Self {
+        assert!(
+            (4..=18).contains(&precision),
+            "`precision` must be in the range 4..=18"
+        );
+        let m = 1 << precision;
+        Self {
+            precision,
+            registers: vec![0; m],
+            sum: m as f64,
+            zeros: m,
+            build_hasher,
+        }
+    }
+
+    /// Adds `value` to the sketch, and returns whether this changed the sketch.
+    ///
+    /// Values that were already added never change the sketch.
+    pub fn insert<T: Hash + ?Sized>(&mut self, value: &T) -> bool {
+        let hash = self.build_hasher.hash_one(value);
+        let index = (hash >> (64 - self.precision)) as usize;
+        // Setting the lowest bit bounds the run of zeros if the remaining bits are all zero.
+        let rank = ((hash << self.precision) | 1).leading_zeros() as u8 + 1;
+        let register = &mut self.registers[index];
+        if rank <= *register {
+            return false;
+        }
+        if *register == 0 {
+            self.zeros -= 1;
+        }
+        self.sum += pow2_neg(rank) - pow2_neg(*register);
+        *register = rank;
+        true
+    }
+
+    /// Returns the precision of the sketch, i.e. the base 2 logarithm of the number of
+    /// registers.
+    #[inline]
+    pub fn precision(&self) -> u32 {
+        self.precision
+    }
+
+    /// Returns the estimated number of distinct values that have been added.
+    ///
+    /// This takes constant time. For small estimates, linear counting over the empty registers is
+    /// used instead, which is more accurate.
+    pub fn estimate(&self) -> f64 {
+        let m = self.registers.len() as f64;
+        let alpha = match self.registers.len() {
+            16 => 0.673,
+            32 => 0.697,
+            64 => 0.709,
+            _ => 0.7213 / (1.0 + 1.079 / m),
+        };
+        let raw = alpha * m * m / self.sum;
+        if raw <= 2.5 * m && self.zeros > 0 {
+            m * ln(m / self.zeros as f64)
+        } else {
+            raw
+        }
+    }
+
+    /// Merges `other` into this sketch, so that it estimates the number of distinct values added
+    /// to either of them.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the sketches have a different precision. Both sketches should also hash values
+    /// the same way, or the estimate will be meaningless.
+    pub fn merge(&mut self, other: &Self) {
+        assert_eq!(
+            self.precision, other.precision,
+            "cannot merge sketches with a different precision"
+        );
+        for (register, &rank) in self.registers.iter_mut().zip(&other.registers) {
+            if rank > *register {
+                if *register == 0 {
+                    self.zeros -= 1;
+                }
+                self.sum += pow2_neg(rank) - pow2_neg(*register);
+                *register = rank;
+            }
+        }
+    }
+}
+
+/// Returns `2^-n` for `n <= 65`, which is exact because it only sets the exponent of the float.
+fn pow2_neg(n: u8) -> f64 {
+    f64::from_bits((1023 - u64::from(n)) << 52)
+}
+
+/// Returns the natural logarithm of `x >= 1` without the standard library.
+///
+/// `x` is split into `m * 2^e` with `m` in `[1, 2)`, and `ln(m)` is computed from the quickly
+/// converging series `ln(m) = 2 * (y + y^3 / 3 + y^5 / 5 + ...)` with `y = (m - 1) / (m + 1)`.
+fn ln(x: f64) -> f64 {
+    let bits = x.to_bits();
+    let e = ((bits >> 52) & 0x7ff) as i64 - 1023;
+    let m = f64::from_bits((bits & ((1 << 52) - 1)) | (1023 << 52));
+    let y = (m - 1.0) / (m + 1.0);
+    let y2 = y * y;
+    let mut term = y;
+    let mut series = 0.0;
+    for k in 0..20 {
+        series += term / f64::from(2 * k + 1);
+        term *= y2;
+    }
+    e as f64 * core::f64::consts::LN_2 + 2.0 * series
+}
+
+/// An iterator adaptor that yields a running estimate of the number of distinct elements from the
+/// base iterator.
+///
+/// This is created by [`running_distinct_count()`]. See its documentation for more information.
+///
+/// [`running_distinct_count()`]: crate::IterAccumulate::running_distinct_count
+pub type RunningDistinctCount<I, S> = AccumulateOutput<
+    I,
+    HyperLogLog<S>,
+    fn(HyperLogLog<S>, <I as Iterator>::Item) -> (HyperLogLog<S>, f64),
+>;
+
+/// Adds `value` to `sketch` and yields the new estimate.
+pub(crate) fn distinct_step<T: Hash, S: BuildHasher>(
+    mut sketch: HyperLogLog<S>,
+    value: T,
+) -> (HyperLogLog<S>, f64) {
+    sketch.insert(&value);
+    let estimate = sketch.estimate();
+    (sketch, estimate)
+}
+
+/// A count-min sketch, which estimates how often each value was added to it.
+///
+/// The sketch is a grid of `depth` rows of `width` counters. Every value is hashed to one counter
+/// per row, and adding the value increments those counters. The estimated count of a value is
+/// the smallest of its counters, which is never less than the actual count and exceeds it by at
+/// most `e / width` times the total count with probability `1 - e^-depth`.
+///
+/// This is the accumulated state of [`RunningFrequency`], see
+/// [`running_frequency()`](crate::IterAccumulate::running_frequency).
+#[derive(Clone, Debug)]
+pub struct CountMinSketch<S> {
+    width: usize,
+    depth: usize,
+    /// The counters, row by row.
+    counters: Vec<u64>,
+    total: u64,
+    build_hasher: S,
+}
+
+impl<S: BuildHasher> CountMinSketch<S> {
+    /// Creates an empty sketch with `depth` rows of `width` counters, which hashes values using
+    /// `build_hasher`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `width` or `depth` is zero.
+    pub fn with_hasher(width: usize, depth: usize, build_hasher: S) -> Self {
+        assert!(width != 0, "`width` must not be zero");
+        assert!(depth != 0, "`depth` must not be zero");
+        Self {
+            width,
+            depth,
+            counters: vec![0; width * depth],
+            total: 0,
+            build_hasher,
+        }
+    }
+
+    /// Returns the index of the counter of `value` in each row.
+    ///
+    /// The rows use the hashes `h1 + i * h2` derived from the two halves of a single hash, which
+    /// is as good as independent hash functions for this purpose.
+    fn indices<T: Hash + ?Sized>(&self, value: &T) -> impl Iterator<Item = usize> {
+        let hash = self.build_hasher.hash_one(value);
+        let (h1, h2) = (hash as u32 as u64, (hash >> 32) | 1);
+        let width = self.width as u64;
+        (0..self.depth as u64)
+            .map(move |i| ((h1.wrapping_add(i.wrapping_mul(h2)) % width) + i * width) as usize)
+    }
+
+    /// Adds `count` occurrences of `value` to the sketch, and returns its new estimated count.
+    pub fn insert<T: Hash + ?Sized>(&mut self, value: &T, count: u64) -> u64 {
+        self.total = self.total.saturating_add(count);
+        let mut estimate = u64::MAX;
+        for i in self.indices(value) {
+            let counter = &mut self.counters[i];
+            *counter = counter.saturating_add(count);
+            estimate = estimate.min(*counter);
+        }
+        estimate
+    }
+
+    /// Returns the estimated number of times that `value` has been added, which is never less
+    /// than the actual number.
+    pub fn estimate<T: Hash + ?Sized>(&self, value: &T) -> u64 {
+        self.indices(value)
+            .map(|i| self.counters[i])
+            .min()
+            .unwrap_or(0)
+    }
+
+    /// Returns the total number of values that have been added.
+    #[inline]
+    pub fn total(&self) -> u64 {
+        self.total
+    }
+
+    /// Returns the number of counters per row.
+    #[inline]
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Returns the number of rows.
+    #[inline]
+    pub fn depth(&self) -> usize {
+        self.depth
+    }
+}
+
+/// An iterator adaptor that yields the estimated number of times each element from the base
+/// iterator has occurred so far.
+///
+/// This is created by [`running_frequency()`]. See its documentation for more information.
+///
+/// [`running_frequency()`]: crate::IterAccumulate::running_frequency
+pub type RunningFrequency<I, S> = AccumulateOutput<
+    I,
+    CountMinSketch<S>,
+    fn(CountMinSketch<S>, <I as Iterator>::Item) -> (CountMinSketch<S>, u64),
+>;
+
+/// Adds `value` to `sketch` and yields its estimated count.
+pub(crate) fn frequency_step<T: Hash, S: BuildHasher>(
+    mut sketch: CountMinSketch<S>,
+    value: T,
+) -> (CountMinSketch<S>, u64) {
+    let estimate = sketch.insert(&value, 1);
+    (sketch, estimate)
+}
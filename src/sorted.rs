@@ -0,0 +1,105 @@
+use core::{fmt, iter::FusedIterator};
+
+/// An iterator adaptor that yields whether the elements from the base iterator so far are
+/// sorted.
+///
+/// This `struct` is created by [`running_is_sorted()`] and [`running_is_sorted_by()`]. See their
+/// documentation for more information.
+///
+/// [`running_is_sorted()`]: crate::IterAccumulate::running_is_sorted
+/// [`running_is_sorted_by()`]: crate::IterAccumulate::running_is_sorted_by
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone)]
+pub struct RunningIsSorted<I: Iterator, F> {
+    iter: I,
+    /// The latest element, or [`None`] if there is none or the elements are no longer sorted.
+    last: Option<I::Item>,
+    sorted: bool,
+    compare: F,
+}
+
+impl<I: Iterator, F> RunningIsSorted<I, F> {
+    pub(crate) fn new(iter: I, compare: F) -> Self {
+        Self {
+            iter,
+            last: None,
+            sorted: true,
+            compare,
+        }
+    }
+
+    /// Returns whether the elements so far are sorted.
+    #[inline]
+    pub fn is_sorted(&self) -> bool {
+        self.sorted
+    }
+}
+
+impl<I, F> fmt::Debug for RunningIsSorted<I, F>
+where
+    I: Iterator + fmt::Debug,
+    I::Item: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("RunningIsSorted")
+            .field("iter", &self.iter)
+            .field("last", &self.last)
+            .field("sorted", &self.sorted)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<I, F> Iterator for RunningIsSorted<I, F>
+where
+    I: Iterator,
+    F: FnMut(&I::Item, &I::Item) -> bool,
+{
+    type Item = bool;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.iter.next()?;
+        if self.sorted {
+            if let Some(last) = &self.last {
+                self.sorted = (self.compare)(last, &item);
+            }
+            // Once the elements are out of order they stay that way, so there is no need to keep
+            // the latest one around.
+            self.last = self.sorted.then_some(item);
+        }
+        Some(self.sorted)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        self.iter.count()
+    }
+}
+
+impl<I, F> ExactSizeIterator for RunningIsSorted<I, F>
+where
+    I: ExactSizeIterator,
+    F: FnMut(&I::Item, &I::Item) -> bool,
+{
+    #[inline]
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+
+impl<I, F> FusedIterator for RunningIsSorted<I, F>
+where
+    I: FusedIterator,
+    F: FnMut(&I::Item, &I::Item) -> bool,
+{
+}
+
+/// The comparison of the [`RunningIsSorted`] created by
+/// [`running_is_sorted()`](crate::IterAccumulate::running_is_sorted), which allows naming its
+/// type.
+pub type Compare<T> = fn(&T, &T) -> bool;
@@ -0,0 +1,658 @@
+use crate::{Accumulate, AccumulateOutput, Float};
+
+/// The state of a running arithmetic mean.
+///
+/// This is the accumulated state of [`RunningMean`], see
+/// [`running_mean()`](crate::IterAccumulate::running_mean).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Mean<T> {
+    count: usize,
+    mean: T,
+}
+
+impl<T: Float> Mean<T> {
+    /// Creates the state of a mean of no values.
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            count: 0,
+            mean: T::ZERO,
+        }
+    }
+
+    /// Adds `x` to the mean.
+    ///
+    /// The mean is updated incrementally instead of dividing a running sum, so it does not
+    /// overflow or lose precision for long streams of large values.
+    #[inline]
+    pub fn push(&mut self, x: T) {
+        self.count += 1;
+        self.mean = self.mean + (x - self.mean) / T::from_usize(self.count);
+    }
+
+    /// Removes `x`, which has to be one of the values that have been added, from the mean.
+    ///
+    /// This is the inverse of [`push()`](Self::push), e.g. for the mean of a sliding window.
+    ///
+    /// # Panics
+    ///
+    /// Panics if no values have been added.
+    #[inline]
+    pub fn remove(&mut self, x: T) {
+        assert!(self.count != 0, "cannot remove a value from an empty mean");
+        self.count -= 1;
+        self.mean = match self.count {
+            0 => T::ZERO,
+            n => self.mean - (x - self.mean) / T::from_usize(n),
+        };
+    }
+
+    /// Returns the number of values that have been added.
+    #[inline]
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Returns the mean of the values that have been added, or zero if there are none.
+    #[inline]
+    pub fn mean(&self) -> T {
+        self.mean
+    }
+}
+
+impl<T: Float> Default for Mean<T> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Whether a variance is computed for a whole population or estimated from a sample of it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum VarianceKind {
+    /// The population variance, which divides the sum of squared deviations by `n`.
+    Population,
+    /// The unbiased sample variance, which divides the sum of squared deviations by `n - 1`.
+    Sample,
+}
+
+/// The state of a running variance, computed using Welford's online algorithm.
+///
+/// This is the accumulated state of [`RunningVariance`], see
+/// [`running_variance()`](crate::IterAccumulate::running_variance).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Variance<T> {
+    mean: Mean<T>,
+    /// The sum of squared deviations from the mean.
+    m2: T,
+}
+
+impl<T: Float> Variance<T> {
+    /// Creates the state of a variance of no values.
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            mean: Mean::new(),
+            m2: T::ZERO,
+        }
+    }
+
+    /// Adds `x` to the variance.
+    #[inline]
+    pub fn push(&mut self, x: T) {
+        let delta = x - self.mean.mean();
+        self.mean.push(x);
+        self.m2 = self.m2 + delta * (x - self.mean.mean());
+    }
+
+    /// Removes `x`, which has to be one of the values that have been added, from the variance.
+    ///
+    /// This is the inverse of [`push()`](Self::push), e.g. for the variance of a sliding window.
+    ///
+    /// # Panics
+    ///
+    /// Panics if no values have been added.
+    #[inline]
+    pub fn remove(&mut self, x: T) {
+        let delta = x - self.mean.mean();
+        self.mean.remove(x);
+        // Rounding errors could otherwise make the sum of squares slightly negative.
+        self.m2 = (self.m2 - delta * (x - self.mean.mean())).max(T::ZERO);
+        if self.count() == 0 {
+            self.m2 = T::ZERO;
+        }
+    }
+
+    /// Returns the number of values that have been added.
+    #[inline]
+    pub fn count(&self) -> usize {
+        self.mean.count()
+    }
+
+    /// Returns the mean of the values that have been added, or zero if there are none.
+    #[inline]
+    pub fn mean(&self) -> T {
+        self.mean.mean()
+    }
+
+    /// Returns the variance of the values that have been added.
+    ///
+    /// This is NaN if there are no values, or only one value for the sample variance.
+    #[inline]
+    pub fn variance(&self, kind: VarianceKind) -> T {
+        let n = match kind {
+            VarianceKind::Population => self.count(),
+            VarianceKind::Sample => self.count().saturating_sub(1),
+        };
+        if n == 0 {
+            return T::NAN;
+        }
+        self.m2 / T::from_usize(n)
+    }
+
+    /// Returns the standard deviation of the values that have been added, i.e. the square root of
+    /// the [variance](Self::variance).
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn stddev(&self, kind: VarianceKind) -> T {
+        self.variance(kind).sqrt()
+    }
+}
+
+impl<T: Float> Default for Variance<T> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// An iterator adaptor that yields the running arithmetic mean of the floating point numbers
+/// from the base iterator.
+///
+/// This is created by [`running_mean()`](crate::IterAccumulate::running_mean). See its
+/// documentation for more information.
+pub type RunningMean<I, T> = AccumulateOutput<I, Mean<T>, fn(Mean<T>, T) -> (Mean<T>, T)>;
+
+/// An iterator adaptor that yields the running variance or standard deviation of the floating
+/// point numbers from the base iterator.
+///
+/// This is created by [`running_variance()`](crate::IterAccumulate::running_variance) and, with
+/// the `std` feature, `running_stddev()`. See their documentation for more information.
+pub type RunningVariance<I, T> =
+    AccumulateOutput<I, Variance<T>, fn(Variance<T>, T) -> (Variance<T>, T)>;
+
+/// An iterator adaptor that yields the z-score of every floating point number from the base
+/// iterator relative to the preceding numbers.
+///
+/// This is created by [`running_zscore()`](crate::IterAccumulate::running_zscore). See its
+/// documentation for more information.
+#[cfg(feature = "std")]
+pub type RunningZScore<I, T> = RunningVariance<I, T>;
+
+impl VarianceKind {
+    /// Returns the closure that updates a running variance and yields its value of this kind.
+    pub(crate) fn variance<T: Float>(self) -> fn(Variance<T>, T) -> (Variance<T>, T) {
+        match self {
+            VarianceKind::Population => |mut v, x| {
+                v.push(x);
+                (v, v.variance(VarianceKind::Population))
+            },
+            VarianceKind::Sample => |mut v, x| {
+                v.push(x);
+                (v, v.variance(VarianceKind::Sample))
+            },
+        }
+    }
+
+    /// Returns the closure that updates a running variance and yields the standard deviation of
+    /// this kind.
+    #[cfg(feature = "std")]
+    pub(crate) fn stddev<T: Float>(self) -> fn(Variance<T>, T) -> (Variance<T>, T) {
+        match self {
+            VarianceKind::Population => |mut v, x| {
+                v.push(x);
+                (v, v.stddev(VarianceKind::Population))
+            },
+            VarianceKind::Sample => |mut v, x| {
+                v.push(x);
+                (v, v.stddev(VarianceKind::Sample))
+            },
+        }
+    }
+
+    /// Returns the function that returns the standard deviation of this kind of a variance.
+    #[cfg(feature = "std")]
+    pub(crate) fn stddev_of<T: Float>(self) -> fn(Variance<T>) -> T {
+        match self {
+            VarianceKind::Population => |v| v.stddev(VarianceKind::Population),
+            VarianceKind::Sample => |v| v.stddev(VarianceKind::Sample),
+        }
+    }
+
+    /// Returns the closure that yields the z-score of an element relative to the running mean and
+    /// standard deviation of this kind of the preceding elements, and then updates them.
+    #[cfg(feature = "std")]
+    pub(crate) fn zscore<T: Float>(self) -> fn(Variance<T>, T) -> (Variance<T>, T) {
+        match self {
+            VarianceKind::Population => |mut v, x| {
+                let z = (x - v.mean()) / v.stddev(VarianceKind::Population);
+                v.push(x);
+                (v, z)
+            },
+            VarianceKind::Sample => |mut v, x| {
+                let z = (x - v.mean()) / v.stddev(VarianceKind::Sample);
+                v.push(x);
+                (v, z)
+            },
+        }
+    }
+}
+
+/// The state of the running central moments up to the fourth, computed using Pébay's online
+/// formulas.
+///
+/// This is yielded by [`running_moments()`](crate::IterAccumulate::running_moments).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Moments<T> {
+    count: usize,
+    mean: T,
+    /// The sums of the second, third and fourth powers of the deviations from the mean.
+    m2: T,
+    m3: T,
+    m4: T,
+}
+
+impl<T: Float> Moments<T> {
+    /// Creates the state of the moments of no values.
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            count: 0,
+            mean: T::ZERO,
+            m2: T::ZERO,
+            m3: T::ZERO,
+            m4: T::ZERO,
+        }
+    }
+
+    /// Adds `x` to the moments.
+    #[inline]
+    pub fn push(&mut self, x: T) {
+        let n1 = T::from_usize(self.count);
+        self.count += 1;
+        let n = T::from_usize(self.count);
+        let two = T::ONE + T::ONE;
+        let three = two + T::ONE;
+
+        let delta = x - self.mean;
+        let delta_n = delta / n;
+        let delta_n2 = delta_n * delta_n;
+        let term = delta * delta_n * n1;
+
+        self.mean = self.mean + delta_n;
+        self.m4 = self.m4
+            + term * delta_n2 * (n * n - three * n + three)
+            + two * three * delta_n2 * self.m2
+            - two * two * delta_n * self.m3;
+        self.m3 = self.m3 + term * delta_n * (n - two) - three * delta_n * self.m2;
+        self.m2 = self.m2 + term;
+    }
+
+    /// Returns the number of values that have been added.
+    #[inline]
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Returns the mean of the values that have been added, or zero if there are none.
+    #[inline]
+    pub fn mean(&self) -> T {
+        self.mean
+    }
+
+    /// Returns the variance of the values that have been added.
+    ///
+    /// This is NaN if there are no values, or only one value for the sample variance.
+    #[inline]
+    pub fn variance(&self, kind: VarianceKind) -> T {
+        Variance {
+            mean: Mean {
+                count: self.count,
+                mean: self.mean,
+            },
+            m2: self.m2,
+        }
+        .variance(kind)
+    }
+
+    /// Returns the population skewness of the values that have been added.
+    ///
+    /// This is NaN if there are no values or all values are equal.
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn skewness(&self) -> T {
+        let n = T::from_usize(self.count);
+        n.sqrt() * self.m3 / (self.m2 * self.m2.sqrt())
+    }
+
+    /// Returns the population excess kurtosis of the values that have been added, which is zero
+    /// for a normal distribution.
+    ///
+    /// This is NaN if there are no values or all values are equal.
+    #[inline]
+    pub fn kurtosis(&self) -> T {
+        let n = T::from_usize(self.count);
+        let three = T::ONE + T::ONE + T::ONE;
+        n * self.m4 / (self.m2 * self.m2) - three
+    }
+}
+
+impl<T: Float> Default for Moments<T> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// An iterator adaptor that yields the running central moments of the floating point numbers from
+/// the base iterator.
+///
+/// This is created by [`running_moments()`](crate::IterAccumulate::running_moments). See its
+/// documentation for more information.
+pub type RunningMoments<I, T> = Accumulate<I, Moments<T>, fn(Moments<T>, T) -> Moments<T>>;
+
+/// The state of a running covariance between two variables, computed using a single-pass
+/// co-moment update.
+///
+/// This is the accumulated state of [`RunningCovariance`], see
+/// [`running_covariance()`](crate::IterAccumulate::running_covariance).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Covariance<T> {
+    x: Variance<T>,
+    y: Variance<T>,
+    /// The sum of the products of the deviations of `x` and `y` from their means.
+    c: T,
+}
+
+impl<T: Float> Covariance<T> {
+    /// Creates the state of a covariance of no pairs.
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            x: Variance::new(),
+            y: Variance::new(),
+            c: T::ZERO,
+        }
+    }
+
+    /// Adds the pair `(x, y)` to the covariance.
+    #[inline]
+    pub fn push(&mut self, x: T, y: T) {
+        let dx = x - self.x.mean();
+        self.x.push(x);
+        self.y.push(y);
+        self.c = self.c + dx * (y - self.y.mean());
+    }
+
+    /// Returns the number of pairs that have been added.
+    #[inline]
+    pub fn count(&self) -> usize {
+        self.x.count()
+    }
+
+    /// Returns the running statistics of the first variable.
+    #[inline]
+    pub fn x(&self) -> &Variance<T> {
+        &self.x
+    }
+
+    /// Returns the running statistics of the second variable.
+    #[inline]
+    pub fn y(&self) -> &Variance<T> {
+        &self.y
+    }
+
+    /// Returns the covariance of the pairs that have been added.
+    ///
+    /// This is NaN if there are no pairs, or only one pair for the sample covariance.
+    #[inline]
+    pub fn covariance(&self, kind: VarianceKind) -> T {
+        let n = match kind {
+            VarianceKind::Population => self.count(),
+            VarianceKind::Sample => self.count().saturating_sub(1),
+        };
+        if n == 0 {
+            return T::NAN;
+        }
+        self.c / T::from_usize(n)
+    }
+
+    /// Returns the Pearson correlation coefficient of the pairs that have been added.
+    ///
+    /// This is NaN if there are fewer than two pairs, or if either variable is constant.
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn correlation(&self) -> T {
+        self.c / (self.x.m2 * self.y.m2).sqrt()
+    }
+
+    /// Returns the slope of the least squares regression line of `y` on `x` through the pairs
+    /// that have been added.
+    ///
+    /// This is NaN if there are no pairs, and infinite or NaN if `x` is constant.
+    #[inline]
+    pub fn slope(&self) -> T {
+        self.c / self.x.m2
+    }
+
+    /// Returns the intercept of the least squares regression line of `y` on `x` through the pairs
+    /// that have been added.
+    ///
+    /// This is NaN whenever the [slope](Self::slope) is not finite.
+    #[inline]
+    pub fn intercept(&self) -> T {
+        self.y.mean() - self.slope() * self.x.mean()
+    }
+
+    /// Returns the coefficient of determination (R²) of the least squares regression line, i.e.
+    /// the fraction of the variance of `y` that is explained by `x`.
+    #[inline]
+    pub fn r_squared(&self) -> T {
+        self.c * self.c / (self.x.m2 * self.y.m2)
+    }
+}
+
+impl<T: Float> Default for Covariance<T> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// An iterator adaptor that yields the running covariance or correlation of the pairs of floating
+/// point numbers from the base iterator.
+///
+/// This is created by [`running_covariance()`](crate::IterAccumulate::running_covariance) and,
+/// with the `std` feature, `running_correlation()`. See their documentation for more information.
+pub type RunningCovariance<I, T> =
+    AccumulateOutput<I, Covariance<T>, fn(Covariance<T>, (T, T)) -> (Covariance<T>, T)>;
+
+/// An iterator adaptor that yields the running least squares regression line through the pairs of
+/// floating point numbers from the base iterator.
+///
+/// This is created by
+/// [`running_linear_regression()`](crate::IterAccumulate::running_linear_regression). See its
+/// documentation for more information.
+pub type RunningLinearRegression<I, T> =
+    AccumulateOutput<I, Covariance<T>, fn(Covariance<T>, (T, T)) -> (Covariance<T>, (T, T))>;
+
+/// The closure of [`RunningCovariance`].
+type CovarianceStep<T> = fn(Covariance<T>, (T, T)) -> (Covariance<T>, T);
+
+impl VarianceKind {
+    /// Returns the closure that updates a running covariance and yields its value of this kind.
+    pub(crate) fn covariance<T: Float>(self) -> CovarianceStep<T> {
+        match self {
+            VarianceKind::Population => |mut c, (x, y)| {
+                c.push(x, y);
+                (c, c.covariance(VarianceKind::Population))
+            },
+            VarianceKind::Sample => |mut c, (x, y)| {
+                c.push(x, y);
+                (c, c.covariance(VarianceKind::Sample))
+            },
+        }
+    }
+}
+
+/// The state of an exponentially weighted moving average.
+///
+/// This is the accumulated state of [`RunningEwma`], see
+/// [`ewma()`](crate::IterAccumulate::ewma).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Ewma<T> {
+    alpha: T,
+    /// The smoothed value, which starts at zero if the bias is corrected.
+    value: T,
+    /// `(1 - alpha)^n`, or [`None`] if the bias is not corrected and `value` is taken from the
+    /// first value instead.
+    decay: Option<T>,
+    count: usize,
+}
+
+impl<T: Float> Ewma<T> {
+    /// Creates the state of an exponentially weighted moving average of no values, with the
+    /// smoothing factor `alpha`.
+    ///
+    /// If `bias_corrected` is `false`, the average starts at the first value. Otherwise, it starts
+    /// at zero and is divided by `1 - (1 - alpha)^n` after `n` values, which corrects the bias
+    /// towards zero, like in the Adam optimizer.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `alpha` is not in the range `(0, 1]`.
+    #[inline]
+    pub fn new(alpha: T, bias_corrected: bool) -> Self {
+        assert!(
+            alpha > T::ZERO && alpha <= T::ONE,
+            "`alpha` must be in the range (0, 1]"
+        );
+        Self {
+            alpha,
+            value: T::ZERO,
+            decay: bias_corrected.then_some(T::ONE),
+            count: 0,
+        }
+    }
+
+    /// Adds `x` to the average.
+    #[inline]
+    pub fn push(&mut self, x: T) {
+        self.count += 1;
+        if self.count == 1 && self.decay.is_none() {
+            self.value = x;
+        } else {
+            self.value = self.value + self.alpha * (x - self.value);
+        }
+        if let Some(decay) = &mut self.decay {
+            *decay = *decay * (T::ONE - self.alpha);
+        }
+    }
+
+    /// Returns the number of values that have been added.
+    #[inline]
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Returns the average of the values that have been added, or zero if there are none.
+    #[inline]
+    pub fn value(&self) -> T {
+        match self.decay {
+            Some(decay) if self.count > 0 => self.value / (T::ONE - decay),
+            _ => self.value,
+        }
+    }
+}
+
+/// An iterator adaptor that yields the exponentially weighted moving average of the floating
+/// point numbers from the base iterator.
+///
+/// This is created by [`ewma()`] and [`ewma_bias_corrected()`]. See their documentation for more
+/// information.
+///
+/// [`ewma()`]: crate::IterAccumulate::ewma
+/// [`ewma_bias_corrected()`]: crate::IterAccumulate::ewma_bias_corrected
+pub type RunningEwma<I, T> = AccumulateOutput<I, Ewma<T>, fn(Ewma<T>, T) -> (Ewma<T>, T)>;
+
+/// Updates an exponentially weighted moving average and yields its value.
+pub(crate) fn ewma_step<T: Float>(mut ewma: Ewma<T>, x: T) -> (Ewma<T>, T) {
+    ewma.push(x);
+    (ewma, ewma.value())
+}
+
+/// The state of a running log-sum-exp, i.e. the logarithm of the sum of the exponentials of
+/// some values.
+///
+/// This is the accumulated state of [`RunningLogSumExp`], see
+/// [`running_log_sum_exp()`](crate::IterAccumulate::running_log_sum_exp).
+#[cfg(feature = "std")]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct LogSumExp<T> {
+    /// The maximum value so far, which the exponentials are scaled by.
+    max: T,
+    /// The sum of `exp(x - max)` over all values `x`.
+    sum: T,
+}
+
+#[cfg(feature = "std")]
+impl<T: Float> LogSumExp<T> {
+    /// Creates the state of a log-sum-exp of no values.
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            max: T::NEG_INFINITY,
+            sum: T::ZERO,
+        }
+    }
+
+    /// Adds `x` to the log-sum-exp.
+    ///
+    /// The exponentials are scaled by the running maximum, so that they neither overflow nor
+    /// underflow.
+    #[inline]
+    pub fn push(&mut self, x: T) {
+        if x == T::NEG_INFINITY {
+            // `exp(-inf) = 0` does not contribute to the sum
+        } else if x <= self.max {
+            self.sum = self.sum + (x - self.max).exp();
+        } else {
+            self.sum = self.sum * (self.max - x).exp() + T::ONE;
+            self.max = x;
+        }
+    }
+
+    /// Returns the log-sum-exp of the values that have been added, or negative infinity if there
+    /// are none.
+    #[inline]
+    pub fn value(&self) -> T {
+        self.max + self.sum.ln()
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: Float> Default for LogSumExp<T> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// An iterator adaptor that yields the running log-sum-exp of the floating point numbers from the
+/// base iterator.
+///
+/// This is created by [`running_log_sum_exp()`](crate::IterAccumulate::running_log_sum_exp). See
+/// its documentation for more information.
+#[cfg(feature = "std")]
+pub type RunningLogSumExp<I, T> =
+    AccumulateOutput<I, LogSumExp<T>, fn(LogSumExp<T>, T) -> (LogSumExp<T>, T)>;
@@ -0,0 +1,265 @@
+use core::{fmt, iter::FusedIterator, ops::ControlFlow};
+
+/// An iterator adaptor that accumulates the elements from the base iterator using the provided
+/// closure, until the closure decides to stop.
+///
+/// This `struct` is created by [`accumulate_while()`]. See its documentation for more information.
+///
+/// [`accumulate_while()`]: crate::IterAccumulate::accumulate_while
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone)]
+pub struct AccumulateWhile<I, B, F> {
+    iter: I,
+    /// The accumulated value. This is [`None`] once the iterator has finished, either because the
+    /// base iterator returned [`None`] or because the closure returned [`ControlFlow::Break`].
+    acc: Option<B>,
+    f: F,
+}
+
+impl<I, B, F> AccumulateWhile<I, B, F> {
+    pub(crate) fn new(iter: I, acc: B, f: F) -> Self {
+        Self {
+            iter,
+            acc: Some(acc),
+            f,
+        }
+    }
+}
+
+impl<I, B, F> fmt::Debug for AccumulateWhile<I, B, F>
+where
+    I: fmt::Debug,
+    B: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("AccumulateWhile")
+            .field("iter", &self.iter)
+            .field("acc", &self.acc)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<I, B, F> Iterator for AccumulateWhile<I, B, F>
+where
+    I: Iterator,
+    B: Clone,
+    F: FnMut(B, I::Item) -> ControlFlow<B, B>,
+{
+    type Item = B;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let acc = self.acc.take()?;
+        let item = self.iter.next()?;
+        match (self.f)(acc, item) {
+            ControlFlow::Continue(acc) => Some(self.acc.insert(acc).clone()),
+            ControlFlow::Break(acc) => Some(acc),
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.acc.is_none() {
+            (0, Some(0))
+        } else {
+            let (_, upper) = self.iter.size_hint();
+            (0, upper)
+        }
+    }
+}
+
+impl<I, B, F> FusedIterator for AccumulateWhile<I, B, F>
+where
+    I: Iterator,
+    B: Clone,
+    F: FnMut(B, I::Item) -> ControlFlow<B, B>,
+{
+}
+
+/// Whether [`accumulate_until()`] and [`accumulate_while_acc()`] yield the accumulated value that
+/// ends the iteration.
+///
+/// [`accumulate_until()`]: crate::IterAccumulate::accumulate_until
+/// [`accumulate_while_acc()`]: crate::IterAccumulate::accumulate_while_acc
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Boundary {
+    /// Yield the accumulated value that ends the iteration as the last element.
+    Inclusive,
+    /// Do not yield the accumulated value that ends the iteration.
+    Exclusive,
+}
+
+/// An iterator adaptor that accumulates the elements from the base iterator using the provided
+/// closure, until the accumulated value satisfies or stops satisfying a predicate.
+///
+/// This `struct` is created by [`accumulate_until()`] and [`accumulate_while_acc()`]. See their
+/// documentation for more information.
+///
+/// [`accumulate_until()`]: crate::IterAccumulate::accumulate_until
+/// [`accumulate_while_acc()`]: crate::IterAccumulate::accumulate_while_acc
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone)]
+pub struct AccumulateUntil<I, B, F, P> {
+    iter: I,
+    /// The accumulated value. This is [`None`] once the iterator has finished.
+    acc: Option<B>,
+    f: F,
+    pred: P,
+    /// The result of the predicate that ends the iteration.
+    stop_on: bool,
+    boundary: Boundary,
+}
+
+impl<I, B, F, P> AccumulateUntil<I, B, F, P> {
+    pub(crate) fn new(iter: I, acc: B, f: F, pred: P, stop_on: bool, boundary: Boundary) -> Self {
+        Self {
+            iter,
+            acc: Some(acc),
+            f,
+            pred,
+            stop_on,
+            boundary,
+        }
+    }
+}
+
+impl<I, B, F, P> fmt::Debug for AccumulateUntil<I, B, F, P>
+where
+    I: fmt::Debug,
+    B: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("AccumulateUntil")
+            .field("iter", &self.iter)
+            .field("acc", &self.acc)
+            .field("stop_on", &self.stop_on)
+            .field("boundary", &self.boundary)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<I, B, F, P> Iterator for AccumulateUntil<I, B, F, P>
+where
+    I: Iterator,
+    B: Clone,
+    F: FnMut(B, I::Item) -> B,
+    P: FnMut(&B) -> bool,
+{
+    type Item = B;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let acc = self.acc.take()?;
+        let item = self.iter.next()?;
+        let acc = (self.f)(acc, item);
+        if (self.pred)(&acc) == self.stop_on {
+            match self.boundary {
+                Boundary::Inclusive => Some(acc),
+                Boundary::Exclusive => None,
+            }
+        } else {
+            Some(self.acc.insert(acc).clone())
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.acc.is_none() {
+            (0, Some(0))
+        } else {
+            let (_, upper) = self.iter.size_hint();
+            (0, upper)
+        }
+    }
+}
+
+impl<I, B, F, P> FusedIterator for AccumulateUntil<I, B, F, P>
+where
+    I: Iterator,
+    B: Clone,
+    F: FnMut(B, I::Item) -> B,
+    P: FnMut(&B) -> bool,
+{
+}
+
+/// An iterator adaptor that accumulates the elements from the base iterator using the provided
+/// closure, until two successive accumulated values have converged.
+///
+/// This `struct` is created by [`accumulate_converge()`]. See its documentation for more
+/// information.
+///
+/// [`accumulate_converge()`]: crate::IterAccumulate::accumulate_converge
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone)]
+pub struct AccumulateConverge<I, B, F, P> {
+    iter: I,
+    /// The accumulated value. This is [`None`] once the iterator has finished.
+    acc: Option<B>,
+    f: F,
+    converged: P,
+}
+
+impl<I, B, F, P> AccumulateConverge<I, B, F, P> {
+    pub(crate) fn new(iter: I, acc: B, f: F, converged: P) -> Self {
+        Self {
+            iter,
+            acc: Some(acc),
+            f,
+            converged,
+        }
+    }
+}
+
+impl<I, B, F, P> fmt::Debug for AccumulateConverge<I, B, F, P>
+where
+    I: fmt::Debug,
+    B: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("AccumulateConverge")
+            .field("iter", &self.iter)
+            .field("acc", &self.acc)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<I, B, F, P> Iterator for AccumulateConverge<I, B, F, P>
+where
+    I: Iterator,
+    B: Clone,
+    F: FnMut(B, I::Item) -> B,
+    P: FnMut(&B, &B) -> bool,
+{
+    type Item = B;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let prev = self.acc.take()?;
+        let item = self.iter.next()?;
+        let acc = (self.f)(prev.clone(), item);
+        if (self.converged)(&prev, &acc) {
+            Some(acc)
+        } else {
+            Some(self.acc.insert(acc).clone())
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.acc.is_none() {
+            (0, Some(0))
+        } else {
+            let (_, upper) = self.iter.size_hint();
+            (0, upper)
+        }
+    }
+}
+
+impl<I, B, F, P> FusedIterator for AccumulateConverge<I, B, F, P>
+where
+    I: Iterator,
+    B: Clone,
+    F: FnMut(B, I::Item) -> B,
+    P: FnMut(&B, &B) -> bool,
+{
+}
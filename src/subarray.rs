@@ -0,0 +1,106 @@
+use core::ops::{Add, Range};
+
+use crate::AccumulateOutput;
+
+/// The state of Kadane's algorithm for finding the contiguous subarray with the largest sum.
+///
+/// This is the accumulated state of [`RunningMaxSubarraySum`], see
+/// [`running_max_subarray_sum()`](crate::IterAccumulate::running_max_subarray_sum).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MaxSubarray<T> {
+    index: usize,
+    /// The largest sum of a subarray ending at the latest value, and where that subarray starts.
+    ending_here: Option<(T, usize)>,
+    /// The largest sum of any subarray so far, and where that subarray starts and ends.
+    best: Option<(T, usize, usize)>,
+}
+
+impl<T> MaxSubarray<T>
+where
+    T: Copy + PartialOrd + Add<Output = T>,
+{
+    /// Creates the state of no values.
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            index: 0,
+            ending_here: None,
+            best: None,
+        }
+    }
+
+    /// Adds `x` as the next value.
+    ///
+    /// The subarray ending here is extended by `x` unless starting over at `x` alone gives a
+    /// strictly larger sum. The best subarray is only replaced by a strictly larger one, so ties
+    /// are resolved in favor of the earliest subarray.
+    #[inline]
+    pub fn push(&mut self, x: T) {
+        let ending_here = match self.ending_here {
+            Some((sum, start)) if sum + x >= x => (sum + x, start),
+            _ => (x, self.index),
+        };
+        self.index += 1;
+        self.ending_here = Some(ending_here);
+        if self.best.is_none_or(|(best, _, _)| ending_here.0 > best) {
+            self.best = Some((ending_here.0, ending_here.1, self.index));
+        }
+    }
+
+    /// Returns the number of values that have been added.
+    #[inline]
+    pub fn count(&self) -> usize {
+        self.index
+    }
+
+    /// Returns the largest sum of a subarray ending at the latest value, or [`None`] if no values
+    /// have been added.
+    #[inline]
+    pub fn ending_here(&self) -> Option<T> {
+        self.ending_here.map(|(sum, _)| sum)
+    }
+
+    /// Returns the largest sum of any non-empty subarray so far, or [`None`] if no values have
+    /// been added.
+    #[inline]
+    pub fn best(&self) -> Option<T> {
+        self.best.map(|(sum, _, _)| sum)
+    }
+
+    /// Returns the index range of the subarray with the largest sum so far, or [`None`] if no
+    /// values have been added.
+    #[inline]
+    pub fn range(&self) -> Option<Range<usize>> {
+        self.best.map(|(_, start, end)| start..end)
+    }
+}
+
+impl<T> Default for MaxSubarray<T>
+where
+    T: Copy + PartialOrd + Add<Output = T>,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// An iterator adaptor that yields the largest sum of any contiguous run of the elements from the
+/// base iterator so far.
+///
+/// This is created by [`running_max_subarray_sum()`]. See its documentation for more
+/// information.
+///
+/// [`running_max_subarray_sum()`]: crate::IterAccumulate::running_max_subarray_sum
+pub type RunningMaxSubarraySum<I, T> =
+    AccumulateOutput<I, MaxSubarray<T>, fn(MaxSubarray<T>, T) -> (MaxSubarray<T>, T)>;
+
+/// Adds `x` to `state` and returns it along with the best sum so far.
+pub(crate) fn step<T>(mut state: MaxSubarray<T>, x: T) -> (MaxSubarray<T>, T)
+where
+    T: Copy + PartialOrd + Add<Output = T>,
+{
+    state.push(x);
+    let best = state.best().unwrap_or(x);
+    (state, best)
+}
@@ -0,0 +1,91 @@
+use crate::IterAccumulate;
+use alloc::vec::{self, Vec};
+use core::{fmt, iter};
+
+/// An iterator adaptor that accumulates the elements from the back of the base iterator and
+/// yields the suffix accumulations in the order of the base iterator.
+///
+/// This `struct` is created by [`suffix_accumulate_aligned()`]. See its documentation for more
+/// information.
+///
+/// [`suffix_accumulate_aligned()`]: crate::IterAccumulate::suffix_accumulate_aligned
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone)]
+pub struct SuffixAccumulateAligned<I, B, F> {
+    pending: Option<(I, B, F)>,
+    buf: iter::Rev<vec::IntoIter<B>>,
+}
+
+impl<I, B, F> SuffixAccumulateAligned<I, B, F> {
+    pub(crate) fn new(iter: I, acc: B, f: F) -> Self {
+        Self {
+            pending: Some((iter, acc, f)),
+            buf: Vec::new().into_iter().rev(),
+        }
+    }
+}
+
+impl<I, B, F> SuffixAccumulateAligned<I, B, F>
+where
+    I: DoubleEndedIterator,
+    B: Clone,
+    F: FnMut(B, I::Item) -> B,
+{
+    /// Accumulates the whole base iterator into the buffer if that has not happened yet.
+    fn fill(&mut self) {
+        if let Some((iter, acc, f)) = self.pending.take() {
+            let buf: Vec<B> = iter.rev().accumulate(acc, f).collect();
+            self.buf = buf.into_iter().rev();
+        }
+    }
+}
+
+impl<I, B, F> fmt::Debug for SuffixAccumulateAligned<I, B, F>
+where
+    I: fmt::Debug,
+    B: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let pending = self.pending.as_ref().map(|(iter, acc, _)| (iter, acc));
+        f.debug_struct("SuffixAccumulateAligned")
+            .field("pending", &pending)
+            .field("buf", &self.buf)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<I, B, F> Iterator for SuffixAccumulateAligned<I, B, F>
+where
+    I: DoubleEndedIterator,
+    B: Clone,
+    F: FnMut(B, I::Item) -> B,
+{
+    type Item = B;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.fill();
+        self.buf.next()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match &self.pending {
+            Some((iter, _, _)) => iter.size_hint(),
+            None => self.buf.size_hint(),
+        }
+    }
+}
+
+impl<I, B, F> DoubleEndedIterator for SuffixAccumulateAligned<I, B, F>
+where
+    I: DoubleEndedIterator,
+    B: Clone,
+    F: FnMut(B, I::Item) -> B,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.fill();
+        self.buf.next_back()
+    }
+}
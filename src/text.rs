@@ -0,0 +1,224 @@
+use core::iter::FusedIterator;
+
+mod sealed {
+    pub trait Sealed {}
+
+    impl Sealed for char {}
+    impl Sealed for u8 {}
+}
+
+/// A unit of text, i.e. a [`char`] or a byte.
+///
+/// This trait is sealed and cannot be implemented outside of this crate. It only exists to make
+/// the text adaptors generic over character and byte streams.
+pub trait TextUnit: Copy + sealed::Sealed {
+    /// Returns the unit as an ASCII byte, or [`None`] if it is not ASCII.
+    fn to_ascii(self) -> Option<u8>;
+}
+
+impl TextUnit for char {
+    #[inline]
+    fn to_ascii(self) -> Option<u8> {
+        self.is_ascii().then_some(self as u8)
+    }
+}
+
+impl TextUnit for u8 {
+    #[inline]
+    fn to_ascii(self) -> Option<u8> {
+        self.is_ascii().then_some(self)
+    }
+}
+
+/// A line and column position in a text, both starting at one.
+///
+/// Columns count units of text, i.e. characters for [`char`] streams and bytes for [`u8`]
+/// streams.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Position {
+    /// The line number, starting at one.
+    pub line: usize,
+    /// The column number, starting at one.
+    pub column: usize,
+}
+
+impl Position {
+    /// The position of the first unit of a text.
+    pub const START: Self = Self { line: 1, column: 1 };
+}
+
+impl Default for Position {
+    #[inline]
+    fn default() -> Self {
+        Self::START
+    }
+}
+
+/// An iterator adaptor that yields the units of text from the base iterator, together with their
+/// line and column positions.
+///
+/// This `struct` is created by [`text_positions()`]. See its documentation for more information.
+///
+/// [`text_positions()`]: crate::IterAccumulate::text_positions
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone, Debug)]
+pub struct Positions<I> {
+    iter: I,
+    /// The position of the next unit.
+    position: Position,
+    /// The position of the preceding unit if it was a `'\r'`, so that a following `'\n'` does not
+    /// end another line.
+    cr: Option<Position>,
+    tab_width: usize,
+}
+
+impl<I> Positions<I> {
+    pub(crate) fn new(iter: I, tab_width: usize) -> Self {
+        assert!(tab_width != 0, "`tab_width` must not be zero");
+        Self {
+            iter,
+            position: Position::START,
+            cr: None,
+            tab_width,
+        }
+    }
+
+    /// Returns the position of the next unit of text, which is the end position of the text once
+    /// the iterator has finished.
+    #[inline]
+    pub fn position(&self) -> Position {
+        self.position
+    }
+
+    /// Returns the current position and moves to the start of the next line.
+    fn new_line(&mut self) -> Position {
+        let position = self.position;
+        self.position = Position {
+            line: position.line + 1,
+            column: 1,
+        };
+        position
+    }
+}
+
+impl<I> Iterator for Positions<I>
+where
+    I: Iterator,
+    I::Item: TextUnit,
+{
+    type Item = (I::Item, Position);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let unit = self.iter.next()?;
+        let cr = self.cr.take();
+        let position = match unit.to_ascii() {
+            Some(b'\n') => match cr {
+                Some(cr) => Position {
+                    column: cr.column + 1,
+                    ..cr
+                },
+                None => self.new_line(),
+            },
+            Some(b'\r') => {
+                let position = self.new_line();
+                self.cr = Some(position);
+                position
+            }
+            Some(b'\t') => {
+                let position = self.position;
+                self.position.column += self.tab_width - (position.column - 1) % self.tab_width;
+                position
+            }
+            _ => {
+                let position = self.position;
+                self.position.column += 1;
+                position
+            }
+        };
+        Some((unit, position))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I> ExactSizeIterator for Positions<I>
+where
+    I: ExactSizeIterator,
+    I::Item: TextUnit,
+{
+}
+
+impl<I> FusedIterator for Positions<I>
+where
+    I: FusedIterator,
+    I::Item: TextUnit,
+{
+}
+
+/// The offset of a [`char`] in a text, both in UTF-8 bytes and in UTF-16 code units.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct TextOffset {
+    /// The offset in UTF-8 bytes, as used to index a [`str`].
+    pub utf8: usize,
+    /// The offset in UTF-16 code units, as used by e.g. the Language Server Protocol.
+    pub utf16: usize,
+}
+
+/// An iterator adaptor that yields the characters from the base iterator, together with their
+/// UTF-8 and UTF-16 offsets.
+///
+/// This `struct` is created by [`text_offsets()`]. See its documentation for more information.
+///
+/// [`text_offsets()`]: crate::IterAccumulate::text_offsets
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone, Debug)]
+pub struct TextOffsets<I> {
+    iter: I,
+    /// The offset of the next character.
+    offset: TextOffset,
+}
+
+impl<I> TextOffsets<I> {
+    pub(crate) fn new(iter: I) -> Self {
+        Self {
+            iter,
+            offset: TextOffset::default(),
+        }
+    }
+
+    /// Returns the offset of the next character, which is the length of the text once the
+    /// iterator has finished.
+    #[inline]
+    pub fn offset(&self) -> TextOffset {
+        self.offset
+    }
+}
+
+impl<I> Iterator for TextOffsets<I>
+where
+    I: Iterator<Item = char>,
+{
+    type Item = (char, TextOffset);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let c = self.iter.next()?;
+        let offset = self.offset;
+        self.offset.utf8 += c.len_utf8();
+        self.offset.utf16 += c.len_utf16();
+        Some((c, offset))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I> ExactSizeIterator for TextOffsets<I> where I: ExactSizeIterator<Item = char> {}
+
+impl<I> FusedIterator for TextOffsets<I> where I: FusedIterator<Item = char> {}
@@ -0,0 +1,312 @@
+#[cfg(feature = "alloc")]
+use alloc::collections::VecDeque;
+use core::{
+    fmt,
+    iter::FusedIterator,
+    ops::{Add, Sub},
+};
+
+/// An iterator adaptor that groups the timestamped elements from the base iterator into windows
+/// of a fixed duration or sessions separated by gaps, and yields the accumulated value of every
+/// window once it is complete.
+///
+/// This `struct` is created by [`tumbling_time_windows()`] and [`session_windows()`]. See their
+/// documentation for more information.
+///
+/// [`tumbling_time_windows()`]: crate::IterAccumulate::tumbling_time_windows
+/// [`session_windows()`]: crate::IterAccumulate::session_windows
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone)]
+pub struct TimeWindows<I, T, D, B, F> {
+    iter: I,
+    /// The start and the last timestamp of the current window, and its accumulated value.
+    window: Option<(T, T, B)>,
+    /// The width of a window, or the largest gap within a session.
+    limit: D,
+    /// Whether `limit` is measured from the last element instead of the start of the window.
+    session: bool,
+    /// Returns the start of the next window for a timestamp that does not fit into the window
+    /// with the given start.
+    next_start: fn(T, &D, T) -> T,
+    init: B,
+    f: F,
+}
+
+impl<I, T, D, B, F> TimeWindows<I, T, D, B, F> {
+    pub(crate) fn new(
+        iter: I,
+        limit: D,
+        session: bool,
+        next_start: fn(T, &D, T) -> T,
+        init: B,
+        f: F,
+    ) -> Self {
+        Self {
+            iter,
+            window: None,
+            limit,
+            session,
+            next_start,
+            init,
+            f,
+        }
+    }
+}
+
+impl<I, T, D, B, F> fmt::Debug for TimeWindows<I, T, D, B, F>
+where
+    I: fmt::Debug,
+    T: fmt::Debug,
+    D: fmt::Debug,
+    B: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("TimeWindows")
+            .field("iter", &self.iter)
+            .field("window", &self.window)
+            .field("limit", &self.limit)
+            .field("session", &self.session)
+            .field("init", &self.init)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Returns whether an element with the timestamp `t` belongs to the window with the given start
+/// and last timestamp.
+#[inline]
+fn fits<T, D>(session: bool, limit: &D, start: T, last: T, t: T) -> bool
+where
+    T: Sub<Output = D>,
+    D: PartialOrd,
+{
+    if session {
+        t - last <= *limit
+    } else {
+        t - start < *limit
+    }
+}
+
+/// Returns the start of the tumbling window of the timestamp `t`, the first of the windows
+/// following the one starting at `start` that `t` is less than `width` after.
+#[inline]
+pub(crate) fn tumbling_start<T, D>(start: T, width: &D, t: T) -> T
+where
+    T: Copy + Add<D, Output = T> + Sub<Output = D>,
+    D: Clone + PartialOrd + Add<Output = D> + Sub<Output = D>,
+{
+    if fits(false, width, start, start, t) {
+        return start;
+    }
+    let next = start + width.clone();
+    assert!(
+        t - next < t - start,
+        "the width of a tumbling window must be positive"
+    );
+    advance(start, width.clone(), t)
+}
+
+/// Returns `start` advanced by the largest multiple of `step` that keeps it at or before `t`.
+///
+/// The step is doubled as long as it fits into the remaining distance, so this takes a number of
+/// steps logarithmic in `(t - start) / step`.
+fn advance<T, D>(start: T, step: D, t: T) -> T
+where
+    T: Copy + Add<D, Output = T> + Sub<Output = D>,
+    D: Clone + PartialOrd + Add<Output = D> + Sub<Output = D>,
+{
+    if t - start < step {
+        return start;
+    }
+    // Only double the step if it fits twice, so that doubling cannot overflow.
+    let start = if t - start - step.clone() < step {
+        start
+    } else {
+        advance(start, step.clone() + step.clone(), t)
+    };
+    if t - start < step {
+        start
+    } else {
+        start + step
+    }
+}
+
+/// Returns the timestamp `t` itself, the start of the session that follows a gap.
+#[inline]
+pub(crate) fn session_start<T, D>(_: T, _: &D, t: T) -> T {
+    t
+}
+
+impl<I, T, V, D, B, F> Iterator for TimeWindows<I, T, D, B, F>
+where
+    I: Iterator<Item = (T, V)>,
+    T: Copy + Sub<Output = D>,
+    D: PartialOrd,
+    B: Clone,
+    F: FnMut(B, V) -> B,
+{
+    type Item = (T, T, B);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        for (t, value) in self.iter.by_ref() {
+            match self.window.take() {
+                Some((start, last, acc)) if fits(self.session, &self.limit, start, last, t) => {
+                    self.window = Some((start, t, (self.f)(acc, value)));
+                }
+                closed => {
+                    let start = match closed {
+                        Some((start, _, _)) => (self.next_start)(start, &self.limit, t),
+                        None => t,
+                    };
+                    self.window = Some((start, t, (self.f)(self.init.clone(), value)));
+                    if closed.is_some() {
+                        return closed;
+                    }
+                }
+            }
+        }
+        self.window.take()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.iter.size_hint();
+        let pending = usize::from(self.window.is_some());
+        (
+            usize::from(lower != 0 || pending != 0),
+            upper.and_then(|upper| upper.checked_add(pending)),
+        )
+    }
+}
+
+impl<I, T, V, D, B, F> FusedIterator for TimeWindows<I, T, D, B, F>
+where
+    I: FusedIterator<Item = (T, V)>,
+    T: Copy + Sub<Output = D>,
+    D: PartialOrd,
+    B: Clone,
+    F: FnMut(B, V) -> B,
+{
+}
+
+/// An iterator adaptor that accumulates the values of the timestamped elements from the base
+/// iterator within a trailing duration, by adding values as they enter the window and removing
+/// them as they leave it.
+///
+/// This `struct` is created by [`sliding_time_window()`]. See its documentation for more
+/// information.
+///
+/// [`sliding_time_window()`]: crate::IterAccumulate::sliding_time_window
+#[cfg(feature = "alloc")]
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone)]
+pub struct SlidingTimeWindow<I, T, V, D, B, F, G> {
+    iter: I,
+    /// The elements in the window, from oldest to newest.
+    window: VecDeque<(T, V)>,
+    duration: D,
+    /// The accumulated value of the elements in the window. This is only [`None`] while it is
+    /// moved into a closure, or if a closure panicked.
+    acc: Option<B>,
+    add: F,
+    remove: G,
+}
+
+#[cfg(feature = "alloc")]
+impl<I, T, V, D, B, F, G> SlidingTimeWindow<I, T, V, D, B, F, G> {
+    pub(crate) fn new(iter: I, duration: D, init: B, add: F, remove: G) -> Self {
+        Self {
+            iter,
+            window: VecDeque::new(),
+            duration,
+            acc: Some(init),
+            add,
+            remove,
+        }
+    }
+
+    /// Returns an iterator over the elements in the window, from oldest to newest.
+    #[inline]
+    pub fn window(&self) -> impl Iterator<Item = &(T, V)> {
+        self.window.iter()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<I, T, V, D, B, F, G> fmt::Debug for SlidingTimeWindow<I, T, V, D, B, F, G>
+where
+    I: fmt::Debug,
+    T: fmt::Debug,
+    V: fmt::Debug,
+    D: fmt::Debug,
+    B: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("SlidingTimeWindow")
+            .field("iter", &self.iter)
+            .field("window", &self.window)
+            .field("duration", &self.duration)
+            .field("acc", &self.acc)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<I, T, V, D, B, F, G> Iterator for SlidingTimeWindow<I, T, V, D, B, F, G>
+where
+    I: Iterator<Item = (T, V)>,
+    T: Copy + Sub<Output = D>,
+    V: Clone,
+    D: PartialOrd,
+    B: Clone,
+    F: FnMut(B, V) -> B,
+    G: FnMut(B, V) -> B,
+{
+    type Item = (T, B);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let (t, value) = self.iter.next()?;
+        let mut acc = self.acc.take().expect(crate::POISONED);
+        while let Some(&(oldest, _)) = self.window.front() {
+            if t - oldest < self.duration {
+                break;
+            }
+            let (_, old) = self.window.pop_front().unwrap();
+            acc = (self.remove)(acc, old);
+        }
+        self.window.push_back((t, value.clone()));
+        Some((t, self.acc.insert((self.add)(acc, value)).clone()))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<I, T, V, D, B, F, G> ExactSizeIterator for SlidingTimeWindow<I, T, V, D, B, F, G>
+where
+    I: ExactSizeIterator<Item = (T, V)>,
+    T: Copy + Sub<Output = D>,
+    V: Clone,
+    D: PartialOrd,
+    B: Clone,
+    F: FnMut(B, V) -> B,
+    G: FnMut(B, V) -> B,
+{
+}
+
+#[cfg(feature = "alloc")]
+impl<I, T, V, D, B, F, G> FusedIterator for SlidingTimeWindow<I, T, V, D, B, F, G>
+where
+    I: FusedIterator<Item = (T, V)>,
+    T: Copy + Sub<Output = D>,
+    V: Clone,
+    D: PartialOrd,
+    B: Clone,
+    F: FnMut(B, V) -> B,
+    G: FnMut(B, V) -> B,
+{
+}
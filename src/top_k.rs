@@ -0,0 +1,101 @@
+use alloc::{collections::BinaryHeap, vec::Vec};
+use core::cmp::Reverse;
+
+use crate::AccumulateOutput;
+
+/// The `k` largest values seen so far.
+///
+/// The values are kept in a min-heap of size `k`, so the smallest of them can be replaced in
+/// `O(log k)` time when a larger value is added. To keep the smallest values instead, wrap them
+/// in [`Reverse`]. To rank values by a key, add them as `(key, value)` tuples.
+///
+/// This is the accumulated state of [`RunningTopK`], see
+/// [`running_top_k()`](crate::IterAccumulate::running_top_k).
+#[derive(Clone, Debug)]
+pub struct TopK<T> {
+    k: usize,
+    heap: BinaryHeap<Reverse<T>>,
+}
+
+impl<T: Ord> TopK<T> {
+    /// Creates an empty set of the `k` largest values.
+    #[inline]
+    pub fn new(k: usize) -> Self {
+        Self {
+            k,
+            heap: BinaryHeap::with_capacity(k),
+        }
+    }
+
+    /// Adds `x` if it is among the `k` largest values so far, and returns whether it was added.
+    ///
+    /// If there are already `k` values, `x` replaces the smallest of them if it is strictly
+    /// larger, so values that were added earlier win ties.
+    pub fn push(&mut self, x: T) -> bool {
+        if self.heap.len() < self.k {
+            self.heap.push(Reverse(x));
+            return true;
+        }
+        match self.heap.peek_mut() {
+            Some(mut min) if x > min.0 => {
+                *min = Reverse(x);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Returns `k`, the maximum number of values that are kept.
+    #[inline]
+    pub fn k(&self) -> usize {
+        self.k
+    }
+
+    /// Returns the number of values that are kept, which is `k` unless fewer values were added.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.heap.len()
+    }
+
+    /// Returns `true` if no values are kept.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.heap.is_empty()
+    }
+
+    /// Returns the smallest of the kept values, which a new value has to exceed to be added once
+    /// there are `k` values.
+    #[inline]
+    pub fn min(&self) -> Option<&T> {
+        self.heap.peek().map(|Reverse(x)| x)
+    }
+
+    /// Returns an iterator over the kept values in arbitrary order.
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.heap.iter().map(|Reverse(x)| x)
+    }
+
+    /// Returns the kept values, sorted from largest to smallest.
+    pub fn into_sorted_vec(self) -> Vec<T> {
+        self.heap
+            .into_sorted_vec()
+            .into_iter()
+            .map(|Reverse(x)| x)
+            .collect()
+    }
+}
+
+/// An iterator adaptor that keeps track of the `k` largest elements from the base iterator so
+/// far, and yields whether each element is among them.
+///
+/// This is created by [`running_top_k()`]. See its documentation for more information.
+///
+/// [`running_top_k()`]: crate::IterAccumulate::running_top_k
+pub type RunningTopK<I, T> = AccumulateOutput<I, TopK<T>, fn(TopK<T>, T) -> (TopK<T>, bool)>;
+
+/// Adds `x` to `top` and yields whether it was added.
+pub(crate) fn step<T: Ord>(mut top: TopK<T>, x: T) -> (TopK<T>, bool) {
+    let added = top.push(x);
+    (top, added)
+}
@@ -0,0 +1,121 @@
+use crate::POISONED;
+use core::{cmp, fmt, iter::FusedIterator};
+
+/// An iterator adaptor that advances two iterators in lock-step and accumulates both of their
+/// elements using the provided closure.
+///
+/// This `struct` is created by [`zip_accumulate()`]. See its documentation for more
+/// information.
+///
+/// [`zip_accumulate()`]: crate::IterAccumulate::zip_accumulate
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone)]
+pub struct ZipAccumulate<A, Z, B, F> {
+    a: A,
+    b: Z,
+    /// The accumulated value. This is only [`None`] while it is moved into the closure, or if the
+    /// closure panicked.
+    acc: Option<B>,
+    f: F,
+    /// Whether either of the iterators has returned [`None`] yet.
+    done: bool,
+}
+
+impl<A, Z, B, F> ZipAccumulate<A, Z, B, F> {
+    pub(crate) fn new(a: A, b: Z, acc: B, f: F) -> Self {
+        Self {
+            a,
+            b,
+            acc: Some(acc),
+            f,
+            done: false,
+        }
+    }
+
+    /// Returns a reference to the current accumulated value.
+    #[inline]
+    pub fn acc(&self) -> &B {
+        self.acc.as_ref().expect(POISONED)
+    }
+
+    /// Consumes the iterator adaptor and returns the current accumulated value.
+    #[inline]
+    pub fn into_acc(self) -> B {
+        self.acc.expect(POISONED)
+    }
+}
+
+impl<A, Z, B, F> fmt::Debug for ZipAccumulate<A, Z, B, F>
+where
+    A: fmt::Debug,
+    Z: fmt::Debug,
+    B: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("ZipAccumulate")
+            .field("a", &self.a)
+            .field("b", &self.b)
+            .field("acc", &self.acc)
+            .field("done", &self.done)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<A, Z, B, F> Iterator for ZipAccumulate<A, Z, B, F>
+where
+    A: Iterator,
+    Z: Iterator,
+    B: Clone,
+    F: FnMut(B, A::Item, Z::Item) -> B,
+{
+    type Item = B;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        match (self.a.next(), self.b.next()) {
+            (Some(a), Some(b)) => {
+                let acc = (self.f)(self.acc.take().expect(POISONED), a, b);
+                Some(self.acc.insert(acc).clone())
+            }
+            _ => {
+                self.done = true;
+                None
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            return (0, Some(0));
+        }
+        let (a_lower, a_upper) = self.a.size_hint();
+        let (b_lower, b_upper) = self.b.size_hint();
+        let upper = match (a_upper, b_upper) {
+            (Some(a), Some(b)) => Some(cmp::min(a, b)),
+            (a, b) => a.or(b),
+        };
+        (cmp::min(a_lower, b_lower), upper)
+    }
+}
+
+impl<A, Z, B, F> ExactSizeIterator for ZipAccumulate<A, Z, B, F>
+where
+    A: ExactSizeIterator,
+    Z: ExactSizeIterator,
+    B: Clone,
+    F: FnMut(B, A::Item, Z::Item) -> B,
+{
+}
+
+impl<A, Z, B, F> FusedIterator for ZipAccumulate<A, Z, B, F>
+where
+    A: Iterator,
+    Z: Iterator,
+    B: Clone,
+    F: FnMut(B, A::Item, Z::Item) -> B,
+{
+}